@@ -84,48 +84,26 @@ export class AgreementsService {
 
     const savedAgreement = await this.agreementRepository.save(agreement);
 
-    // Create on-chain agreement
-    try {
-      const txHash = await this.chiomaContract.createAgreement({
-        agreementId: agreementNumber,
-        landlord: createAgreementDto.landlordStellarPubKey,
-        tenant: createAgreementDto.tenantStellarPubKey,
-        agent: createAgreementDto.agentStellarPubKey,
-        monthlyRent: createAgreementDto.monthlyRent.toString(),
-        securityDeposit: createAgreementDto.securityDeposit.toString(),
-        startDate: Math.floor(startDate.getTime() / 1000),
-        endDate: Math.floor(endDate.getTime() / 1000),
-        agentCommissionRate: createAgreementDto.agentCommissionRate || 0,
-        paymentToken: 'NATIVE',
-      });
+    // The on-chain draft must be created by the landlord or tenant with
+    // their own key (see ChiomaContractService.createAgreement); the
+    // contract rejects drafts from the platform account. It is picked up
+    // by agreement number once it exists.
 
-      savedAgreement.transactionHash = txHash;
-      savedAgreement.blockchainAgreementId = agreementNumber;
-      savedAgreement.blockchainSyncedAt = new Date();
-      await this.agreementRepository.save(savedAgreement);
-
-      // Create escrow for security deposit if required
-      if (
-        createAgreementDto.securityDeposit &&
-        Number(createAgreementDto.securityDeposit) > 0
-      ) {
-        try {
-          await this.escrowIntegration.createEscrowForAgreement(
-            savedAgreement.id,
-          );
-        } catch (escrowError) {
-          this.logger.warn(
-            `Failed to create escrow for agreement ${savedAgreement.id}: ${escrowError.message}`,
-          );
-          // Don't fail the entire agreement creation if escrow fails
-        }
+    // Create escrow for security deposit if required
+    if (
+      createAgreementDto.securityDeposit &&
+      Number(createAgreementDto.securityDeposit) > 0
+    ) {
+      try {
+        await this.escrowIntegration.createEscrowForAgreement(
+          savedAgreement.id,
+        );
+      } catch (escrowError) {
+        this.logger.warn(
+          `Failed to create escrow for agreement ${savedAgreement.id}: ${escrowError.message}`,
+        );
+        // Don't fail the entire agreement creation if escrow fails
       }
-    } catch (error) {
-      // Rollback database if blockchain fails
-      await this.agreementRepository.remove(savedAgreement);
-      throw new BadRequestException(
-        `Failed to create on-chain agreement: ${error.message}`,
-      );
     }
 
     return savedAgreement;
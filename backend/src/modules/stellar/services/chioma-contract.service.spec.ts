@@ -43,6 +43,7 @@ describe('ChiomaContractService', () => {
   it('should have all contract methods', () => {
     expect(service.createAgreement).toBeDefined();
     expect(service.signAgreement).toBeDefined();
+    expect(service.getTermsHash).toBeDefined();
     expect(service.submitAgreement).toBeDefined();
     expect(service.cancelAgreement).toBeDefined();
     expect(service.getAgreement).toBeDefined();
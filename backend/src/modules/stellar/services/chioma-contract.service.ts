@@ -5,6 +5,7 @@ import { Contract, SorobanRpc, xdr } from '@stellar/stellar-sdk';
 
 export interface CreateAgreementParams {
   agreementId: string;
+  propertyId: string;
   landlord: string;
   tenant: string;
   agent?: string;
@@ -14,6 +15,10 @@ export interface CreateAgreementParams {
   endDate: number;
   agentCommissionRate: number;
   paymentToken: string;
+  /** Hex-encoded SHA-256 of the lease document */
+  documentHash: string;
+  /** IPFS CID of the lease document, if pinned */
+  documentCid?: string;
 }
 
 export interface PaymentSplit {
@@ -67,35 +72,25 @@ export class ChiomaContractService {
     }
   }
 
-  async createAgreement(params: CreateAgreementParams): Promise<string> {
+  /**
+   * Draft an agreement on chain. The initiator must be the landlord or the
+   * tenant; the contract rejects drafts submitted by any other account.
+   */
+  async createAgreement(
+    initiator: string,
+    params: CreateAgreementParams,
+    initiatorKeypair: StellarSdk.Keypair,
+  ): Promise<string> {
     try {
       if (!this.isConfigured || !this.contract) {
         throw new Error('Contract not configured');
       }
-      if (!this.adminKeypair) {
-        throw new Error('Admin keypair not configured');
-      }
-
-      const account = await this.server.getAccount(
-        this.adminKeypair.publicKey(),
-      );
+      const account = await this.server.getAccount(initiator);
 
       const operation = this.contract.call(
         'create_agreement',
-        xdr.ScVal.scvString(params.agreementId),
-        new StellarSdk.Address(params.landlord).toScVal(),
-        new StellarSdk.Address(params.tenant).toScVal(),
-        params.agent
-          ? xdr.ScVal.scvVec([new StellarSdk.Address(params.agent).toScVal()])
-          : xdr.ScVal.scvVec([]),
-        StellarSdk.nativeToScVal(BigInt(params.monthlyRent), { type: 'i128' }),
-        StellarSdk.nativeToScVal(BigInt(params.securityDeposit), {
-          type: 'i128',
-        }),
-        StellarSdk.nativeToScVal(params.startDate, { type: 'u64' }),
-        StellarSdk.nativeToScVal(params.endDate, { type: 'u64' }),
-        StellarSdk.nativeToScVal(params.agentCommissionRate, { type: 'u32' }),
-        new StellarSdk.Address(params.paymentToken).toScVal(),
+        new StellarSdk.Address(initiator).toScVal(),
+        this.agreementTermsToScVal(params),
       );
 
       const tx = new StellarSdk.TransactionBuilder(account, {
@@ -107,7 +102,7 @@ export class ChiomaContractService {
         .build();
 
       const prepared = await this.server.prepareTransaction(tx);
-      prepared.sign(this.adminKeypair);
+      prepared.sign(initiatorKeypair);
 
       const result = await this.server.sendTransaction(prepared);
       return await this.pollTransactionStatus(result.hash);
@@ -120,21 +115,28 @@ export class ChiomaContractService {
     }
   }
 
+  /**
+   * Sign the current terms of a Pending agreement. The landlord, every
+   * tenant and the guarantor each sign; the terms hash is read from the
+   * contract so the signature covers exactly what is stored.
+   */
   async signAgreement(
-    tenant: string,
+    signer: string,
     agreementId: string,
-    tenantKeypair: StellarSdk.Keypair,
+    signerKeypair: StellarSdk.Keypair,
   ): Promise<string> {
     try {
       if (!this.isConfigured || !this.contract) {
         throw new Error('Contract not configured');
       }
-      const account = await this.server.getAccount(tenant);
+      const termsHash = await this.getTermsHash(agreementId);
+      const account = await this.server.getAccount(signer);
 
       const operation = this.contract.call(
         'sign_agreement',
-        new StellarSdk.Address(tenant).toScVal(),
+        new StellarSdk.Address(signer).toScVal(),
         xdr.ScVal.scvString(agreementId),
+        xdr.ScVal.scvBytes(termsHash),
       );
 
       const tx = new StellarSdk.TransactionBuilder(account, {
@@ -146,7 +148,7 @@ export class ChiomaContractService {
         .build();
 
       const prepared = await this.server.prepareTransaction(tx);
-      prepared.sign(tenantKeypair);
+      prepared.sign(signerKeypair);
 
       const result = await this.server.sendTransaction(prepared);
       return await this.pollTransactionStatus(result.hash);
@@ -159,6 +161,48 @@ export class ChiomaContractService {
     }
   }
 
+  async getTermsHash(agreementId: string): Promise<Buffer> {
+    try {
+      if (!this.isConfigured || !this.contract) {
+        throw new Error('Contract not configured');
+      }
+      if (!this.adminKeypair) {
+        throw new Error('Admin keypair not configured');
+      }
+
+      const account = await this.server.getAccount(
+        this.adminKeypair.publicKey(),
+      );
+
+      const operation = this.contract.call(
+        'get_terms_hash',
+        xdr.ScVal.scvString(agreementId),
+      );
+
+      const tx = new StellarSdk.TransactionBuilder(account, {
+        fee: StellarSdk.BASE_FEE,
+        networkPassphrase: this.networkPassphrase,
+      })
+        .addOperation(operation)
+        .setTimeout(30)
+        .build();
+
+      const simulated = await this.server.simulateTransaction(tx);
+
+      if (SorobanRpc.Api.isSimulationSuccess(simulated) && simulated.result) {
+        return Buffer.from(simulated.result.retval.bytes());
+      }
+
+      throw new Error('Failed to get terms hash');
+    } catch (error) {
+      this.logger.error(
+        `Failed to get terms hash: ${error.message}`,
+        error.stack,
+      );
+      throw error;
+    }
+  }
+
   async submitAgreement(
     landlord: string,
     agreementId: string,
@@ -440,6 +484,62 @@ export class ChiomaContractService {
     throw new Error(`Transaction timeout: ${hash}`);
   }
 
+  /**
+   * Encode `AgreementTerms` the way the contract stores structs: a map keyed
+   * by field name, in sorted key order, with `None` as void.
+   */
+  private agreementTermsToScVal(params: CreateAgreementParams): xdr.ScVal {
+    const fields: [string, xdr.ScVal][] = [
+      [
+        'agent',
+        params.agent
+          ? new StellarSdk.Address(params.agent).toScVal()
+          : xdr.ScVal.scvVoid(),
+      ],
+      [
+        'agent_commission_rate',
+        StellarSdk.nativeToScVal(params.agentCommissionRate, { type: 'u32' }),
+      ],
+      ['agreement_id', xdr.ScVal.scvString(params.agreementId)],
+      [
+        'document_cid',
+        params.documentCid
+          ? xdr.ScVal.scvString(params.documentCid)
+          : xdr.ScVal.scvVoid(),
+      ],
+      [
+        'document_hash',
+        xdr.ScVal.scvBytes(Buffer.from(params.documentHash, 'hex')),
+      ],
+      ['end_date', StellarSdk.nativeToScVal(params.endDate, { type: 'u64' })],
+      ['landlord', new StellarSdk.Address(params.landlord).toScVal()],
+      [
+        'monthly_rent',
+        StellarSdk.nativeToScVal(BigInt(params.monthlyRent), { type: 'i128' }),
+      ],
+      ['payment_token', new StellarSdk.Address(params.paymentToken).toScVal()],
+      ['property_id', xdr.ScVal.scvString(params.propertyId)],
+      [
+        'security_deposit',
+        StellarSdk.nativeToScVal(BigInt(params.securityDeposit), {
+          type: 'i128',
+        }),
+      ],
+      [
+        'start_date',
+        StellarSdk.nativeToScVal(params.startDate, { type: 'u64' }),
+      ],
+      ['tenant', new StellarSdk.Address(params.tenant).toScVal()],
+    ];
+
+    return xdr.ScVal.scvMap(
+      fields.map(
+        ([key, val]) =>
+          new xdr.ScMapEntry({ key: xdr.ScVal.scvSymbol(key), val }),
+      ),
+    );
+  }
+
   private parseAgreementResult(result: xdr.ScVal): any {
     const native = StellarSdk.scValToNative(result);
     return native;
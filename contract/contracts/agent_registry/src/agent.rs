@@ -1,15 +1,18 @@
 use soroban_sdk::{Address, Env, String, Vec};
 
+use crate::access;
 use crate::errors::AgentError;
 use crate::events;
 use crate::storage::DataKey;
-use crate::types::{AgentInfo, AgentTransaction, ContractState};
+use crate::types::{AgentInfo, AgentTransaction, Operation, Role};
 
 pub fn register_agent(
     env: &Env,
     agent: Address,
     external_profile_hash: String,
 ) -> Result<(), AgentError> {
+    access::require_not_paused(env, Operation::Registration)?;
+
     if !env.storage().persistent().has(&DataKey::Initialized) {
         return Err(AgentError::NotInitialized);
     }
@@ -51,18 +54,10 @@ pub fn register_agent(
     Ok(())
 }
 
-pub fn verify_agent(env: &Env, admin: Address, agent: Address) -> Result<(), AgentError> {
-    let state: ContractState = env
-        .storage()
-        .instance()
-        .get(&DataKey::State)
-        .ok_or(AgentError::NotInitialized)?;
-
-    admin.require_auth();
+pub fn verify_agent(env: &Env, caller: Address, agent: Address) -> Result<(), AgentError> {
+    access::require_not_paused(env, Operation::Verification)?;
 
-    if admin != state.admin {
-        return Err(AgentError::Unauthorized);
-    }
+    access::require_role(env, &caller, Role::Verifier)?;
 
     let key = DataKey::Agent(agent.clone());
     let mut agent_info: AgentInfo = env
@@ -81,7 +76,7 @@ pub fn verify_agent(env: &Env, admin: Address, agent: Address) -> Result<(), Age
     env.storage().persistent().set(&key, &agent_info);
     env.storage().persistent().extend_ttl(&key, 500000, 500000);
 
-    events::agent_verified(env, admin, agent);
+    events::agent_verified(env, caller, agent);
 
     Ok(())
 }
@@ -93,6 +88,8 @@ pub fn rate_agent(
     score: u32,
     transaction_id: String,
 ) -> Result<(), AgentError> {
+    access::require_not_paused(env, Operation::Ratings)?;
+
     if !env.storage().persistent().has(&DataKey::Initialized) {
         return Err(AgentError::NotInitialized);
     }
@@ -182,6 +179,8 @@ pub fn register_transaction(
     agent: Address,
     parties: Vec<Address>,
 ) -> Result<(), AgentError> {
+    access::require_not_paused(env, Operation::Transactions)?;
+
     if !env.storage().persistent().has(&DataKey::Initialized) {
         return Err(AgentError::NotInitialized);
     }
@@ -215,6 +214,8 @@ pub fn complete_transaction(
     transaction_id: String,
     agent: Address,
 ) -> Result<(), AgentError> {
+    access::require_not_paused(env, Operation::Transactions)?;
+
     if !env.storage().persistent().has(&DataKey::Initialized) {
         return Err(AgentError::NotInitialized);
     }
@@ -1,3 +1,4 @@
+use chioma_types::AccessError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -17,4 +18,19 @@ pub enum AgentError {
     TransactionNotFound = 11,
     NotTransactionParty = 12,
     TransactionNotCompleted = 13,
+    NoPendingAdmin = 14,
+    InvalidRole = 15,
+    OperationPaused = 16,
+}
+
+impl From<AccessError> for AgentError {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => AgentError::NotInitialized,
+            AccessError::Unauthorized => AgentError::Unauthorized,
+            AccessError::InvalidRole => AgentError::InvalidRole,
+            AccessError::NoPendingAdmin => AgentError::NoPendingAdmin,
+            AccessError::OperationPaused => AgentError::OperationPaused,
+        }
+    }
 }
@@ -1,4 +1,5 @@
-use soroban_sdk::{contractevent, Address, Env, String};
+use crate::types::Operation;
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
 
 #[contractevent(topics = ["initialized"])]
 pub struct ContractInitialized {
@@ -38,6 +39,35 @@ pub struct TransactionRegistered {
     pub agent: Address,
 }
 
+#[contractevent(topics = ["upgraded"])]
+pub struct ContractUpgraded {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+#[contractevent(topics = ["migrated"])]
+pub struct ContractMigrated {
+    #[topic]
+    pub admin: Address,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+#[contractevent(topics = ["op_paused"])]
+pub struct OperationPaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+#[contractevent(topics = ["op_unpaused"])]
+pub struct OperationUnpaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
 pub(crate) fn contract_initialized(env: &Env, admin: Address) {
     ContractInitialized { admin }.publish(env);
 }
@@ -70,3 +100,24 @@ pub(crate) fn transaction_registered(env: &Env, transaction_id: String, agent: A
     }
     .publish(env);
 }
+
+pub(crate) fn contract_upgraded(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    ContractUpgraded { admin, wasm_hash }.publish(env);
+}
+
+pub(crate) fn contract_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    ContractMigrated {
+        admin,
+        from_version,
+        to_version,
+    }
+    .publish(env);
+}
+
+pub(crate) fn operation_paused(env: &Env, operation: Operation, by: Address) {
+    OperationPaused { operation, by }.publish(env);
+}
+
+pub(crate) fn operation_unpaused(env: &Env, operation: Operation, by: Address) {
+    OperationUnpaused { operation, by }.publish(env);
+}
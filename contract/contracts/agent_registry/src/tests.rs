@@ -48,6 +48,149 @@ fn test_double_initialization_fails() {
     client.initialize(&admin);
 }
 
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let wasm_hash = soroban_sdk::BytesN::from_array(&env, &[9; 32]);
+
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.try_migrate(&admin),
+        Err(Ok(AgentError::NotInitialized))
+    );
+
+    client.initialize(&admin);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_migrate(&outsider),
+        Err(Ok(AgentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_upgrade(&outsider, &wasm_hash),
+        Err(Ok(AgentError::Unauthorized))
+    );
+
+    // A deployment from before schema versioning has no version key
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_admin_transfer_requires_acceptance() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(AgentError::NoPendingAdmin))
+    );
+
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_state().unwrap().admin, admin);
+
+    assert_eq!(
+        client.try_accept_admin(&outsider),
+        Err(Ok(AgentError::Unauthorized))
+    );
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_state().unwrap().admin, new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_propose_admin(&admin, &outsider),
+        Err(Ok(AgentError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_verifier_role_can_verify_agents() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.try_grant_role(&admin, &Role::Admin, &verifier),
+        Err(Ok(AgentError::InvalidRole))
+    );
+
+    client.grant_role(&admin, &Role::Verifier, &verifier);
+    assert!(client.has_role(&Role::Verifier, &verifier));
+
+    let profile_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    client.register_agent(&first, &profile_hash);
+    client.register_agent(&second, &profile_hash);
+
+    client.verify_agent(&verifier, &first);
+    assert!(client.get_agent_info(&first).unwrap().verified);
+
+    client.revoke_role(&admin, &Role::Verifier, &verifier);
+    assert_eq!(
+        client.try_verify_agent(&verifier, &second),
+        Err(Ok(AgentError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_guardian_can_pause_but_not_unpause() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+    client.grant_role(&admin, &Role::Guardian, &guardian);
+    client.grant_role(&admin, &Role::Pauser, &pauser);
+
+    client.pause(&guardian, &Operation::Registration);
+    assert!(client.is_paused(&Operation::Registration));
+    assert!(!client.is_paused(&Operation::Ratings));
+
+    let profile_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    assert_eq!(
+        client.try_register_agent(&agent, &profile_hash),
+        Err(Ok(AgentError::OperationPaused))
+    );
+    assert_eq!(
+        client.try_unpause(&guardian, &Operation::Registration),
+        Err(Ok(AgentError::Unauthorized))
+    );
+
+    client.unpause(&pauser, &Operation::Registration);
+    client.register_agent(&agent, &profile_hash);
+}
+
 #[test]
 fn test_register_agent_success() {
     let env = Env::default();
@@ -1,5 +1,7 @@
 use soroban_sdk::{contracttype, Address, String, Vec};
 
+pub use chioma_types::Role;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AgentInfo {
@@ -15,11 +17,9 @@ pub struct AgentInfo {
 
 impl AgentInfo {
     pub fn average_rating(&self) -> u32 {
-        if self.total_ratings == 0 {
-            0
-        } else {
-            self.total_score / self.total_ratings
-        }
+        self.total_score
+            .checked_div(self.total_ratings)
+            .unwrap_or_default()
     }
 }
 
@@ -47,3 +47,13 @@ pub struct AgentTransaction {
     pub parties: Vec<Address>,
     pub completed: bool,
 }
+
+/// A group of calls that can be paused on its own. Reads are never paused.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    Registration,
+    Verification,
+    Transactions,
+    Ratings,
+}
@@ -0,0 +1,114 @@
+//! Access control for the Chioma/Rental contract. Admin transfer, roles and
+//! pause flags are handled by `chioma_types::access`; this module binds them
+//! to the admin in `ContractState` and emits this contract's pause events.
+use chioma_types::access::{self as shared, AccessControl};
+use soroban_sdk::{Address, Env};
+
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{ContractState, Operation, Role};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+fn load_state(env: &Env) -> Result<ContractState, RentalError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(RentalError::InvalidState)
+}
+
+fn save_state(env: &Env, state: &ContractState) {
+    env.storage().instance().set(&DataKey::State, state);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+}
+
+pub(crate) struct Access;
+
+impl AccessControl for Access {
+    type Error = RentalError;
+
+    fn admin(env: &Env) -> Option<Address> {
+        load_state(env).ok().map(|state| state.admin)
+    }
+
+    fn set_admin(env: &Env, admin: &Address) {
+        if let Ok(mut state) = load_state(env) {
+            state.admin = admin.clone();
+            save_state(env, &state);
+        }
+    }
+
+    fn is_grantable(role: &Role) -> bool {
+        matches!(role, Role::Pauser | Role::FeeManager | Role::Guardian)
+    }
+}
+
+/// Whether `account` holds `role`. The admin holds every role.
+pub fn has_role(env: &Env, role: Role, account: Address) -> bool {
+    shared::has_role::<Access>(env, role, account)
+}
+
+/// Require `caller` to hold `role` and to have authorized the call
+pub(crate) fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), RentalError> {
+    shared::require_role::<Access>(env, caller, role)
+}
+
+/// Grant `role` to `account` (admin only)
+pub fn grant_role(env: &Env, role: Role, account: Address) -> Result<(), RentalError> {
+    let state = load_state(env)?;
+    shared::grant_role::<Access>(env, state.admin, role, account)
+}
+
+/// Revoke `role` from `account` (admin only)
+pub fn revoke_role(env: &Env, role: Role, account: Address) -> Result<(), RentalError> {
+    let state = load_state(env)?;
+    shared::revoke_role::<Access>(env, state.admin, role, account)
+}
+
+/// Nominate the next admin (admin only). Nothing changes until the nominee
+/// accepts; a later nomination replaces an unaccepted one.
+pub fn propose_admin(env: &Env, new_admin: Address) -> Result<(), RentalError> {
+    let state = load_state(env)?;
+    shared::propose_admin::<Access>(env, state.admin, new_admin)
+}
+
+/// Take over as admin (nominee only). Granted roles are left in place.
+pub fn accept_admin(env: &Env) -> Result<(), RentalError> {
+    load_state(env)?;
+    let new_admin = get_pending_admin(env).ok_or(RentalError::NoPendingAdmin)?;
+    shared::accept_admin::<Access>(env, new_admin)
+}
+
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    shared::get_pending_admin(env)
+}
+
+/// Whether `operation` is paused
+pub fn is_paused(env: &Env, operation: Operation) -> bool {
+    shared::is_paused(env, operation)
+}
+
+/// Pause one operation (admin, pauser or guardian)
+pub fn pause(env: &Env, caller: Address, operation: Operation) -> Result<(), RentalError> {
+    if shared::pause::<Access, _>(env, &caller, operation.clone())? {
+        events::operation_paused(env, operation, caller);
+    }
+
+    Ok(())
+}
+
+/// Resume a paused operation (admin or pauser)
+pub fn unpause(env: &Env, caller: Address, operation: Operation) -> Result<(), RentalError> {
+    if shared::unpause::<Access, _>(env, &caller, operation.clone())? {
+        events::operation_unpaused(env, operation, caller);
+    }
+
+    Ok(())
+}
+
+/// Fail with `ContractPaused` if `operation` is paused
+pub(crate) fn require_not_paused(env: &Env, operation: Operation) -> Result<(), RentalError> {
+    shared::require_not_paused::<Access, _>(env, operation)
+}
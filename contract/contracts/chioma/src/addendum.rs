@@ -0,0 +1,208 @@
+//! Signed addenda to Active agreements for the Chioma/Rental contract.
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+use crate::agreement;
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{Addendum, AgreementStatus, RentAgreement};
+use crate::MAX_PAGE_SIZE;
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+fn save_addendum(env: &Env, addendum: &Addendum) {
+    let key = DataKey::Addendum(addendum.agreement_id.clone(), addendum.index);
+    env.storage().persistent().set(&key, addendum);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Record `signer`'s signature on an addendum and return how many of
+/// `signers` have yet to sign
+fn record_signature(
+    env: &Env,
+    addendum: &Addendum,
+    signers: &Vec<Address>,
+    signer: &Address,
+    signed_at: u64,
+) -> u32 {
+    let key = DataKey::AddendumSignature(
+        addendum.agreement_id.clone(),
+        addendum.index,
+        signer.clone(),
+    );
+    env.storage().persistent().set(&key, &signed_at);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    signers
+        .iter()
+        .filter(|address| {
+            get_addendum_signature(
+                env,
+                addendum.agreement_id.clone(),
+                addendum.index,
+                address.clone(),
+            )
+            .is_none()
+        })
+        .count() as u32
+}
+
+/// Append an addendum to an Active agreement, signed by the proposer.
+/// Any party who signed the agreement may propose one. Returns its index.
+pub fn propose_addendum(
+    env: &Env,
+    proposer: Address,
+    agreement_id: String,
+    document_hash: BytesN<32>,
+    document_cid: Option<String>,
+) -> Result<u32, RentalError> {
+    proposer.require_auth();
+
+    let agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    let signers = agreement::required_signers(env, &agreement);
+    if !signers.contains(&proposer) {
+        return Err(RentalError::Unauthorized);
+    }
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    let now = env.ledger().timestamp();
+    let index = get_addendum_count(env, agreement_id.clone());
+    let mut addendum = Addendum {
+        agreement_id: agreement_id.clone(),
+        index,
+        document_hash: document_hash.clone(),
+        document_cid: document_cid.clone(),
+        proposed_by: proposer.clone(),
+        proposed_at: now,
+        executed_at: None,
+    };
+
+    let count_key = DataKey::AddendumCount(agreement_id.clone());
+    env.storage().persistent().set(&count_key, &(index + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, TTL_THRESHOLD, TTL_BUMP);
+
+    events::addendum_proposed(
+        env,
+        agreement_id.clone(),
+        proposer.clone(),
+        index,
+        document_hash,
+        document_cid,
+    );
+
+    let remaining = record_signature(env, &addendum, &signers, &proposer, now);
+    if remaining == 0 {
+        addendum.executed_at = Some(now);
+    }
+    save_addendum(env, &addendum);
+
+    events::addendum_signed(env, agreement_id, proposer, index, now, remaining);
+
+    Ok(index)
+}
+
+/// Sign an addendum's document hash. The addendum is in force once every
+/// signer of the agreement has signed it.
+pub fn sign_addendum(
+    env: &Env,
+    signer: Address,
+    agreement_id: String,
+    index: u32,
+    document_hash: BytesN<32>,
+) -> Result<(), RentalError> {
+    signer.require_auth();
+
+    let agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    let mut addendum =
+        get_addendum(env, agreement_id.clone(), index).ok_or(RentalError::AddendumNotFound)?;
+
+    let signers = agreement::required_signers(env, &agreement);
+    if !signers.contains(&signer) {
+        return Err(RentalError::Unauthorized);
+    }
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    if get_addendum_signature(env, agreement_id.clone(), index, signer.clone()).is_some() {
+        return Err(RentalError::InvalidState);
+    }
+
+    // The signer must have seen exactly the document on record
+    if document_hash != addendum.document_hash {
+        return Err(RentalError::TermsMismatch);
+    }
+
+    let now = env.ledger().timestamp();
+    let remaining = record_signature(env, &addendum, &signers, &signer, now);
+    if remaining == 0 {
+        addendum.executed_at = Some(now);
+        save_addendum(env, &addendum);
+    }
+
+    events::addendum_signed(env, agreement_id, signer, index, now, remaining);
+
+    Ok(())
+}
+
+pub fn get_addendum(env: &Env, agreement_id: String, index: u32) -> Option<Addendum> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Addendum(agreement_id, index))
+}
+
+/// Returns the number of addenda proposed on an agreement
+pub fn get_addendum_count(env: &Env, agreement_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AddendumCount(agreement_id))
+        .unwrap_or(0)
+}
+
+/// Returns when `signer` signed an addendum, if they have
+pub fn get_addendum_signature(
+    env: &Env,
+    agreement_id: String,
+    index: u32,
+    signer: Address,
+) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AddendumSignature(agreement_id, index, signer))
+}
+
+/// Returns a page of an agreement's addenda, oldest first
+pub fn list_addenda(env: &Env, agreement_id: String, start: u32, limit: u32) -> Vec<Addendum> {
+    let mut addenda = Vec::new(env);
+    let count = get_addendum_count(env, agreement_id.clone());
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    for index in start..end {
+        if let Some(addendum) = get_addendum(env, agreement_id.clone(), index) {
+            addenda.push_back(addendum);
+        }
+    }
+
+    addenda
+}
@@ -1,10 +1,17 @@
 //! Agreement management logic for the Chioma/Rental contract.
-use soroban_sdk::{Address, Env, Map, String};
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env, Map, String, Vec};
 
+use crate::co_tenancy;
 use crate::errors::RentalError;
 use crate::events;
+use crate::index;
+use crate::negotiation;
+use crate::property;
 use crate::storage::DataKey;
-use crate::types::{AgreementStatus, PaymentSplit, RentAgreement};
+use crate::types::{
+    AgreementSignature, AgreementStatus, AgreementTerms, ContractState, LeaseDocument,
+    RentAgreement,
+};
 
 const TTL_THRESHOLD: u32 = 500000;
 const TTL_BUMP: u32 = 500000;
@@ -42,23 +49,34 @@ pub fn validate_agreement_params(
     Ok(())
 }
 
-/// Create a new rent agreement
-#[allow(clippy::too_many_arguments)]
+/// Create a new rent agreement on behalf of either party
 pub fn create_agreement(
     env: &Env,
-    agreement_id: String,
-    landlord: Address,
-    tenant: Address,
-    agent: Option<Address>,
-    monthly_rent: i128,
-    security_deposit: i128,
-    start_date: u64,
-    end_date: u64,
-    agent_commission_rate: u32,
-    payment_token: Address,
+    initiator: Address,
+    terms: AgreementTerms,
 ) -> Result<(), RentalError> {
-    // Tenant MUST authorize creation
-    tenant.require_auth();
+    let AgreementTerms {
+        agreement_id,
+        property_id,
+        landlord,
+        tenant,
+        agent,
+        monthly_rent,
+        security_deposit,
+        start_date,
+        end_date,
+        agent_commission_rate,
+        payment_token,
+        document_hash,
+        document_cid,
+    } = terms;
+
+    // Either party may draft the agreement; both sign it later
+    initiator.require_auth();
+
+    if initiator != landlord && initiator != tenant {
+        return Err(RentalError::Unauthorized);
+    }
 
     // Validate inputs
     validate_agreement_params(
@@ -70,6 +88,9 @@ pub fn create_agreement(
         &agent_commission_rate,
     )?;
 
+    // Only the registered owner of a verified property may let it
+    property::verify_listing(env, &property_id, &landlord)?;
+
     // Check for duplicate agreement_id
     if env
         .storage()
@@ -99,6 +120,17 @@ pub fn create_agreement(
         payment_history: Map::new(env),
     };
 
+    property::check_available(env, &property_id, &agreement)?;
+    property::set_agreement_property(env, &agreement_id, &property_id);
+    set_lease_document(
+        env,
+        &agreement_id,
+        &LeaseDocument {
+            hash: document_hash,
+            cid: document_cid,
+        },
+    );
+
     // Store agreement
     env.storage()
         .persistent()
@@ -108,6 +140,7 @@ pub fn create_agreement(
         TTL_THRESHOLD,
         TTL_BUMP,
     );
+    index::add_agreement(env, &agreement);
 
     // Update counter
     let mut count: u32 = env
@@ -125,6 +158,8 @@ pub fn create_agreement(
     events::agreement_created(
         env,
         agreement_id,
+        property_id,
+        initiator,
         tenant,
         landlord,
         monthly_rent,
@@ -137,10 +172,92 @@ pub fn create_agreement(
     Ok(())
 }
 
-/// Sign an agreement as the tenant
-pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Result<(), RentalError> {
-    // Tenant MUST authorize signing
-    tenant.require_auth();
+/// Returns the terms both parties sign, as stored on `agreement`
+pub fn agreement_terms(env: &Env, agreement: &RentAgreement) -> AgreementTerms {
+    // Agreements created before documents were anchored have none
+    let document =
+        get_lease_document(env, agreement.agreement_id.clone()).unwrap_or_else(|| LeaseDocument {
+            hash: BytesN::from_array(env, &[0; 32]),
+            cid: None,
+        });
+
+    AgreementTerms {
+        agreement_id: agreement.agreement_id.clone(),
+        property_id: property::get_agreement_property(env, agreement.agreement_id.clone())
+            .unwrap_or_else(|| String::from_str(env, "")),
+        landlord: agreement.landlord.clone(),
+        tenant: agreement.tenant.clone(),
+        agent: agreement.agent.clone(),
+        monthly_rent: agreement.monthly_rent,
+        security_deposit: agreement.security_deposit,
+        start_date: agreement.start_date,
+        end_date: agreement.end_date,
+        agent_commission_rate: agreement.agent_commission_rate,
+        payment_token: agreement.payment_token.clone(),
+        document_hash: document.hash,
+        document_cid: document.cid,
+    }
+}
+
+/// SHA-256 of the XDR encoding of the agreement's terms together with its
+/// co-tenancy, so every signer also agrees to the rent shares and guarantor
+pub fn compute_terms_hash(env: &Env, agreement: &RentAgreement) -> BytesN<32> {
+    let co_tenancy = co_tenancy::get_co_tenancy(env, agreement.agreement_id.clone());
+    env.crypto()
+        .sha256(&(agreement_terms(env, agreement), co_tenancy).to_xdr(env))
+        .to_bytes()
+}
+
+/// Returns the hash a signer must supply to sign the agreement's current terms
+pub fn get_terms_hash(env: &Env, agreement_id: String) -> Result<BytesN<32>, RentalError> {
+    let agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    Ok(compute_terms_hash(env, &agreement))
+}
+
+/// Returns everyone who must sign before the agreement becomes Active: the
+/// landlord, every tenant and the guarantor, if any
+pub(crate) fn required_signers(env: &Env, agreement: &RentAgreement) -> Vec<Address> {
+    let mut signers = Vec::new(env);
+    signers.push_back(agreement.landlord.clone());
+    signers.append(&co_tenancy::tenant_signers(env, agreement));
+    signers
+}
+
+/// Returns `signer`'s signature on the agreement, if they have signed
+pub fn get_signature(
+    env: &Env,
+    agreement_id: String,
+    signer: Address,
+) -> Option<AgreementSignature> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Signature(agreement_id, signer))
+}
+
+/// Drop every signature on the agreement, e.g. after its terms change
+pub(crate) fn clear_signatures(env: &Env, agreement: &RentAgreement) {
+    for signer in required_signers(env, agreement).iter() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Signature(agreement.agreement_id.clone(), signer));
+    }
+}
+
+/// Sign the exact terms of a Pending agreement as the landlord, a tenant or
+/// the guarantor. The agreement becomes Active once everyone has signed.
+pub fn sign_agreement(
+    env: &Env,
+    signer: Address,
+    agreement_id: String,
+    terms_hash: BytesN<32>,
+) -> Result<(), RentalError> {
+    // Signer MUST authorize signing
+    signer.require_auth();
 
     // Retrieve the agreement
     let mut agreement: RentAgreement = env
@@ -149,8 +266,9 @@ pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Resul
         .get(&DataKey::Agreement(agreement_id.clone()))
         .ok_or(RentalError::AgreementNotFound)?;
 
-    // Validate caller is the intended tenant
-    if agreement.tenant != tenant {
+    // Validate caller is one of the intended signers
+    let signers = required_signers(env, &agreement);
+    if !signers.contains(&signer) {
         return Err(RentalError::NotTenant);
     }
 
@@ -159,15 +277,60 @@ pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Resul
         return Err(RentalError::InvalidState);
     }
 
+    if get_signature(env, agreement_id.clone(), signer.clone()).is_some() {
+        return Err(RentalError::InvalidState);
+    }
+
+    // The signer must have seen exactly the terms on record
+    if terms_hash != compute_terms_hash(env, &agreement) {
+        return Err(RentalError::TermsMismatch);
+    }
+
     // Validate agreement has not expired
     let current_time = env.ledger().timestamp();
     if current_time > agreement.end_date {
         return Err(RentalError::Expired);
     }
 
+    let signature = AgreementSignature {
+        signer: signer.clone(),
+        terms_hash: terms_hash.clone(),
+        signed_at: current_time,
+    };
+    let key = DataKey::Signature(agreement_id.clone(), signer.clone());
+    env.storage().persistent().set(&key, &signature);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    let remaining = signers
+        .iter()
+        .filter(|address| get_signature(env, agreement_id.clone(), address.clone()).is_none())
+        .count() as u32;
+
+    events::agreement_party_signed(
+        env,
+        agreement_id.clone(),
+        signer,
+        terms_hash,
+        current_time,
+        remaining,
+    );
+
+    if remaining > 0 {
+        return Ok(());
+    }
+
+    // Another lease may have taken the property since this one was drafted
+    if let Some(property_id) = property::get_agreement_property(env, agreement_id.clone()) {
+        property::check_available(env, &property_id, &agreement)?;
+        property::record_lease(env, &property_id, &agreement)?;
+    }
+
     // Update agreement status and record signing time
     agreement.status = AgreementStatus::Active;
     agreement.signed_at = Some(current_time);
+    negotiation::clear_counter_offer(env, &agreement_id);
 
     // Save updated agreement
     env.storage()
@@ -184,7 +347,7 @@ pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Resul
     events::agreement_signed(
         env,
         agreement_id,
-        tenant,
+        agreement.tenant.clone(),
         agreement.landlord.clone(),
         current_time,
     );
@@ -192,13 +355,13 @@ pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Resul
     Ok(())
 }
 
-/// Submit a draft agreement for tenant signature (Draft → Pending)
+/// Submit a draft agreement for signature (Draft → Pending), by either party
 pub fn submit_agreement(
     env: &Env,
-    landlord: Address,
+    caller: Address,
     agreement_id: String,
 ) -> Result<(), RentalError> {
-    landlord.require_auth();
+    caller.require_auth();
 
     let mut agreement: RentAgreement = env
         .storage()
@@ -206,7 +369,7 @@ pub fn submit_agreement(
         .get(&DataKey::Agreement(agreement_id.clone()))
         .ok_or(RentalError::AgreementNotFound)?;
 
-    if agreement.landlord != landlord {
+    if agreement.landlord != caller && agreement.tenant != caller {
         return Err(RentalError::Unauthorized);
     }
 
@@ -225,7 +388,12 @@ pub fn submit_agreement(
         TTL_BUMP,
     );
 
-    events::agreement_submitted(env, agreement_id, landlord, agreement.tenant.clone());
+    events::agreement_submitted(
+        env,
+        agreement_id,
+        agreement.landlord.clone(),
+        agreement.tenant.clone(),
+    );
 
     Ok(())
 }
@@ -244,8 +412,8 @@ pub fn cancel_agreement(
         .get(&DataKey::Agreement(agreement_id.clone()))
         .ok_or(RentalError::AgreementNotFound)?;
 
-    // Only landlord can cancel
-    if agreement.landlord != caller {
+    // Either party can walk away before the agreement takes effect
+    if caller != agreement.landlord && caller != agreement.tenant {
         return Err(RentalError::Unauthorized);
     }
 
@@ -255,6 +423,7 @@ pub fn cancel_agreement(
     }
 
     agreement.status = AgreementStatus::Cancelled;
+    negotiation::clear_counter_offer(env, &agreement_id);
 
     env.storage()
         .persistent()
@@ -265,7 +434,121 @@ pub fn cancel_agreement(
         TTL_BUMP,
     );
 
-    events::agreement_cancelled(env, agreement_id, caller, agreement.tenant.clone());
+    events::agreement_cancelled(
+        env,
+        agreement_id,
+        agreement.landlord.clone(),
+        agreement.tenant.clone(),
+        caller,
+    );
+
+    Ok(())
+}
+
+/// Load the dispute contract registered by the admin and require its authorization
+fn require_dispute_contract(env: &Env) -> Result<Address, RentalError> {
+    let dispute_contract: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::DisputeContract)
+        .ok_or(RentalError::DisputeContractNotSet)?;
+
+    dispute_contract.require_auth();
+
+    Ok(dispute_contract)
+}
+
+/// Register the dispute resolution contract allowed to change dispute state
+pub fn set_dispute_contract(env: &Env, dispute_contract: Address) -> Result<(), RentalError> {
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(RentalError::InvalidState)?;
+
+    state.admin.require_auth();
+
+    env.storage()
+        .instance()
+        .set(&DataKey::DisputeContract, &dispute_contract);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+    events::dispute_contract_set(env, state.admin, dispute_contract);
+
+    Ok(())
+}
+
+/// Move an Active agreement into Disputed (dispute contract only)
+pub fn mark_disputed(env: &Env, agreement_id: String) -> Result<(), RentalError> {
+    require_dispute_contract(env)?;
+
+    let mut agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    agreement.status = AgreementStatus::Disputed;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id.clone()),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+
+    events::agreement_disputed(env, agreement_id, agreement.landlord, agreement.tenant);
+
+    Ok(())
+}
+
+/// Close the dispute on an agreement, restoring it to Active or terminating it
+/// (dispute contract only)
+pub fn resolve_dispute(
+    env: &Env,
+    agreement_id: String,
+    terminate: bool,
+) -> Result<(), RentalError> {
+    require_dispute_contract(env)?;
+
+    let mut agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    if agreement.status != AgreementStatus::Disputed {
+        return Err(RentalError::InvalidState);
+    }
+
+    agreement.status = if terminate {
+        AgreementStatus::Terminated
+    } else {
+        AgreementStatus::Active
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id.clone()),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+
+    events::agreement_dispute_resolved(
+        env,
+        agreement_id,
+        agreement.landlord,
+        agreement.tenant,
+        terminate,
+    );
 
     Ok(())
 }
@@ -277,6 +560,21 @@ pub fn get_agreement(env: &Env, agreement_id: String) -> Option<RentAgreement> {
         .get(&DataKey::Agreement(agreement_id))
 }
 
+/// Returns the lease document an agreement's parties sign, if it has one
+pub fn get_lease_document(env: &Env, agreement_id: String) -> Option<LeaseDocument> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgreementDocument(agreement_id))
+}
+
+pub(crate) fn set_lease_document(env: &Env, agreement_id: &String, document: &LeaseDocument) {
+    let key = DataKey::AgreementDocument(agreement_id.clone());
+    env.storage().persistent().set(&key, document);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
 /// Check whether a rent agreement exists for the given identifier
 pub fn has_agreement(env: &Env, agreement_id: String) -> bool {
     env.storage()
@@ -291,21 +589,3 @@ pub fn get_agreement_count(env: &Env) -> u32 {
         .get(&DataKey::AgreementCount)
         .unwrap_or(0)
 }
-
-/// Get payment split for a specific month in an agreement
-pub fn get_payment_split(
-    env: &Env,
-    agreement_id: String,
-    month: u32,
-) -> Result<PaymentSplit, RentalError> {
-    let agreement: RentAgreement = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id))
-        .ok_or(RentalError::AgreementNotFound)?;
-
-    agreement
-        .payment_history
-        .get(month)
-        .ok_or(RentalError::AgreementNotFound)
-}
@@ -0,0 +1,457 @@
+//! Shared agreements: co-tenants, rent shares and guarantors.
+use soroban_sdk::{token, vec, Address, Env, IntoVal, String, Val, Vec};
+
+use crate::errors::RentalError;
+use crate::events;
+use crate::history;
+use crate::index;
+use crate::storage::DataKey;
+use crate::types::{
+    AgreementStatus, CoTenancy, ContractState, PartyRole, PaymentSplit, RentAgreement, RentPeriod,
+    TenantShare,
+};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Maximum number of tenants on one agreement
+pub const MAX_CO_TENANTS: u32 = 10;
+
+/// Length of a rent period in seconds (30 days)
+pub const RENT_PERIOD: u64 = 2_592_000;
+
+fn load_agreement(env: &Env, agreement_id: &String) -> Result<RentAgreement, RentalError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)
+}
+
+fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Name the tenants sharing a Draft agreement, their rent shares and an
+/// optional guarantor (landlord only)
+pub fn set_co_tenants(
+    env: &Env,
+    landlord: Address,
+    agreement_id: String,
+    tenants: Vec<TenantShare>,
+    guarantor: Option<Address>,
+) -> Result<(), RentalError> {
+    landlord.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.landlord != landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    // Shares are part of the terms, so they are fixed before signing starts
+    if agreement.status != AgreementStatus::Draft {
+        return Err(RentalError::InvalidState);
+    }
+
+    if tenants.is_empty() || tenants.len() > MAX_CO_TENANTS {
+        return Err(RentalError::InvalidShares);
+    }
+
+    let mut total_bps: u32 = 0;
+    let mut includes_lead = false;
+    for (index, share) in tenants.iter().enumerate() {
+        if share.share_bps == 0 || share.tenant == landlord {
+            return Err(RentalError::InvalidShares);
+        }
+        let duplicate = tenants
+            .iter()
+            .skip(index + 1)
+            .any(|other| other.tenant == share.tenant);
+        if duplicate {
+            return Err(RentalError::InvalidShares);
+        }
+        includes_lead |= share.tenant == agreement.tenant;
+        total_bps = total_bps.saturating_add(share.share_bps);
+    }
+
+    if !includes_lead || total_bps != 10_000 {
+        return Err(RentalError::InvalidShares);
+    }
+
+    if let Some(guarantor) = &guarantor {
+        if *guarantor == landlord || tenants.iter().any(|share| share.tenant == *guarantor) {
+            return Err(RentalError::InvalidShares);
+        }
+    }
+
+    let co_tenancy = CoTenancy {
+        tenants: tenants.clone(),
+        guarantor: guarantor.clone(),
+    };
+    set_persistent(env, &DataKey::CoTenancy(agreement_id.clone()), &co_tenancy);
+    for share in co_tenancy.tenants.iter() {
+        index::add(env, PartyRole::Tenant, &share.tenant, &agreement_id);
+    }
+
+    events::co_tenants_set(env, agreement_id, landlord, tenants.len(), guarantor);
+
+    Ok(())
+}
+
+pub fn get_co_tenancy(env: &Env, agreement_id: String) -> Option<CoTenancy> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CoTenancy(agreement_id))
+}
+
+/// Returns the rent shares of an agreement; a sole tenant holds all of it
+pub(crate) fn tenant_shares(env: &Env, agreement: &RentAgreement) -> Vec<TenantShare> {
+    match get_co_tenancy(env, agreement.agreement_id.clone()) {
+        Some(co_tenancy) => co_tenancy.tenants,
+        None => vec![
+            env,
+            TenantShare {
+                tenant: agreement.tenant.clone(),
+                share_bps: 10_000,
+            },
+        ],
+    }
+}
+
+/// Whether `address` is one of the agreement's tenants
+pub(crate) fn is_tenant(env: &Env, agreement: &RentAgreement, address: &Address) -> bool {
+    tenant_shares(env, agreement)
+        .iter()
+        .any(|share| share.tenant == *address)
+}
+
+/// Returns the tenants and guarantor who must sign alongside the landlord
+pub(crate) fn tenant_signers(env: &Env, agreement: &RentAgreement) -> Vec<Address> {
+    let mut signers = Vec::new(env);
+    for share in tenant_shares(env, agreement).iter() {
+        signers.push_back(share.tenant);
+    }
+    if let Some(co_tenancy) = get_co_tenancy(env, agreement.agreement_id.clone()) {
+        if let Some(guarantor) = co_tenancy.guarantor {
+            signers.push_back(guarantor);
+        }
+    }
+    signers
+}
+
+/// Returns the rent owed for the agreement's current period: the amount
+/// recorded when its first share was paid, or else the current rent
+fn period_rent(env: &Env, agreement: &RentAgreement) -> i128 {
+    env.storage()
+        .persistent()
+        .get::<_, RentPeriod>(&DataKey::RentPeriod(
+            agreement.agreement_id.clone(),
+            agreement.payment_count,
+        ))
+        .map_or(agreement.monthly_rent, |period| period.rent)
+}
+
+/// Returns what `tenant` owes of `rent`. The lead tenant also carries any
+/// rounding remainder so the shares always add up to the full rent.
+fn share_amount(
+    agreement: &RentAgreement,
+    rent: i128,
+    shares: &Vec<TenantShare>,
+    tenant: &Address,
+) -> i128 {
+    let portion = |share: &TenantShare| rent * share.share_bps as i128 / 10_000;
+
+    if *tenant != agreement.tenant {
+        return shares
+            .iter()
+            .find(|share| share.tenant == *tenant)
+            .map_or(0, |share| portion(&share));
+    }
+
+    let others: i128 = shares
+        .iter()
+        .filter(|share| share.tenant != agreement.tenant)
+        .map(|share| portion(&share))
+        .sum();
+    rent - others
+}
+
+fn has_paid(env: &Env, agreement_id: &String, period: u32, tenant: &Address) -> bool {
+    env.storage().persistent().has(&DataKey::SharePaid(
+        agreement_id.clone(),
+        period,
+        tenant.clone(),
+    ))
+}
+
+/// Whether every period of the lease has been paid for. Periods are paid in
+/// advance, so the current one is owed only if it starts before the lease ends.
+fn lease_paid_out(agreement: &RentAgreement) -> bool {
+    agreement.next_payment_due >= agreement.end_date
+}
+
+/// Loads an Active agreement with a period still owed, and the contract
+/// state needed to take payment
+fn load_for_payment(
+    env: &Env,
+    agreement_id: &String,
+) -> Result<(ContractState, RentAgreement), RentalError> {
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(RentalError::InvalidState)?;
+
+    let agreement = load_agreement(env, agreement_id)?;
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    // No rent is owed, by tenants or the guarantor, past the end of the lease
+    if lease_paid_out(&agreement) {
+        return Err(RentalError::Expired);
+    }
+
+    Ok((state, agreement))
+}
+
+/// Takes `tenant`'s share of the current period from `payer`, closing the
+/// period once every share is in.
+///
+/// The caller is responsible for persisting the updated agreement, which it
+/// must do before calling `transfer_share`.
+fn collect_share(
+    env: &Env,
+    state: &ContractState,
+    agreement: &mut RentAgreement,
+    share_count: u32,
+    tenant: &Address,
+    payer: &Address,
+    amount: i128,
+) -> (i128, i128) {
+    let period = agreement.payment_count;
+    let agreement_id = agreement.agreement_id.clone();
+    let now = env.ledger().timestamp();
+
+    let platform_amount = amount * state.config.fee_bps as i128 / 10_000;
+    let landlord_amount = amount - platform_amount;
+
+    let period_key = DataKey::RentPeriod(agreement_id.clone(), period);
+    let mut totals: RentPeriod =
+        env.storage()
+            .persistent()
+            .get(&period_key)
+            .unwrap_or(RentPeriod {
+                rent: agreement.monthly_rent,
+                amount_paid: 0,
+                landlord_amount: 0,
+                platform_amount: 0,
+                shares_paid: 0,
+            });
+    totals.amount_paid += amount;
+    totals.landlord_amount += landlord_amount;
+    totals.platform_amount += platform_amount;
+    totals.shares_paid += 1;
+    set_persistent(env, &period_key, &totals);
+
+    let paid_key = DataKey::SharePaid(agreement_id.clone(), period, tenant.clone());
+    set_persistent(env, &paid_key, payer);
+
+    agreement.total_rent_paid += amount;
+
+    let period_complete = totals.shares_paid == share_count;
+    if period_complete {
+        history::record_payment(
+            env,
+            &agreement_id,
+            period,
+            &PaymentSplit {
+                landlord_amount: totals.landlord_amount,
+                platform_amount: totals.platform_amount,
+                token: agreement.payment_token.clone(),
+                payment_date: now,
+                payer: payer.clone(),
+            },
+        );
+        agreement.payment_count += 1;
+        agreement.next_payment_due += RENT_PERIOD;
+    }
+
+    events::rent_share_paid(
+        env,
+        agreement_id,
+        tenant.clone(),
+        payer.clone(),
+        period,
+        amount,
+        period_complete,
+    );
+
+    (landlord_amount, platform_amount)
+}
+
+fn transfer_share(
+    env: &Env,
+    state: &ContractState,
+    agreement: &RentAgreement,
+    payer: &Address,
+    (landlord_amount, platform_amount): (i128, i128),
+) {
+    let token_client = token::Client::new(env, &agreement.payment_token);
+    if landlord_amount > 0 {
+        token_client.transfer(payer, &agreement.landlord, &landlord_amount);
+    }
+    if platform_amount > 0 {
+        token_client.transfer(payer, &state.config.fee_collector, &platform_amount);
+    }
+}
+
+/// Pay the caller's share of the current rent period (tenants only).
+/// A period can be paid once the previous one has fallen due, so shares
+/// can't be prepaid further ahead. Returns the period paid for.
+pub fn pay_rent_share(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+) -> Result<u32, RentalError> {
+    tenant.require_auth();
+
+    let (state, mut agreement) = load_for_payment(env, &agreement_id)?;
+    let shares = tenant_shares(env, &agreement);
+
+    if !shares.iter().any(|share| share.tenant == tenant) {
+        return Err(RentalError::NotTenant);
+    }
+
+    if env.ledger().timestamp() < agreement.next_payment_due.saturating_sub(RENT_PERIOD) {
+        return Err(RentalError::PaymentNotDue);
+    }
+
+    let period = agreement.payment_count;
+    if has_paid(env, &agreement_id, period, &tenant) {
+        return Err(RentalError::ShareAlreadyPaid);
+    }
+
+    let amount = share_amount(&agreement, period_rent(env, &agreement), &shares, &tenant);
+    let split = collect_share(
+        env,
+        &state,
+        &mut agreement,
+        shares.len(),
+        &tenant,
+        &tenant,
+        amount,
+    );
+    set_persistent(env, &DataKey::Agreement(agreement_id), &agreement);
+
+    transfer_share(env, &state, &agreement, &tenant, split);
+
+    Ok(period)
+}
+
+/// Pay every share still outstanding on an overdue period (guarantor only).
+/// Returns the amount covered.
+pub fn cover_shortfall(
+    env: &Env,
+    guarantor: Address,
+    agreement_id: String,
+) -> Result<i128, RentalError> {
+    guarantor.require_auth();
+
+    let (state, mut agreement) = load_for_payment(env, &agreement_id)?;
+
+    let co_tenancy = get_co_tenancy(env, agreement_id.clone()).ok_or(RentalError::Unauthorized)?;
+    if co_tenancy.guarantor != Some(guarantor.clone()) {
+        return Err(RentalError::Unauthorized);
+    }
+
+    // Tenants keep until the due date to pay their own shares
+    if env.ledger().timestamp() <= agreement.next_payment_due {
+        return Err(RentalError::NoShortfall);
+    }
+
+    let period = agreement.payment_count;
+    let rent = period_rent(env, &agreement);
+    let shares = co_tenancy.tenants;
+    let mut covered: i128 = 0;
+    let mut landlord_total: i128 = 0;
+    let mut platform_total: i128 = 0;
+
+    for share in shares.iter() {
+        if has_paid(env, &agreement_id, period, &share.tenant) {
+            continue;
+        }
+        let amount = share_amount(&agreement, rent, &shares, &share.tenant);
+        let (landlord_amount, platform_amount) = collect_share(
+            env,
+            &state,
+            &mut agreement,
+            shares.len(),
+            &share.tenant,
+            &guarantor,
+            amount,
+        );
+        covered += amount;
+        landlord_total += landlord_amount;
+        platform_total += platform_amount;
+    }
+
+    if covered == 0 {
+        return Err(RentalError::NoShortfall);
+    }
+
+    set_persistent(env, &DataKey::Agreement(agreement_id), &agreement);
+
+    transfer_share(
+        env,
+        &state,
+        &agreement,
+        &guarantor,
+        (landlord_total, platform_total),
+    );
+
+    Ok(covered)
+}
+
+/// Returns the amount still unpaid on the agreement's current rent period
+pub fn get_rent_shortfall(env: &Env, agreement_id: String) -> Result<i128, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    if lease_paid_out(&agreement) {
+        return Ok(0);
+    }
+
+    let shares = tenant_shares(env, &agreement);
+    let period = agreement.payment_count;
+    let rent = period_rent(env, &agreement);
+
+    let mut shortfall: i128 = 0;
+    for share in shares.iter() {
+        if !has_paid(env, &agreement_id, period, &share.tenant) {
+            shortfall += share_amount(&agreement, rent, &shares, &share.tenant);
+        }
+    }
+    Ok(shortfall)
+}
+
+/// Returns the running totals for a rent period, if anything has been paid
+pub fn get_rent_period(env: &Env, agreement_id: String, period: u32) -> Option<RentPeriod> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RentPeriod(agreement_id, period))
+}
+
+/// Returns who paid `tenant`'s share of a rent period, if it has been paid
+pub fn get_share_payer(
+    env: &Env,
+    agreement_id: String,
+    period: u32,
+    tenant: Address,
+) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SharePaid(agreement_id, period, tenant))
+}
@@ -1,5 +1,8 @@
+use crate::types::{
+    ConfigChange, MaintenancePriority, MaintenanceSla, Operation, RentControl, SlaBreach,
+};
 use crate::Config;
-use soroban_sdk::{contractevent, Address, Env, String};
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
 
 /// Event emitted when the contract is initialized
 /// Topics: ["initialized", admin: Address]
@@ -9,7 +12,6 @@ pub struct ContractInitialized {
     pub admin: Address,
     pub fee_bps: u32,
     pub fee_collector: Address,
-    pub paused: bool,
 }
 
 /// Event emitted when an agreement is created
@@ -21,6 +23,8 @@ pub struct AgreementCreated {
     #[topic]
     pub landlord: Address,
     pub agreement_id: String,
+    pub property_id: String,
+    pub initiator: Address,
     pub monthly_rent: i128,
     pub security_deposit: i128,
     pub start_date: u64,
@@ -60,6 +64,8 @@ pub struct AgreementCancelled {
     #[topic]
     pub tenant: Address,
     pub agreement_id: String,
+    /// The landlord or tenant who cancelled
+    pub cancelled_by: Address,
 }
 
 /// Event emitted when the contract configuration is updated
@@ -72,8 +78,385 @@ pub struct ConfigUpdated {
     pub new_fee_bps: u32,
     pub old_fee_collector: Address,
     pub new_fee_collector: Address,
-    pub old_paused: bool,
-    pub new_paused: bool,
+}
+
+/// Event emitted when the dispute resolution contract is registered
+/// Topics: ["dispute_cfg", admin: Address]
+#[contractevent(topics = ["dispute_cfg"])]
+pub struct DisputeContractSet {
+    #[topic]
+    pub admin: Address,
+    pub dispute_contract: Address,
+}
+
+/// Event emitted when the property registry contract is registered
+/// Topics: ["property_cfg", admin: Address]
+#[contractevent(topics = ["property_cfg"])]
+pub struct PropertyRegistrySet {
+    #[topic]
+    pub admin: Address,
+    pub property_registry: Address,
+}
+
+/// Event emitted when an agreement enters the Disputed state
+/// Topics: ["agr_dispute", landlord: Address, tenant: Address]
+#[contractevent(topics = ["agr_dispute"])]
+pub struct AgreementDisputed {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+}
+
+/// Event emitted when a dispute on an agreement is closed
+/// Topics: ["agr_resolved", landlord: Address, tenant: Address]
+#[contractevent(topics = ["agr_resolved"])]
+pub struct AgreementDisputeResolved {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub terminated: bool,
+}
+
+/// Event emitted when the SLA for a maintenance priority is set
+/// Topics: ["maint_sla", admin: Address]
+#[contractevent(topics = ["maint_sla"])]
+pub struct MaintenanceSlaSet {
+    #[topic]
+    pub admin: Address,
+    pub priority: MaintenancePriority,
+    pub acknowledge_within: u64,
+    pub complete_within: u64,
+}
+
+/// Event emitted when a tenant opens a maintenance request
+/// Topics: ["maint_opened", tenant: Address, landlord: Address]
+#[contractevent(topics = ["maint_opened"])]
+pub struct MaintenanceOpened {
+    #[topic]
+    pub tenant: Address,
+    #[topic]
+    pub landlord: Address,
+    pub request_id: u32,
+    pub agreement_id: String,
+    pub priority: MaintenancePriority,
+    pub description_hash: String,
+}
+
+/// Event emitted when a landlord acknowledges a maintenance request
+/// Topics: ["maint_ack", landlord: Address, tenant: Address]
+#[contractevent(topics = ["maint_ack"])]
+pub struct MaintenanceAcknowledged {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub request_id: u32,
+    pub acknowledged_at: u64,
+}
+
+/// Event emitted when a landlord schedules the work on a maintenance request
+/// Topics: ["maint_sched", landlord: Address, tenant: Address]
+#[contractevent(topics = ["maint_sched"])]
+pub struct MaintenanceScheduled {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub request_id: u32,
+    pub scheduled_for: u64,
+}
+
+/// Event emitted when a landlord completes a maintenance request
+/// Topics: ["maint_done", landlord: Address, tenant: Address]
+#[contractevent(topics = ["maint_done"])]
+pub struct MaintenanceCompleted {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub request_id: u32,
+    pub completed_at: u64,
+}
+
+/// Event emitted when a maintenance request misses an SLA deadline
+/// Topics: ["sla_breach", landlord: Address, tenant: Address]
+#[contractevent(topics = ["sla_breach"])]
+pub struct SlaBreached {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub request_id: u32,
+    pub agreement_id: String,
+    pub breach: SlaBreach,
+    pub recorded_at: u64,
+}
+
+/// Event emitted when rent control rules are set for a jurisdiction
+/// Topics: ["rent_control", admin: Address]
+#[contractevent(topics = ["rent_control"])]
+pub struct RentControlSet {
+    #[topic]
+    pub admin: Address,
+    pub jurisdiction: String,
+    pub max_increase_bps: u32,
+    pub notice_period: u64,
+}
+
+/// Event emitted when rent control is lifted in a jurisdiction
+/// Topics: ["rent_ctl_removed", admin: Address]
+#[contractevent(topics = ["rent_ctl_removed"])]
+pub struct RentControlRemoved {
+    #[topic]
+    pub admin: Address,
+    pub jurisdiction: String,
+}
+
+/// Event emitted when an agreement's jurisdiction is recorded
+/// Topics: ["jurisdiction", caller: Address]
+#[contractevent(topics = ["jurisdiction"])]
+pub struct JurisdictionSet {
+    #[topic]
+    pub caller: Address,
+    pub agreement_id: String,
+    pub jurisdiction: String,
+}
+
+/// Event emitted when a landlord proposes a new rent
+/// Topics: ["rent_proposed", landlord: Address, tenant: Address]
+#[contractevent(topics = ["rent_proposed"])]
+pub struct RentChangeProposed {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub current_rent: i128,
+    pub proposed_rent: i128,
+    pub effective_from: u64,
+    pub terminate_on_reject: bool,
+}
+
+/// Event emitted when a tenant accepts a proposed rent
+/// Topics: ["rent_accepted", landlord: Address, tenant: Address]
+#[contractevent(topics = ["rent_accepted"])]
+pub struct RentChangeAccepted {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub proposed_rent: i128,
+    pub effective_from: u64,
+}
+
+/// Event emitted when a tenant rejects a proposed rent
+/// Topics: ["rent_rejected", landlord: Address, tenant: Address]
+#[contractevent(topics = ["rent_rejected"])]
+pub struct RentChangeRejected {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub terminated: bool,
+    pub end_date: u64,
+}
+
+/// Event emitted when a landlord withdraws a proposed rent
+/// Topics: ["rent_withdrawn", landlord: Address, tenant: Address]
+#[contractevent(topics = ["rent_withdrawn"])]
+pub struct RentChangeWithdrawn {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+}
+
+/// Event emitted when an accepted rent takes effect
+/// Topics: ["rent_applied", landlord: Address, tenant: Address]
+#[contractevent(topics = ["rent_applied"])]
+pub struct RentChangeApplied {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub old_rent: i128,
+    pub new_rent: i128,
+}
+
+/// Event emitted when the co-tenants of a draft agreement are set
+/// Topics: ["co_tenants", landlord: Address]
+#[contractevent(topics = ["co_tenants"])]
+pub struct CoTenantsSet {
+    #[topic]
+    pub landlord: Address,
+    pub agreement_id: String,
+    pub tenant_count: u32,
+    pub guarantor: Option<Address>,
+}
+
+/// Event emitted when a party signs an agreement's terms
+/// Topics: ["agr_party_signed", signer: Address]
+#[contractevent(topics = ["agr_party_signed"])]
+pub struct AgreementPartySigned {
+    #[topic]
+    pub signer: Address,
+    pub agreement_id: String,
+    pub terms_hash: BytesN<32>,
+    pub signed_at: u64,
+    pub remaining: u32,
+}
+
+/// Event emitted when an unsigned agreement's terms change
+/// Topics: ["agr_amended", landlord: Address, tenant: Address]
+#[contractevent(topics = ["agr_amended"])]
+pub struct AgreementAmended {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    /// Who proposed the new terms
+    pub proposed_by: Address,
+    pub revision: u32,
+}
+
+/// Event emitted when a tenant counters a Pending agreement's terms
+/// Topics: ["counter_offered", landlord: Address, proposer: Address]
+#[contractevent(topics = ["counter_offered"])]
+pub struct CounterOfferMade {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub proposer: Address,
+    pub agreement_id: String,
+    pub monthly_rent: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+}
+
+/// Event emitted when a counter-offer is rejected or withdrawn
+/// Topics: ["counter_closed", landlord: Address, proposer: Address]
+#[contractevent(topics = ["counter_closed"])]
+pub struct CounterOfferClosed {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub proposer: Address,
+    pub agreement_id: String,
+    /// True if the proposer withdrew it, false if the landlord rejected it
+    pub withdrawn: bool,
+}
+
+/// Event emitted when a party proposes an addendum to an Active agreement
+/// Topics: ["addendum_proposed", proposer: Address]
+#[contractevent(topics = ["addendum_proposed"])]
+pub struct AddendumProposed {
+    #[topic]
+    pub proposer: Address,
+    pub agreement_id: String,
+    pub index: u32,
+    pub document_hash: BytesN<32>,
+    pub document_cid: Option<String>,
+}
+
+/// Event emitted when a party signs an addendum
+/// Topics: ["addendum_signed", signer: Address]
+#[contractevent(topics = ["addendum_signed"])]
+pub struct AddendumSigned {
+    #[topic]
+    pub signer: Address,
+    pub agreement_id: String,
+    pub index: u32,
+    pub signed_at: u64,
+    /// Signatures still missing; the addendum is in force at zero
+    pub remaining: u32,
+}
+
+/// Event emitted when a tenant's share of a rent period is paid
+/// Topics: ["rent_share", tenant: Address, payer: Address]
+#[contractevent(topics = ["rent_share"])]
+pub struct RentSharePaid {
+    #[topic]
+    pub tenant: Address,
+    #[topic]
+    pub payer: Address,
+    pub agreement_id: String,
+    pub period: u32,
+    pub amount: i128,
+    pub period_complete: bool,
+}
+
+/// Event emitted when the contract code is upgraded
+/// Topics: ["upgraded", admin: Address]
+#[contractevent(topics = ["upgraded"])]
+pub struct ContractUpgraded {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Event emitted when stored data is migrated to a new schema version
+/// Topics: ["migrated", admin: Address]
+#[contractevent(topics = ["migrated"])]
+pub struct ContractMigrated {
+    #[topic]
+    pub admin: Address,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+/// Event emitted when an operation is paused
+/// Topics: ["op_paused", operation: Operation]
+#[contractevent(topics = ["op_paused"])]
+pub struct OperationPaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+/// Event emitted when a paused operation is resumed
+/// Topics: ["op_unpaused", operation: Operation]
+#[contractevent(topics = ["op_unpaused"])]
+pub struct OperationUnpaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+/// Event emitted when a fee change is queued behind the timelock
+/// Topics: ["cfg_scheduled", proposed_by: Address]
+#[contractevent(topics = ["cfg_scheduled"])]
+pub struct ConfigChangeScheduled {
+    #[topic]
+    pub proposed_by: Address,
+    pub change_id: u32,
+    pub fee_bps: u32,
+    pub fee_collector: Address,
+    pub executable_at: u64,
+}
+
+/// Event emitted when a queued fee change takes effect
+/// Topics: ["cfg_executed"]
+#[contractevent(topics = ["cfg_executed"])]
+pub struct ConfigChangeExecuted {
+    pub change_id: u32,
+}
+
+/// Event emitted when a queued fee change is cancelled
+/// Topics: ["cfg_cancelled", cancelled_by: Address]
+#[contractevent(topics = ["cfg_cancelled"])]
+pub struct ConfigChangeCancelled {
+    #[topic]
+    pub cancelled_by: Address,
+    pub change_id: u32,
 }
 
 /// Helper function to emit contract initialized event
@@ -82,7 +465,6 @@ pub(crate) fn contract_initialized(env: &Env, admin: Address, config: Config) {
         admin,
         fee_bps: config.fee_bps,
         fee_collector: config.fee_collector,
-        paused: config.paused,
     }
     .publish(env);
 }
@@ -92,6 +474,8 @@ pub(crate) fn contract_initialized(env: &Env, admin: Address, config: Config) {
 pub(crate) fn agreement_created(
     env: &Env,
     agreement_id: String,
+    property_id: String,
+    initiator: Address,
     tenant: Address,
     landlord: Address,
     monthly_rent: i128,
@@ -104,6 +488,8 @@ pub(crate) fn agreement_created(
         tenant,
         landlord,
         agreement_id,
+        property_id,
+        initiator,
         monthly_rent,
         security_deposit,
         start_date,
@@ -151,11 +537,13 @@ pub(crate) fn agreement_cancelled(
     agreement_id: String,
     landlord: Address,
     tenant: Address,
+    cancelled_by: Address,
 ) {
     AgreementCancelled {
         landlord,
         tenant,
         agreement_id,
+        cancelled_by,
     }
     .publish(env);
 }
@@ -168,8 +556,504 @@ pub(crate) fn config_updated(env: &Env, admin: Address, old_config: Config, new_
         new_fee_bps: new_config.fee_bps,
         old_fee_collector: old_config.fee_collector,
         new_fee_collector: new_config.fee_collector,
-        old_paused: old_config.paused,
-        new_paused: new_config.paused,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute contract set event
+pub(crate) fn dispute_contract_set(env: &Env, admin: Address, dispute_contract: Address) {
+    DisputeContractSet {
+        admin,
+        dispute_contract,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit property registry set event
+pub(crate) fn property_registry_set(env: &Env, admin: Address, property_registry: Address) {
+    PropertyRegistrySet {
+        admin,
+        property_registry,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit agreement disputed event
+pub(crate) fn agreement_disputed(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+) {
+    AgreementDisputed {
+        landlord,
+        tenant,
+        agreement_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit agreement dispute resolved event
+pub(crate) fn agreement_dispute_resolved(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    terminated: bool,
+) {
+    AgreementDisputeResolved {
+        landlord,
+        tenant,
+        agreement_id,
+        terminated,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit maintenance SLA set event
+pub(crate) fn maintenance_sla_set(
+    env: &Env,
+    admin: Address,
+    priority: MaintenancePriority,
+    sla: MaintenanceSla,
+) {
+    MaintenanceSlaSet {
+        admin,
+        priority,
+        acknowledge_within: sla.acknowledge_within,
+        complete_within: sla.complete_within,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit maintenance opened event
+pub(crate) fn maintenance_opened(
+    env: &Env,
+    request_id: u32,
+    agreement_id: String,
+    tenant: Address,
+    landlord: Address,
+    priority: MaintenancePriority,
+    description_hash: String,
+) {
+    MaintenanceOpened {
+        tenant,
+        landlord,
+        request_id,
+        agreement_id,
+        priority,
+        description_hash,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit maintenance acknowledged event
+pub(crate) fn maintenance_acknowledged(
+    env: &Env,
+    request_id: u32,
+    landlord: Address,
+    tenant: Address,
+    acknowledged_at: u64,
+) {
+    MaintenanceAcknowledged {
+        landlord,
+        tenant,
+        request_id,
+        acknowledged_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit maintenance scheduled event
+pub(crate) fn maintenance_scheduled(
+    env: &Env,
+    request_id: u32,
+    landlord: Address,
+    tenant: Address,
+    scheduled_for: u64,
+) {
+    MaintenanceScheduled {
+        landlord,
+        tenant,
+        request_id,
+        scheduled_for,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit maintenance completed event
+pub(crate) fn maintenance_completed(
+    env: &Env,
+    request_id: u32,
+    landlord: Address,
+    tenant: Address,
+    completed_at: u64,
+) {
+    MaintenanceCompleted {
+        landlord,
+        tenant,
+        request_id,
+        completed_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit SLA breached event
+pub(crate) fn sla_breached(
+    env: &Env,
+    request_id: u32,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    breach: SlaBreach,
+    recorded_at: u64,
+) {
+    SlaBreached {
+        landlord,
+        tenant,
+        request_id,
+        agreement_id,
+        breach,
+        recorded_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent control set event
+pub(crate) fn rent_control_set(
+    env: &Env,
+    admin: Address,
+    jurisdiction: String,
+    control: RentControl,
+) {
+    RentControlSet {
+        admin,
+        jurisdiction,
+        max_increase_bps: control.max_increase_bps,
+        notice_period: control.notice_period,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent control removed event
+pub(crate) fn rent_control_removed(env: &Env, admin: Address, jurisdiction: String) {
+    RentControlRemoved {
+        admin,
+        jurisdiction,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit jurisdiction set event
+pub(crate) fn jurisdiction_set(
+    env: &Env,
+    caller: Address,
+    agreement_id: String,
+    jurisdiction: String,
+) {
+    JurisdictionSet {
+        caller,
+        agreement_id,
+        jurisdiction,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent change proposed event
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rent_change_proposed(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    current_rent: i128,
+    proposed_rent: i128,
+    effective_from: u64,
+    terminate_on_reject: bool,
+) {
+    RentChangeProposed {
+        landlord,
+        tenant,
+        agreement_id,
+        current_rent,
+        proposed_rent,
+        effective_from,
+        terminate_on_reject,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent change accepted event
+pub(crate) fn rent_change_accepted(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    proposed_rent: i128,
+    effective_from: u64,
+) {
+    RentChangeAccepted {
+        landlord,
+        tenant,
+        agreement_id,
+        proposed_rent,
+        effective_from,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent change rejected event
+pub(crate) fn rent_change_rejected(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    terminated: bool,
+    end_date: u64,
+) {
+    RentChangeRejected {
+        landlord,
+        tenant,
+        agreement_id,
+        terminated,
+        end_date,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent change withdrawn event
+pub(crate) fn rent_change_withdrawn(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+) {
+    RentChangeWithdrawn {
+        landlord,
+        tenant,
+        agreement_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent change applied event
+pub(crate) fn rent_change_applied(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    old_rent: i128,
+    new_rent: i128,
+) {
+    RentChangeApplied {
+        landlord,
+        tenant,
+        agreement_id,
+        old_rent,
+        new_rent,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit co-tenants set event
+pub(crate) fn co_tenants_set(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant_count: u32,
+    guarantor: Option<Address>,
+) {
+    CoTenantsSet {
+        landlord,
+        agreement_id,
+        tenant_count,
+        guarantor,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit agreement party signed event
+pub(crate) fn agreement_party_signed(
+    env: &Env,
+    agreement_id: String,
+    signer: Address,
+    terms_hash: BytesN<32>,
+    signed_at: u64,
+    remaining: u32,
+) {
+    AgreementPartySigned {
+        signer,
+        agreement_id,
+        terms_hash,
+        signed_at,
+        remaining,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit rent share paid event
+pub(crate) fn rent_share_paid(
+    env: &Env,
+    agreement_id: String,
+    tenant: Address,
+    payer: Address,
+    period: u32,
+    amount: i128,
+    period_complete: bool,
+) {
+    RentSharePaid {
+        tenant,
+        payer,
+        agreement_id,
+        period,
+        amount,
+        period_complete,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit agreement amended event
+pub(crate) fn agreement_amended(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    proposed_by: Address,
+    revision: u32,
+) {
+    AgreementAmended {
+        landlord,
+        tenant,
+        agreement_id,
+        proposed_by,
+        revision,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit counter-offer made event
+pub(crate) fn counter_offer_made(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    proposer: Address,
+    monthly_rent: i128,
+    start_date: u64,
+    end_date: u64,
+) {
+    CounterOfferMade {
+        landlord,
+        proposer,
+        agreement_id,
+        monthly_rent,
+        start_date,
+        end_date,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit counter-offer closed event
+pub(crate) fn counter_offer_closed(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    proposer: Address,
+    withdrawn: bool,
+) {
+    CounterOfferClosed {
+        landlord,
+        proposer,
+        agreement_id,
+        withdrawn,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit addendum proposed event
+pub(crate) fn addendum_proposed(
+    env: &Env,
+    agreement_id: String,
+    proposer: Address,
+    index: u32,
+    document_hash: BytesN<32>,
+    document_cid: Option<String>,
+) {
+    AddendumProposed {
+        proposer,
+        agreement_id,
+        index,
+        document_hash,
+        document_cid,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit addendum signed event
+pub(crate) fn addendum_signed(
+    env: &Env,
+    agreement_id: String,
+    signer: Address,
+    index: u32,
+    signed_at: u64,
+    remaining: u32,
+) {
+    AddendumSigned {
+        signer,
+        agreement_id,
+        index,
+        signed_at,
+        remaining,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit contract upgraded event
+pub(crate) fn contract_upgraded(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    ContractUpgraded { admin, wasm_hash }.publish(env);
+}
+
+/// Helper function to emit contract migrated event
+pub(crate) fn contract_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    ContractMigrated {
+        admin,
+        from_version,
+        to_version,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit operation paused event
+pub(crate) fn operation_paused(env: &Env, operation: Operation, by: Address) {
+    OperationPaused { operation, by }.publish(env);
+}
+
+/// Helper function to emit operation unpaused event
+pub(crate) fn operation_unpaused(env: &Env, operation: Operation, by: Address) {
+    OperationUnpaused { operation, by }.publish(env);
+}
+
+/// Helper function to emit config change scheduled event
+pub(crate) fn config_change_scheduled(env: &Env, change: &ConfigChange) {
+    ConfigChangeScheduled {
+        proposed_by: change.proposed_by.clone(),
+        change_id: change.change_id,
+        fee_bps: change.fee_bps,
+        fee_collector: change.fee_collector.clone(),
+        executable_at: change.executable_at,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit config change executed event
+pub(crate) fn config_change_executed(env: &Env, change_id: u32) {
+    ConfigChangeExecuted { change_id }.publish(env);
+}
+
+/// Helper function to emit config change cancelled event
+pub(crate) fn config_change_cancelled(env: &Env, cancelled_by: Address, change_id: u32) {
+    ConfigChangeCancelled {
+        cancelled_by,
+        change_id,
     }
     .publish(env);
 }
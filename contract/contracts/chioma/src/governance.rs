@@ -0,0 +1,181 @@
+//! Timelocked fee changes for the Chioma/Rental contract.
+//!
+//! The platform fee and its collector can only change through a queue: a
+//! change is scheduled, stays visible for `CONFIG_TIMELOCK`, and can be
+//! cancelled until it is executed. It must then be executed within
+//! `CONFIG_EXECUTION_WINDOW`, and executing a change supersedes every change
+//! scheduled before it, so a stale fee never comes back without fresh
+//! notice. Pausing operations is not delayed.
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::access;
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{Config, ConfigChange, ConfigChangeStatus, ContractState, Role};
+use crate::MAX_PAGE_SIZE;
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+const DAY: u64 = 86400;
+
+/// Seconds a scheduled fee change must wait before it can be executed
+pub const CONFIG_TIMELOCK: u64 = 7 * DAY;
+
+/// Seconds after `executable_at` during which a scheduled change can still
+/// be executed
+pub const CONFIG_EXECUTION_WINDOW: u64 = 7 * DAY;
+
+fn load_state(env: &Env) -> Result<ContractState, RentalError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(RentalError::InvalidState)
+}
+
+fn save_change(env: &Env, change: &ConfigChange) {
+    let key = DataKey::ConfigChange(change.change_id);
+    env.storage().persistent().set(&key, change);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+fn load_scheduled(env: &Env, change_id: u32) -> Result<ConfigChange, RentalError> {
+    let change = get_config_change(env, change_id).ok_or(RentalError::ConfigChangeNotFound)?;
+
+    if change.status != ConfigChangeStatus::Scheduled {
+        return Err(RentalError::ConfigChangeNotFound);
+    }
+
+    Ok(change)
+}
+
+/// Queue a new platform fee and collector (admin or fee manager). Returns
+/// the id of the scheduled change.
+pub fn schedule_config_change(
+    env: &Env,
+    caller: Address,
+    fee_bps: u32,
+    fee_collector: Address,
+) -> Result<u32, RentalError> {
+    access::require_role(env, &caller, Role::FeeManager)?;
+
+    if fee_bps > 10_000 {
+        return Err(RentalError::InvalidConfig);
+    }
+
+    let change_id = get_config_change_count(env);
+    let now = env.ledger().timestamp();
+    let change = ConfigChange {
+        change_id,
+        fee_bps,
+        fee_collector,
+        proposed_by: caller,
+        scheduled_at: now,
+        executable_at: now + CONFIG_TIMELOCK,
+        status: ConfigChangeStatus::Scheduled,
+    };
+    save_change(env, &change);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::ConfigChangeCount, &(change_id + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::ConfigChangeCount, TTL_THRESHOLD, TTL_BUMP);
+
+    events::config_change_scheduled(env, &change);
+
+    Ok(change_id)
+}
+
+/// Drop a scheduled change before it is executed (admin or fee manager)
+pub fn cancel_config_change(env: &Env, caller: Address, change_id: u32) -> Result<(), RentalError> {
+    access::require_role(env, &caller, Role::FeeManager)?;
+
+    let mut change = load_scheduled(env, change_id)?;
+    change.status = ConfigChangeStatus::Cancelled;
+    save_change(env, &change);
+
+    events::config_change_cancelled(env, caller, change_id);
+
+    Ok(())
+}
+
+/// Put a scheduled change into force once its timelock has passed. Anyone
+/// may call this.
+pub fn execute_config_change(env: &Env, change_id: u32) -> Result<(), RentalError> {
+    let mut state = load_state(env)?;
+    let mut change = load_scheduled(env, change_id)?;
+
+    let now = env.ledger().timestamp();
+    if now < change.executable_at {
+        return Err(RentalError::TimelockNotElapsed);
+    }
+    if now > change.executable_at + CONFIG_EXECUTION_WINDOW {
+        return Err(RentalError::ConfigChangeExpired);
+    }
+
+    // A newer change has already run; this one's notice no longer stands
+    if get_last_executed_change(env).is_some_and(|last| change_id < last) {
+        return Err(RentalError::ConfigChangeSuperseded);
+    }
+
+    let old_config = state.config.clone();
+    state.config = Config {
+        fee_bps: change.fee_bps,
+        fee_collector: change.fee_collector.clone(),
+    };
+    env.storage().instance().set(&DataKey::State, &state);
+    env.storage()
+        .instance()
+        .set(&DataKey::LastExecutedConfigChange, &change_id);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+    change.status = ConfigChangeStatus::Executed;
+    save_change(env, &change);
+
+    events::config_updated(env, change.proposed_by, old_config, state.config);
+    events::config_change_executed(env, change_id);
+
+    Ok(())
+}
+
+/// Returns the id of the most recently executed fee change, if any
+pub fn get_last_executed_change(env: &Env) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::LastExecutedConfigChange)
+}
+
+pub fn get_config_change(env: &Env, change_id: u32) -> Option<ConfigChange> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ConfigChange(change_id))
+}
+
+/// Returns how many fee changes have ever been scheduled
+pub fn get_config_change_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ConfigChangeCount)
+        .unwrap_or(0)
+}
+
+/// Returns a page of scheduled, executed and cancelled fee changes, oldest first
+pub fn list_config_changes(env: &Env, start: u32, limit: u32) -> Vec<ConfigChange> {
+    let mut changes = Vec::new(env);
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(get_config_change_count(env));
+
+    for change_id in start..end {
+        if let Some(change) = get_config_change(env, change_id) {
+            changes.push_back(change);
+        }
+    }
+
+    changes
+}
@@ -0,0 +1,81 @@
+//! Per-payment rent history for the Chioma/Rental contract.
+//!
+//! Each completed rent period is stored in its own entry keyed by agreement
+//! and period, so the agreement entry does not grow with the lease. The
+//! agreement keeps only the running totals (`payment_count`, `total_rent_paid`).
+//! This contract has never written the agreement's inline `payment_history`;
+//! the field only exists so the shared `RentAgreement` encoding is unchanged.
+use soroban_sdk::{Env, String, Vec};
+
+use crate::errors::RentalError;
+use crate::storage::DataKey;
+use crate::types::{PaymentSplit, RentAgreement};
+use crate::MAX_PAGE_SIZE;
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Store the split of a completed rent period
+pub(crate) fn record_payment(env: &Env, agreement_id: &String, period: u32, split: &PaymentSplit) {
+    let key = DataKey::Payment(agreement_id.clone(), period);
+    env.storage().persistent().set(&key, split);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Get payment split for a specific month in an agreement
+pub fn get_payment_split(
+    env: &Env,
+    agreement_id: String,
+    month: u32,
+) -> Result<PaymentSplit, RentalError> {
+    if let Some(split) = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Payment(agreement_id.clone(), month))
+    {
+        return Ok(split);
+    }
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::Agreement(agreement_id))
+    {
+        return Err(RentalError::AgreementNotFound);
+    }
+
+    Err(RentalError::PaymentNotFound)
+}
+
+/// Returns a page of an agreement's completed rent payments, oldest first
+pub fn list_payments(
+    env: &Env,
+    agreement_id: String,
+    start: u32,
+    limit: u32,
+) -> Result<Vec<PaymentSplit>, RentalError> {
+    let agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    let mut payments = Vec::new(env);
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(agreement.payment_count);
+
+    for period in start..end {
+        let split = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Payment(agreement_id.clone(), period));
+        if let Some(split) = split {
+            payments.push_back(split);
+        }
+    }
+
+    Ok(payments)
+}
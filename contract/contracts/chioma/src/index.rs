@@ -0,0 +1,111 @@
+//! Per-party agreement indexes for the Chioma/Rental contract.
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::co_tenancy;
+use crate::storage::DataKey;
+use crate::types::{AgreementPage, AgreementStatus, PartyRole, RentAgreement};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Maximum number of index entries examined by a single list call, which
+/// also caps the page size. Each entry reads the index slot and the
+/// agreement, plus the co-tenancy for tenant queries, so this keeps a call
+/// within the transaction's read limit however narrow the status filter.
+pub const MAX_SCAN: u32 = 30;
+
+/// Returns the number of agreements ever indexed under `party` in `role`
+pub fn get_party_agreement_count(env: &Env, role: PartyRole, party: Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartyAgreementCount(role, party))
+        .unwrap_or(0)
+}
+
+/// Add `agreement_id` to `party`'s index for `role`. Indexing the same
+/// agreement twice is a no-op.
+pub(crate) fn add(env: &Env, role: PartyRole, party: &Address, agreement_id: &String) {
+    let marker = DataKey::PartyIndexed(role.clone(), party.clone(), agreement_id.clone());
+    if env.storage().persistent().has(&marker) {
+        return;
+    }
+    env.storage().persistent().set(&marker, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&marker, TTL_THRESHOLD, TTL_BUMP);
+
+    let index = get_party_agreement_count(env, role.clone(), party.clone());
+    let entry = DataKey::PartyAgreement(role.clone(), party.clone(), index);
+    env.storage().persistent().set(&entry, agreement_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&entry, TTL_THRESHOLD, TTL_BUMP);
+
+    let count_key = DataKey::PartyAgreementCount(role, party.clone());
+    env.storage().persistent().set(&count_key, &(index + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Index a newly created agreement under its landlord, tenant and agent
+pub(crate) fn add_agreement(env: &Env, agreement: &RentAgreement) {
+    let agreement_id = &agreement.agreement_id;
+    add(env, PartyRole::Landlord, &agreement.landlord, agreement_id);
+    add(env, PartyRole::Tenant, &agreement.tenant, agreement_id);
+    if let Some(agent) = &agreement.agent {
+        add(env, PartyRole::Agent, agent, agreement_id);
+    }
+}
+
+/// Whether `party` still holds `role` on the agreement. Indexes are append
+/// only, so co-tenants and agents replaced before signing are filtered here.
+fn holds_role(env: &Env, agreement: &RentAgreement, role: &PartyRole, party: &Address) -> bool {
+    match role {
+        PartyRole::Landlord => agreement.landlord == *party,
+        PartyRole::Tenant => co_tenancy::is_tenant(env, agreement, party),
+        PartyRole::Agent => agreement.agent.as_ref() == Some(party),
+    }
+}
+
+/// Returns up to `limit` of `party`'s agreements in `role`, oldest first,
+/// starting from index entry `start` and optionally only those in `status`
+pub fn list_agreements(
+    env: &Env,
+    role: PartyRole,
+    party: Address,
+    status: Option<AgreementStatus>,
+    start: u32,
+    limit: u32,
+) -> AgreementPage {
+    let mut agreements = Vec::new(env);
+    let limit = limit.min(MAX_SCAN);
+    let count = get_party_agreement_count(env, role.clone(), party.clone());
+    let scan_end = start.saturating_add(MAX_SCAN).min(count);
+
+    let mut index = start;
+    while index < scan_end && agreements.len() < limit {
+        let agreement = env
+            .storage()
+            .persistent()
+            .get::<_, String>(&DataKey::PartyAgreement(role.clone(), party.clone(), index))
+            .and_then(|agreement_id| {
+                env.storage()
+                    .persistent()
+                    .get::<_, RentAgreement>(&DataKey::Agreement(agreement_id))
+            });
+        index += 1;
+
+        if let Some(agreement) = agreement {
+            let status_matches = status.as_ref().is_none_or(|s| agreement.status == *s);
+            if status_matches && holds_role(env, &agreement, &role, &party) {
+                agreements.push_back(agreement);
+            }
+        }
+    }
+
+    AgreementPage {
+        agreements,
+        next_start: if index < count { Some(index) } else { None },
+    }
+}
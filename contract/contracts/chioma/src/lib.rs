@@ -1,24 +1,86 @@
 #![no_std]
 #![allow(clippy::too_many_arguments)]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
+mod access;
+mod addendum;
 mod agreement;
+mod co_tenancy;
 mod errors;
 mod events;
+mod governance;
+mod history;
+mod index;
+mod maintenance;
+mod negotiation;
+mod property;
+mod rent_review;
 mod storage;
 mod types;
+mod upgrade;
 
 #[cfg(test)]
 mod tests;
 
+pub use access::{
+    accept_admin, get_pending_admin, grant_role, has_role, is_paused, pause, propose_admin,
+    revoke_role, unpause,
+};
+pub use addendum::{
+    get_addendum, get_addendum_count, get_addendum_signature, list_addenda, propose_addendum,
+    sign_addendum,
+};
 pub use agreement::{
-    cancel_agreement, create_agreement, get_agreement, get_agreement_count, get_payment_split,
-    has_agreement, sign_agreement, submit_agreement, validate_agreement_params,
+    agreement_terms, cancel_agreement, compute_terms_hash, create_agreement, get_agreement,
+    get_agreement_count, get_lease_document, get_signature, get_terms_hash, has_agreement,
+    mark_disputed, resolve_dispute, set_dispute_contract, sign_agreement, submit_agreement,
+    validate_agreement_params,
+};
+pub use co_tenancy::{
+    cover_shortfall, get_co_tenancy, get_rent_period, get_rent_shortfall, get_share_payer,
+    pay_rent_share, set_co_tenants, MAX_CO_TENANTS, RENT_PERIOD,
 };
 pub use errors::RentalError;
+pub use governance::{
+    cancel_config_change, execute_config_change, get_config_change, get_config_change_count,
+    get_last_executed_change, list_config_changes, schedule_config_change, CONFIG_EXECUTION_WINDOW,
+    CONFIG_TIMELOCK,
+};
+pub use history::{get_payment_split, list_payments};
+pub use index::{get_party_agreement_count, list_agreements, MAX_SCAN};
+pub use maintenance::{
+    acknowledge_maintenance, complete_maintenance, default_sla, flag_sla_breach,
+    get_agreement_maintenance_count, get_maintenance_count, get_maintenance_request,
+    get_maintenance_sla, list_agreement_maintenance, open_maintenance_request,
+    schedule_maintenance, set_maintenance_sla,
+};
+pub use negotiation::{
+    accept_counter_offer, amend_agreement, get_counter_offer, get_revision, propose_counter_offer,
+    reject_counter_offer, withdraw_counter_offer,
+};
+pub use property::{
+    get_agreement_property, get_property_leases, get_property_registry, set_property_registry,
+    MAX_CURRENT_LEASES,
+};
+pub use rent_review::{
+    accept_rent_change, apply_rent_change, get_agreement_jurisdiction, get_rent_control,
+    get_rent_proposal, propose_rent_change, reject_rent_change, remove_rent_control,
+    set_agreement_jurisdiction, set_rent_control, withdraw_rent_change, DEFAULT_NOTICE_PERIOD,
+    INCREASE_INTERVAL,
+};
 pub use storage::DataKey;
-pub use types::{AgreementStatus, Config, ContractState, PaymentSplit, RentAgreement};
+pub use types::{
+    Addendum, AgreementPage, AgreementSignature, AgreementStatus, AgreementTerms, CoTenancy,
+    Config, ConfigChange, ConfigChangeStatus, ContractState, CounterOffer, LeaseDocument,
+    LegacyConfig, LegacyContractState, MaintenancePriority, MaintenanceRequest, MaintenanceSla,
+    MaintenanceStatus, Operation, PartyRole, PaymentSplit, RentAgreement, RentControl, RentPeriod,
+    RentProposal, RentProposalStatus, Role, SlaBreach, TenantShare, TermsChange,
+};
+pub use upgrade::{get_schema_version, migrate, upgrade, SCHEMA_VERSION};
+
+/// Maximum number of entries returned by a single list call
+pub const MAX_PAGE_SIZE: u32 = 50;
 
 #[contract]
 pub struct Contract;
@@ -63,6 +125,7 @@ impl Contract {
 
         env.storage().instance().set(&DataKey::State, &state);
         env.storage().instance().extend_ttl(500000, 500000);
+        upgrade::set_schema_version(&env, upgrade::SCHEMA_VERSION);
 
         events::contract_initialized(&env, admin, config);
 
@@ -80,197 +143,1589 @@ impl Contract {
         env.storage().instance().get(&DataKey::State)
     }
 
-    fn check_paused(env: &Env) -> Result<(), RentalError> {
-        if let Some(state) = Self::get_state(env.clone()) {
-            if state.config.paused {
-                return Err(RentalError::ContractPaused);
-            }
-        }
-        Ok(())
+    /// Replace the contract's code with a previously uploaded WASM (admin only).
+    ///
+    /// Storage is kept across the upgrade. The admin may be a multisig
+    /// account, in which case its signers authorize the upgrade together.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `new_wasm_hash` - The hash of the uploaded WASM to run from now on
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if upgraded, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), RentalError> {
+        upgrade::upgrade(&env, new_wasm_hash)
     }
 
-    /// Update contract configuration.
+    /// Bring stored data up to the schema this code expects (admin only).
+    ///
+    /// Call after `upgrade` when the new code changes the storage schema.
+    /// Does nothing if the data is already current.
     ///
     /// # Arguments
     /// * `env` - The environment
-    /// * `new_config` - The new configuration parameters
     ///
     /// # Returns
-    /// * `Result<(), RentalError>` - Ok if updated, otherwise an error
+    /// * `Result<u32, RentalError>` - The schema version after migrating
     ///
     /// # Errors
     /// * `InvalidState` - If contract state is missing
-    /// * `InvalidConfig` - If configuration values are invalid
-    pub fn update_config(env: Env, new_config: Config) -> Result<(), RentalError> {
-        let mut state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+    pub fn migrate(env: Env) -> Result<u32, RentalError> {
+        upgrade::migrate(&env)
+    }
 
-        state.admin.require_auth();
+    /// Get the schema version of the stored data.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `u32` - The stored schema version, 0 if written before versioning
+    pub fn get_schema_version(env: Env) -> u32 {
+        upgrade::get_schema_version(&env)
+    }
 
-        if new_config.fee_bps > 10_000 {
-            return Err(RentalError::InvalidConfig);
-        }
+    /// Nominate the next admin (admin only).
+    ///
+    /// Nothing changes until the nominee calls `accept_admin`. A later
+    /// nomination replaces one that has not been accepted.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `new_admin` - The address nominated to take over as admin
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if nominated, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), RentalError> {
+        access::propose_admin(&env, new_admin)
+    }
+
+    /// Take over as admin (nominee only). Granted roles are kept.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if the admin changed, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `NoPendingAdmin` - If no admin has been nominated
+    pub fn accept_admin(env: Env) -> Result<(), RentalError> {
+        access::accept_admin(&env)
+    }
 
-        let old_config = state.config.clone();
-        state.config = new_config.clone();
+    /// Get the nominated admin waiting to accept, if any.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The nominee, if there is one
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        access::get_pending_admin(&env)
+    }
 
-        env.storage().instance().set(&DataKey::State, &state);
-        env.storage().instance().extend_ttl(500000, 500000);
+    /// Grant an operational role to an account (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `role` - `Pauser`, `Guardian` or `FeeManager`
+    /// * `account` - The account receiving the role
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if granted, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `InvalidRole` - If the role is not used by this contract
+    pub fn grant_role(env: Env, role: Role, account: Address) -> Result<(), RentalError> {
+        access::grant_role(&env, role, account)
+    }
 
-        events::config_updated(&env, state.admin, old_config, new_config);
+    /// Revoke an operational role from an account (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `role` - `Pauser`, `Guardian` or `FeeManager`
+    /// * `account` - The account losing the role
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if revoked, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `InvalidRole` - If the role is not used by this contract
+    pub fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), RentalError> {
+        access::revoke_role(&env, role, account)
+    }
 
-        Ok(())
+    /// Check whether an account holds a role. The admin holds every role.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `role` - The role to check
+    /// * `account` - The account to check
+    ///
+    /// # Returns
+    /// * `bool` - Whether the account holds the role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access::has_role(&env, role, account)
+    }
+
+    /// Pause one group of operations, leaving the rest of the contract
+    /// running (admin, pauser or guardian).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The admin or an account holding `Pauser` or `Guardian`
+    /// * `operation` - The operation to pause
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if paused or already paused
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `Unauthorized` - If the caller holds none of the roles
+    pub fn pause(env: Env, caller: Address, operation: Operation) -> Result<(), RentalError> {
+        access::pause(&env, caller, operation)
+    }
+
+    /// Resume a paused operation (admin or pauser).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The admin or an account holding `Pauser`
+    /// * `operation` - The operation to resume
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if resumed or not paused
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `Unauthorized` - If the caller holds neither role
+    pub fn unpause(env: Env, caller: Address, operation: Operation) -> Result<(), RentalError> {
+        access::unpause(&env, caller, operation)
+    }
+
+    /// Check whether an operation is paused.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `operation` - The operation to check
+    ///
+    /// # Returns
+    /// * `bool` - Whether the operation is paused
+    pub fn is_paused(env: Env, operation: Operation) -> bool {
+        access::is_paused(&env, operation)
+    }
+
+    /// Queue a new platform fee and collector (admin or fee manager).
+    ///
+    /// Fee changes never apply immediately: the change can be executed once
+    /// `CONFIG_TIMELOCK` has passed, for `CONFIG_EXECUTION_WINDOW` after that,
+    /// and can be cancelled until then.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The admin or an account holding `FeeManager`
+    /// * `fee_bps` - The platform fee in basis points
+    /// * `fee_collector` - The address receiving platform fees
+    ///
+    /// # Returns
+    /// * `Result<u32, RentalError>` - The id of the scheduled change
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `Unauthorized` - If the caller holds neither role
+    /// * `InvalidConfig` - If the fee exceeds 10,000 basis points
+    pub fn schedule_config_change(
+        env: Env,
+        caller: Address,
+        fee_bps: u32,
+        fee_collector: Address,
+    ) -> Result<u32, RentalError> {
+        governance::schedule_config_change(&env, caller, fee_bps, fee_collector)
+    }
+
+    /// Cancel a scheduled fee change (admin or fee manager).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The admin or an account holding `FeeManager`
+    /// * `change_id` - The id returned by `schedule_config_change`
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if cancelled, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `Unauthorized` - If the caller holds neither role
+    /// * `ConfigChangeNotFound` - If no such change is still scheduled
+    pub fn cancel_config_change(
+        env: Env,
+        caller: Address,
+        change_id: u32,
+    ) -> Result<(), RentalError> {
+        governance::cancel_config_change(&env, caller, change_id)
+    }
+
+    /// Put a scheduled fee change into force once its timelock has passed.
+    ///
+    /// Anyone may call this, and no `Operation` pause flag gates it.
+    /// Executing a change supersedes every change scheduled before it.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `change_id` - The id returned by `schedule_config_change`
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if executed, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `ConfigChangeNotFound` - If no such change is still scheduled
+    /// * `TimelockNotElapsed` - If the change's `executable_at` has not been reached
+    /// * `ConfigChangeExpired` - If `CONFIG_EXECUTION_WINDOW` has passed since `executable_at`
+    /// * `ConfigChangeSuperseded` - If a later-scheduled change has already been executed
+    pub fn execute_config_change(env: Env, change_id: u32) -> Result<(), RentalError> {
+        governance::execute_config_change(&env, change_id)
+    }
+
+    /// Get a scheduled, executed or cancelled fee change.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `change_id` - The id of the change
+    ///
+    /// # Returns
+    /// * `Option<ConfigChange>` - The change if it exists
+    pub fn get_config_change(env: Env, change_id: u32) -> Option<ConfigChange> {
+        governance::get_config_change(&env, change_id)
+    }
+
+    /// Get how many fee changes have ever been scheduled.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `u32` - The number of changes; ids run from 0 to this count
+    pub fn get_config_change_count(env: Env) -> u32 {
+        governance::get_config_change_count(&env)
+    }
+
+    /// Get the id of the most recently executed fee change.
+    ///
+    /// Changes scheduled before it can no longer be executed.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `Option<u32>` - The change id, if any change has been executed
+    pub fn get_last_executed_change(env: Env) -> Option<u32> {
+        governance::get_last_executed_change(&env)
+    }
+
+    /// List fee changes in the order they were scheduled.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `start` - Id of the first change to return
+    /// * `limit` - Maximum number of changes to return, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * `Vec<ConfigChange>` - The requested page of changes
+    pub fn list_config_changes(env: Env, start: u32, limit: u32) -> Vec<ConfigChange> {
+        governance::list_config_changes(&env, start, limit)
     }
 
-    /// Create a new rental agreement.
+    /// Create a new rental agreement in Draft.
+    ///
+    /// Either party may draft the agreement. Nobody is bound by it until every
+    /// party has signed its terms with `sign_agreement`.
     ///
     /// # Arguments
     /// * `env` - The environment
-    /// * `agreement_id` - Unique identifier for the agreement
-    /// * `landlord` - Address of the property owner
-    /// * `tenant` - Address of the person renting the property
-    /// * `agent` - Optional address of an intermediary agent
-    /// * `monthly_rent` - The rent amount to be paid each month
-    /// * `security_deposit` - The deposit amount held for security
-    /// * `start_date` - Unix timestamp for the start of the lease
-    /// * `end_date` - Unix timestamp for the end of the lease
-    /// * `agent_commission_rate` - Commission rate for the agent in basis points
-    /// * `payment_token` - The address of the token used for payments
+    /// * `initiator` - The landlord or tenant drafting the agreement
+    /// * `terms` - The agreement id, property, parties, rent, deposit, lease
+    ///   dates, agent commission rate (in basis points) and payment token
     ///
     /// # Returns
     /// * `Result<(), RentalError>` - Ok if created, otherwise an error
-    #[allow(clippy::too_many_arguments)]
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the initiator is neither the landlord nor the tenant
+    /// * `AgreementAlreadyExists` - If the agreement id is taken
+    /// * `PropertyRegistryNotSet` - If no property registry has been registered
+    /// * `PropertyNotFound` - If the property is not in the property registry
+    /// * `PropertyNotVerified` - If the registry has not verified the property
+    /// * `PropertyOwnerMismatch` - If the landlord does not own the property
+    /// * `PropertyUnavailable` - If another lease is in force on the property for those dates
     pub fn create_agreement(
         env: Env,
-        agreement_id: String,
-        landlord: Address,
-        tenant: Address,
-        agent: Option<Address>,
-        monthly_rent: i128,
-        security_deposit: i128,
-        start_date: u64,
-        end_date: u64,
-        agent_commission_rate: u32,
-        payment_token: Address,
+        initiator: Address,
+        terms: AgreementTerms,
     ) -> Result<(), RentalError> {
-        Self::check_paused(&env)?;
-        agreement::create_agreement(
-            &env,
-            agreement_id,
-            landlord,
-            tenant,
-            agent,
-            monthly_rent,
-            security_deposit,
-            start_date,
-            end_date,
-            agent_commission_rate,
-            payment_token,
-        )
+        access::require_not_paused(&env, Operation::Agreements)?;
+        agreement::create_agreement(&env, initiator, terms)
     }
 
-    /// Sign an existing rental agreement.
+    /// Sign the terms of a Pending agreement.
+    ///
+    /// The landlord, every tenant and the guarantor, if any, each sign the
+    /// hash returned by `get_terms_hash`. The agreement becomes Active with
+    /// the last signature.
     ///
     /// # Arguments
     /// * `env` - The environment
-    /// * `tenant` - The address of the tenant signing
+    /// * `signer` - The landlord, tenant or guarantor signing
     /// * `agreement_id` - The identifier of the agreement to sign
+    /// * `terms_hash` - SHA-256 of the XDR-encoded `(AgreementTerms, Option<CoTenancy>)`
+    ///   the signer agrees to
     ///
     /// # Returns
     /// * `Result<(), RentalError>` - Ok if signed, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `NotTenant` - If the caller is not a party to the agreement
+    /// * `InvalidState` - If the agreement is not Pending or the caller already signed
+    /// * `TermsMismatch` - If the hash does not match the agreement's terms
+    /// * `Expired` - If the lease has already ended
+    /// * `PropertyUnavailable` - If another lease on the property took effect first, or
+    ///   `MAX_CURRENT_LEASES` leases on it are already running or upcoming
     pub fn sign_agreement(
         env: Env,
-        tenant: Address,
+        signer: Address,
         agreement_id: String,
+        terms_hash: BytesN<32>,
     ) -> Result<(), RentalError> {
-        Self::check_paused(&env)?;
-        agreement::sign_agreement(&env, tenant, agreement_id)
+        access::require_not_paused(&env, Operation::Agreements)?;
+        agreement::sign_agreement(&env, signer, agreement_id, terms_hash)
     }
 
-    /// Submit a draft agreement for tenant signature (Draft → Pending).
+    /// Get the hash of an agreement's current terms.
     ///
     /// # Arguments
     /// * `env` - The environment
-    /// * `landlord` - The address of the landlord submitting
-    /// * `agreement_id` - The identifier of the agreement to submit
+    /// * `agreement_id` - The identifier of the agreement
     ///
     /// # Returns
-    /// * `Result<(), RentalError>` - Ok if submitted, otherwise an error
-    pub fn submit_agreement(
+    /// * `Result<BytesN<32>, RentalError>` - The terms hash, otherwise an error
+    pub fn get_terms_hash(env: Env, agreement_id: String) -> Result<BytesN<32>, RentalError> {
+        agreement::get_terms_hash(&env, agreement_id)
+    }
+
+    /// Get a party's signature on an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `signer` - The landlord, tenant or guarantor
+    ///
+    /// # Returns
+    /// * `Option<AgreementSignature>` - The signed terms hash and time, if signed
+    pub fn get_signature(
         env: Env,
-        landlord: Address,
         agreement_id: String,
-    ) -> Result<(), RentalError> {
-        Self::check_paused(&env)?;
-        agreement::submit_agreement(&env, landlord, agreement_id)
+        signer: Address,
+    ) -> Option<AgreementSignature> {
+        agreement::get_signature(&env, agreement_id, signer)
     }
 
-    /// Cancel an agreement while in Draft or Pending state.
+    /// Submit a draft agreement for signature (Draft → Pending).
     ///
     /// # Arguments
     /// * `env` - The environment
-    /// * `caller` - The address of the caller (must be landlord)
-    /// * `agreement_id` - The identifier of the agreement to cancel
+    /// * `caller` - The landlord or tenant submitting
+    /// * `agreement_id` - The identifier of the agreement to submit
     ///
     /// # Returns
-    /// * `Result<(), RentalError>` - Ok if cancelled, otherwise an error
-    pub fn cancel_agreement(
+    /// * `Result<(), RentalError>` - Ok if submitted, otherwise an error
+    pub fn submit_agreement(
         env: Env,
         caller: Address,
         agreement_id: String,
     ) -> Result<(), RentalError> {
-        Self::check_paused(&env)?;
-        agreement::cancel_agreement(&env, caller, agreement_id)
+        access::require_not_paused(&env, Operation::Agreements)?;
+        agreement::submit_agreement(&env, caller, agreement_id)
     }
 
-    /// Retrieve details of a rental agreement.
+    /// Change the terms of a Draft agreement (landlord only).
     ///
     /// # Arguments
     /// * `env` - The environment
+    /// * `landlord` - The address of the landlord
     /// * `agreement_id` - The identifier of the agreement
+    /// * `change` - The new rent, deposit, lease dates, agent and payment token
     ///
     /// # Returns
-    /// * `Option<RentAgreement>` - The agreement details if found, otherwise None
-    pub fn get_agreement(env: Env, agreement_id: String) -> Option<RentAgreement> {
-        agreement::get_agreement(&env, agreement_id)
+    /// * `Result<u32, RentalError>` - The new revision, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `Unauthorized` - If the caller is not the landlord
+    /// * `InvalidState` - If the agreement is not a Draft
+    /// * `InvalidAmount`, `InvalidDate`, `InvalidCommissionRate` - If the new terms are invalid
+    pub fn amend_agreement(
+        env: Env,
+        landlord: Address,
+        agreement_id: String,
+        change: TermsChange,
+    ) -> Result<u32, RentalError> {
+        access::require_not_paused(&env, Operation::Agreements)?;
+        negotiation::amend_agreement(&env, landlord, agreement_id, change)
     }
 
-    /// Check if an agreement exists for a given ID.
+    /// Propose different terms for a Pending agreement (any tenant).
     ///
     /// # Arguments
     /// * `env` - The environment
+    /// * `tenant` - The tenant making the offer
     /// * `agreement_id` - The identifier of the agreement
+    /// * `change` - The terms the tenant would sign instead
     ///
     /// # Returns
-    /// * `bool` - True if the agreement exists, False otherwise
-    pub fn has_agreement(env: Env, agreement_id: String) -> bool {
-        agreement::has_agreement(&env, agreement_id)
+    /// * `Result<(), RentalError>` - Ok if proposed, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `NotTenant` - If the caller is not a tenant on the agreement
+    /// * `InvalidState` - If the agreement is not Pending
+    /// * `CounterOfferPending` - If a counter-offer is already open
+    /// * `InvalidAmount`, `InvalidDate`, `InvalidCommissionRate` - If the terms are invalid
+    pub fn propose_counter_offer(
+        env: Env,
+        tenant: Address,
+        agreement_id: String,
+        change: TermsChange,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::Agreements)?;
+        negotiation::propose_counter_offer(&env, tenant, agreement_id, change)
     }
 
-    /// Get the total number of agreements created.
+    /// Accept the open counter-offer (landlord only).
+    ///
+    /// The new terms replace the old ones and every signature is cleared, so
+    /// all parties must sign again.
     ///
     /// # Arguments
     /// * `env` - The environment
+    /// * `landlord` - The address of the landlord
+    /// * `agreement_id` - The identifier of the agreement
     ///
     /// # Returns
-    /// * `u32` - The count of agreements
-    pub fn get_agreement_count(env: Env) -> u32 {
-        agreement::get_agreement_count(&env)
+    /// * `Result<u32, RentalError>` - The new revision, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `Unauthorized` - If the caller is not the landlord
+    /// * `CounterOfferNotFound` - If no counter-offer is open
+    /// * `InvalidState` - If the agreement is no longer Pending
+    /// * `TermsMismatch` - If the terms have changed since the offer was made
+    pub fn accept_counter_offer(
+        env: Env,
+        landlord: Address,
+        agreement_id: String,
+    ) -> Result<u32, RentalError> {
+        access::require_not_paused(&env, Operation::Agreements)?;
+        negotiation::accept_counter_offer(&env, landlord, agreement_id)
     }
 
-    /// Get the payment split details for a specific month of an agreement.
+    /// Reject the open counter-offer, keeping the current terms (landlord only).
     ///
     /// # Arguments
     /// * `env` - The environment
+    /// * `landlord` - The address of the landlord
     /// * `agreement_id` - The identifier of the agreement
-    /// * `month` - The month number to calculate splitting for
     ///
     /// # Returns
-    /// * `Result<PaymentSplit, RentalError>` - The split details if successful, otherwise an error
-    pub fn get_payment_split(
+    /// * `Result<(), RentalError>` - Ok if rejected, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `Unauthorized` - If the caller is not the landlord
+    /// * `CounterOfferNotFound` - If no counter-offer is open
+    pub fn reject_counter_offer(
         env: Env,
+        landlord: Address,
         agreement_id: String,
-        month: u32,
-    ) -> Result<PaymentSplit, RentalError> {
-        agreement::get_payment_split(&env, agreement_id, month)
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::Agreements)?;
+        negotiation::reject_counter_offer(&env, landlord, agreement_id)
+    }
+
+    /// Withdraw a counter-offer the landlord has not answered.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `tenant` - The tenant who made the offer
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if withdrawn, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `CounterOfferNotFound` - If no counter-offer is open
+    /// * `Unauthorized` - If the caller did not make the offer
+    pub fn withdraw_counter_offer(
+        env: Env,
+        tenant: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::Agreements)?;
+        negotiation::withdraw_counter_offer(&env, tenant, agreement_id)
+    }
+
+    /// Get the open counter-offer on an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Option<CounterOffer>` - The offer if one is awaiting the landlord
+    pub fn get_counter_offer(env: Env, agreement_id: String) -> Option<CounterOffer> {
+        negotiation::get_counter_offer(&env, agreement_id)
+    }
+
+    /// Get how many times an agreement's terms have changed since creation.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `u32` - The revision, 0 for terms that were never amended
+    pub fn get_revision(env: Env, agreement_id: String) -> u32 {
+        negotiation::get_revision(&env, agreement_id)
+    }
+
+    /// Append an addendum, such as a pet or parking clause, to an Active agreement.
+    ///
+    /// The proposer signs it on submission. It is in force once every party who
+    /// signed the agreement has also signed it with `sign_addendum`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `proposer` - The landlord, tenant or guarantor proposing it
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `document_hash` - SHA-256 of the addendum document
+    /// * `document_cid` - Optional IPFS CID of the addendum document
+    ///
+    /// # Returns
+    /// * `Result<u32, RentalError>` - The addendum's index, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `Unauthorized` - If the caller is not a party to the agreement
+    /// * `AgreementNotActive` - If the agreement is not Active
+    pub fn propose_addendum(
+        env: Env,
+        proposer: Address,
+        agreement_id: String,
+        document_hash: BytesN<32>,
+        document_cid: Option<String>,
+    ) -> Result<u32, RentalError> {
+        access::require_not_paused(&env, Operation::Agreements)?;
+        addendum::propose_addendum(&env, proposer, agreement_id, document_hash, document_cid)
+    }
+
+    /// Sign an addendum to an Active agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `signer` - The landlord, tenant or guarantor signing
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `index` - The addendum's index
+    /// * `document_hash` - SHA-256 of the addendum document the signer agrees to
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if signed, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `AddendumNotFound` - If the agreement has no addendum at `index`
+    /// * `Unauthorized` - If the caller is not a party to the agreement
+    /// * `AgreementNotActive` - If the agreement is not Active
+    /// * `InvalidState` - If the caller already signed the addendum
+    /// * `TermsMismatch` - If the hash does not match the addendum's document
+    pub fn sign_addendum(
+        env: Env,
+        signer: Address,
+        agreement_id: String,
+        index: u32,
+        document_hash: BytesN<32>,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::Agreements)?;
+        addendum::sign_addendum(&env, signer, agreement_id, index, document_hash)
+    }
+
+    /// Get an addendum to an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `index` - The addendum's index
+    ///
+    /// # Returns
+    /// * `Option<Addendum>` - The addendum if it exists
+    pub fn get_addendum(env: Env, agreement_id: String, index: u32) -> Option<Addendum> {
+        addendum::get_addendum(&env, agreement_id, index)
+    }
+
+    /// Get the number of addenda proposed on an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `u32` - The addendum count
+    pub fn get_addendum_count(env: Env, agreement_id: String) -> u32 {
+        addendum::get_addendum_count(&env, agreement_id)
+    }
+
+    /// Get when a party signed an addendum.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `index` - The addendum's index
+    /// * `signer` - The landlord, tenant or guarantor
+    ///
+    /// # Returns
+    /// * `Option<u64>` - The signing time, if they have signed
+    pub fn get_addendum_signature(
+        env: Env,
+        agreement_id: String,
+        index: u32,
+        signer: Address,
+    ) -> Option<u64> {
+        addendum::get_addendum_signature(&env, agreement_id, index, signer)
+    }
+
+    /// List an agreement's addenda, oldest first.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `start` - Index of the first addendum to return
+    /// * `limit` - Maximum number of addenda to return, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * `Vec<Addendum>` - The requested page of addenda
+    pub fn list_addenda(env: Env, agreement_id: String, start: u32, limit: u32) -> Vec<Addendum> {
+        addendum::list_addenda(&env, agreement_id, start, limit)
+    }
+
+    /// Cancel an agreement while in Draft or Pending state.
+    ///
+    /// Either the landlord or the tenant may cancel, whoever drafted it.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `caller` - The landlord or tenant on the agreement
+    /// * `agreement_id` - The identifier of the agreement to cancel
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if cancelled, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `Unauthorized` - If the caller is neither the landlord nor the tenant
+    /// * `InvalidState` - If the agreement is not in Draft or Pending
+    pub fn cancel_agreement(
+        env: Env,
+        caller: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::Agreements)?;
+        agreement::cancel_agreement(&env, caller, agreement_id)
+    }
+
+    /// Register the dispute resolution contract (admin only).
+    ///
+    /// Only the registered contract may move agreements into and out of the
+    /// `Disputed` state.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `dispute_contract` - Address of the dispute resolution contract
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if registered, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    pub fn set_dispute_contract(env: Env, dispute_contract: Address) -> Result<(), RentalError> {
+        agreement::set_dispute_contract(&env, dispute_contract)
+    }
+
+    /// Get the registered dispute resolution contract, if any.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The dispute contract address if registered
+    pub fn get_dispute_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::DisputeContract)
+    }
+
+    /// Register the property registry agreements are checked against (admin only).
+    ///
+    /// Until one is registered, `create_agreement` refuses every agreement with
+    /// `PropertyRegistryNotSet`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `property_registry` - The address of the property registry contract
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if registered, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If the contract has not been initialized
+    pub fn set_property_registry(env: Env, property_registry: Address) -> Result<(), RentalError> {
+        property::set_property_registry(&env, property_registry)
+    }
+
+    /// Get the registered property registry contract, if any.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The property registry address if registered
+    pub fn get_property_registry(env: Env) -> Option<Address> {
+        property::get_property_registry(&env)
+    }
+
+    /// Get the leases on a property that had not ended when the latest one
+    /// took effect.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `property_id` - The identifier of the property
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Agreement ids in the order they became Active
+    pub fn get_property_leases(env: Env, property_id: String) -> Vec<String> {
+        property::get_property_leases(&env, property_id)
+    }
+
+    /// Get the property an agreement lets.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Option<String>` - The property id, or None for agreements created
+    ///   before properties were tracked
+    pub fn get_agreement_property(env: Env, agreement_id: String) -> Option<String> {
+        property::get_agreement_property(&env, agreement_id)
+    }
+
+    /// Mark an Active agreement as Disputed (dispute contract only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement in dispute
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if marked, otherwise an error
+    ///
+    /// # Errors
+    /// * `DisputeContractNotSet` - If no dispute contract has been registered
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `AgreementNotActive` - If the agreement is not Active
+    pub fn mark_disputed(env: Env, agreement_id: String) -> Result<(), RentalError> {
+        agreement::mark_disputed(&env, agreement_id)
+    }
+
+    /// Close the dispute on an agreement (dispute contract only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement in dispute
+    /// * `terminate` - True to terminate the agreement, false to restore it to Active
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if resolved, otherwise an error
+    ///
+    /// # Errors
+    /// * `DisputeContractNotSet` - If no dispute contract has been registered
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `InvalidState` - If the agreement is not Disputed
+    pub fn resolve_dispute(
+        env: Env,
+        agreement_id: String,
+        terminate: bool,
+    ) -> Result<(), RentalError> {
+        agreement::resolve_dispute(&env, agreement_id, terminate)
+    }
+
+    /// Retrieve details of a rental agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Option<RentAgreement>` - The agreement details if found, otherwise None
+    pub fn get_agreement(env: Env, agreement_id: String) -> Option<RentAgreement> {
+        agreement::get_agreement(&env, agreement_id)
+    }
+
+    /// Get the lease document an agreement's parties sign.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Option<LeaseDocument>` - The document hash and CID, or None for
+    ///   agreements created before documents were anchored
+    pub fn get_lease_document(env: Env, agreement_id: String) -> Option<LeaseDocument> {
+        agreement::get_lease_document(&env, agreement_id)
+    }
+
+    /// Check if an agreement exists for a given ID.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `bool` - True if the agreement exists, False otherwise
+    pub fn has_agreement(env: Env, agreement_id: String) -> bool {
+        agreement::has_agreement(&env, agreement_id)
+    }
+
+    /// Get the total number of agreements created.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `u32` - The count of agreements
+    pub fn get_agreement_count(env: Env) -> u32 {
+        agreement::get_agreement_count(&env)
+    }
+
+    /// List agreements where an address is the landlord.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The landlord's address
+    /// * `status` - Only return agreements in this status, if given
+    /// * `start` - Index entry to resume from; 0 for the first page
+    /// * `limit` - Maximum number of agreements to return, capped at `MAX_SCAN`
+    ///
+    /// # Returns
+    /// * `AgreementPage` - The agreements, oldest first, and where the next page starts
+    pub fn list_agreements_by_landlord(
+        env: Env,
+        landlord: Address,
+        status: Option<AgreementStatus>,
+        start: u32,
+        limit: u32,
+    ) -> AgreementPage {
+        index::list_agreements(&env, PartyRole::Landlord, landlord, status, start, limit)
+    }
+
+    /// List agreements where an address is the lead tenant or a co-tenant.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `tenant` - The tenant's address
+    /// * `status` - Only return agreements in this status, if given
+    /// * `start` - Index entry to resume from; 0 for the first page
+    /// * `limit` - Maximum number of agreements to return, capped at `MAX_SCAN`
+    ///
+    /// # Returns
+    /// * `AgreementPage` - The agreements, oldest first, and where the next page starts
+    pub fn list_agreements_by_tenant(
+        env: Env,
+        tenant: Address,
+        status: Option<AgreementStatus>,
+        start: u32,
+        limit: u32,
+    ) -> AgreementPage {
+        index::list_agreements(&env, PartyRole::Tenant, tenant, status, start, limit)
+    }
+
+    /// List agreements where an address is the agent.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agent` - The agent's address
+    /// * `status` - Only return agreements in this status, if given
+    /// * `start` - Index entry to resume from; 0 for the first page
+    /// * `limit` - Maximum number of agreements to return, capped at `MAX_SCAN`
+    ///
+    /// # Returns
+    /// * `AgreementPage` - The agreements, oldest first, and where the next page starts
+    pub fn list_agreements_by_agent(
+        env: Env,
+        agent: Address,
+        status: Option<AgreementStatus>,
+        start: u32,
+        limit: u32,
+    ) -> AgreementPage {
+        index::list_agreements(&env, PartyRole::Agent, agent, status, start, limit)
+    }
+
+    /// Get the payment split details for a specific month of an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `month` - The month number to calculate splitting for
+    ///
+    /// # Returns
+    /// * `Result<PaymentSplit, RentalError>` - The split details if successful, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `PaymentNotFound` - If no payment was recorded for that month
+    pub fn get_payment_split(
+        env: Env,
+        agreement_id: String,
+        month: u32,
+    ) -> Result<PaymentSplit, RentalError> {
+        history::get_payment_split(&env, agreement_id, month)
+    }
+
+    /// List an agreement's completed rent payments, oldest first.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `start` - Rent period of the first payment to return
+    /// * `limit` - Maximum number of payments to return, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * `Result<Vec<PaymentSplit>, RentalError>` - The requested page of payments
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement does not exist
+    pub fn list_payments(
+        env: Env,
+        agreement_id: String,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<PaymentSplit>, RentalError> {
+        history::list_payments(&env, agreement_id, start, limit)
+    }
+
+    /// Set the response times a landlord owes requests of a priority (admin only).
+    ///
+    /// Only requests opened afterwards use the new times.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `priority` - The priority the SLA applies to
+    /// * `sla` - Seconds allowed to acknowledge and to complete a request
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if set, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `InvalidConfig` - If acknowledgement time is zero or exceeds completion time
+    pub fn set_maintenance_sla(
+        env: Env,
+        priority: MaintenancePriority,
+        sla: MaintenanceSla,
+    ) -> Result<(), RentalError> {
+        maintenance::set_maintenance_sla(&env, priority, sla)
+    }
+
+    /// Get the response times that apply to new requests of a priority.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `priority` - The priority to look up
+    ///
+    /// # Returns
+    /// * `MaintenanceSla` - The configured SLA, or the default for the priority
+    pub fn get_maintenance_sla(env: Env, priority: MaintenancePriority) -> MaintenanceSla {
+        maintenance::get_maintenance_sla(&env, priority)
+    }
+
+    /// Open a maintenance request against an agreement (tenant only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `tenant` - The address of the tenant opening the request
+    /// * `agreement_id` - The identifier of an Active or Disputed agreement
+    /// * `description_hash` - Hash reference to the off-chain description (IPFS, etc.)
+    /// * `priority` - How urgent the repair is
+    ///
+    /// # Returns
+    /// * `Result<u32, RentalError>` - The new request id if opened, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `NotTenant` - If the caller is not the agreement's tenant
+    /// * `AgreementNotActive` - If the agreement is neither Active nor Disputed
+    /// * `InvalidMaintenanceRequest` - If the description hash is empty
+    pub fn open_maintenance_request(
+        env: Env,
+        tenant: Address,
+        agreement_id: String,
+        description_hash: String,
+        priority: MaintenancePriority,
+    ) -> Result<u32, RentalError> {
+        access::require_not_paused(&env, Operation::Maintenance)?;
+        maintenance::open_maintenance_request(
+            &env,
+            tenant,
+            agreement_id,
+            description_hash,
+            priority,
+        )
+    }
+
+    /// Acknowledge an open maintenance request (landlord only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The address of the landlord
+    /// * `request_id` - The identifier of the request
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if acknowledged, otherwise an error
+    ///
+    /// # Errors
+    /// * `MaintenanceRequestNotFound` - If the request doesn't exist
+    /// * `Unauthorized` - If the caller is not the request's landlord
+    /// * `InvalidState` - If the request is not Open
+    pub fn acknowledge_maintenance(
+        env: Env,
+        landlord: Address,
+        request_id: u32,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::Maintenance)?;
+        maintenance::acknowledge_maintenance(&env, landlord, request_id)
+    }
+
+    /// Schedule or reschedule the work on a maintenance request (landlord only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The address of the landlord
+    /// * `request_id` - The identifier of the request
+    /// * `scheduled_for` - Unix timestamp the work is planned for
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if scheduled, otherwise an error
+    ///
+    /// # Errors
+    /// * `MaintenanceRequestNotFound` - If the request doesn't exist
+    /// * `Unauthorized` - If the caller is not the request's landlord
+    /// * `InvalidState` - If the request is not Acknowledged or Scheduled
+    /// * `InvalidDate` - If `scheduled_for` is in the past
+    pub fn schedule_maintenance(
+        env: Env,
+        landlord: Address,
+        request_id: u32,
+        scheduled_for: u64,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::Maintenance)?;
+        maintenance::schedule_maintenance(&env, landlord, request_id, scheduled_for)
+    }
+
+    /// Mark a maintenance request as completed (landlord only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The address of the landlord
+    /// * `request_id` - The identifier of the request
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if completed, otherwise an error
+    ///
+    /// # Errors
+    /// * `MaintenanceRequestNotFound` - If the request doesn't exist
+    /// * `Unauthorized` - If the caller is not the request's landlord
+    /// * `InvalidState` - If the request is not Acknowledged or Scheduled
+    pub fn complete_maintenance(
+        env: Env,
+        landlord: Address,
+        request_id: u32,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::Maintenance)?;
+        maintenance::complete_maintenance(&env, landlord, request_id)
+    }
+
+    /// Record a missed SLA deadline on a maintenance request.
+    ///
+    /// Breaches are also recorded automatically when the landlord acts late.
+    /// This lets anyone flag a request the landlord has not answered at all.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `request_id` - The identifier of the request
+    ///
+    /// # Returns
+    /// * `Result<SlaBreach, RentalError>` - The latest deadline newly recorded, otherwise an error
+    ///
+    /// # Errors
+    /// * `MaintenanceRequestNotFound` - If the request doesn't exist
+    /// * `SlaNotBreached` - If no deadline has passed or every missed one is already recorded
+    pub fn flag_sla_breach(env: Env, request_id: u32) -> Result<SlaBreach, RentalError> {
+        maintenance::flag_sla_breach(&env, request_id)
+    }
+
+    /// Retrieve a maintenance request.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `request_id` - The identifier of the request
+    ///
+    /// # Returns
+    /// * `Option<MaintenanceRequest>` - The request if found, otherwise None
+    pub fn get_maintenance_request(env: Env, request_id: u32) -> Option<MaintenanceRequest> {
+        maintenance::get_maintenance_request(&env, request_id)
+    }
+
+    /// Get the total number of maintenance requests opened.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    ///
+    /// # Returns
+    /// * `u32` - The count of maintenance requests
+    pub fn get_maintenance_count(env: Env) -> u32 {
+        maintenance::get_maintenance_count(&env)
+    }
+
+    /// Get the number of maintenance requests opened against an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `u32` - The count of the agreement's maintenance requests
+    pub fn get_agreement_maintenance_count(env: Env, agreement_id: String) -> u32 {
+        maintenance::get_agreement_maintenance_count(&env, agreement_id)
+    }
+
+    /// List an agreement's maintenance requests, oldest first.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `start` - Index of the first request to return
+    /// * `limit` - Maximum number of requests to return, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * `Vec<MaintenanceRequest>` - The requests in the page
+    pub fn list_agreement_maintenance(
+        env: Env,
+        agreement_id: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<MaintenanceRequest> {
+        maintenance::list_agreement_maintenance(&env, agreement_id, start, limit)
+    }
+
+    /// Set the rent control rules for a jurisdiction (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `jurisdiction` - Identifier of the city or region
+    /// * `control` - Maximum annual increase and required notice
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if set, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `InvalidConfig` - If the jurisdiction is empty or the notice period is zero
+    pub fn set_rent_control(
+        env: Env,
+        jurisdiction: String,
+        control: RentControl,
+    ) -> Result<(), RentalError> {
+        rent_review::set_rent_control(&env, jurisdiction, control)
+    }
+
+    /// Lift rent control in a jurisdiction (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `jurisdiction` - Identifier of the city or region
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if removed, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    pub fn remove_rent_control(env: Env, jurisdiction: String) -> Result<(), RentalError> {
+        rent_review::remove_rent_control(&env, jurisdiction)
+    }
+
+    /// Get the rent control rules for a jurisdiction.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `jurisdiction` - Identifier of the city or region
+    ///
+    /// # Returns
+    /// * `Option<RentControl>` - The rules if the jurisdiction is rent controlled
+    pub fn get_rent_control(env: Env, jurisdiction: String) -> Option<RentControl> {
+        rent_review::get_rent_control(&env, jurisdiction)
+    }
+
+    /// Record the jurisdiction of an agreement's property (admin only).
+    ///
+    /// The jurisdiction selects the rent control rules, so the landlord
+    /// cannot set it.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `jurisdiction` - Identifier of the city or region
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if recorded, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `InvalidConfig` - If the jurisdiction is empty
+    pub fn set_agreement_jurisdiction(
+        env: Env,
+        agreement_id: String,
+        jurisdiction: String,
+    ) -> Result<(), RentalError> {
+        rent_review::set_agreement_jurisdiction(&env, agreement_id, jurisdiction)
+    }
+
+    /// Get the jurisdiction recorded for an agreement, if any.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Option<String>` - The jurisdiction if recorded
+    pub fn get_agreement_jurisdiction(env: Env, agreement_id: String) -> Option<String> {
+        rent_review::get_agreement_jurisdiction(&env, agreement_id)
+    }
+
+    /// Propose a new monthly rent for an Active agreement (landlord only).
+    ///
+    /// The new rent must take effect after the required notice and before the
+    /// lease ends. In a rent-controlled jurisdiction an increase may not exceed
+    /// the cap, and capped increases take effect at most once every 12 months.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The address of the landlord
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `proposed_rent` - The new monthly rent
+    /// * `effective_from` - Unix timestamp from which the new rent applies
+    /// * `terminate_on_reject` - End the lease at `effective_from` if the tenant rejects
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if proposed, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `Unauthorized` - If the caller is not the landlord
+    /// * `AgreementNotActive` - If the agreement is not Active
+    /// * `RentProposalPending` - If a previous proposal is awaiting an answer or has not been applied
+    /// * `InvalidAmount` - If the rent is not positive or unchanged
+    /// * `InvalidDate` - If notice is too short or the date is past the lease end
+    /// * `RentIncreaseExceedsCap` - If the increase exceeds the jurisdiction's cap
+    /// * `RentIncreaseTooSoon` - If the last increase took effect less than 12 months earlier
+    pub fn propose_rent_change(
+        env: Env,
+        landlord: Address,
+        agreement_id: String,
+        proposed_rent: i128,
+        effective_from: u64,
+        terminate_on_reject: bool,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::RentChanges)?;
+        rent_review::propose_rent_change(
+            &env,
+            landlord,
+            agreement_id,
+            proposed_rent,
+            effective_from,
+            terminate_on_reject,
+        )
+    }
+
+    /// Accept the pending rent proposal (tenants only).
+    ///
+    /// On a shared agreement the proposal is only accepted once every tenant
+    /// has accepted it.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `tenant` - The address of the tenant or a co-tenant
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if accepted, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `NotTenant` - If the caller is not one of the tenants
+    /// * `RentProposalNotFound` - If no proposal is awaiting an answer
+    /// * `Expired` - If the proposal's effective date has passed
+    /// * `InvalidState` - If the caller has already accepted
+    pub fn accept_rent_change(
+        env: Env,
+        tenant: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::RentChanges)?;
+        rent_review::accept_rent_change(&env, tenant, agreement_id)
+    }
+
+    /// Reject the pending rent proposal (any one of the tenants).
+    ///
+    /// If the landlord proposed with `terminate_on_reject`, the lease end date
+    /// is brought forward to the proposal's effective date.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `tenant` - The address of the tenant or a co-tenant
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if rejected, otherwise an error
+    ///
+    /// # Errors
+    /// * Same as `accept_rent_change`
+    pub fn reject_rent_change(
+        env: Env,
+        tenant: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::RentChanges)?;
+        rent_review::reject_rent_change(&env, tenant, agreement_id)
+    }
+
+    /// Withdraw a rent proposal the tenant has not answered (landlord only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The address of the landlord
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if withdrawn, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `Unauthorized` - If the caller is not the landlord
+    /// * `RentProposalNotFound` - If no proposal is awaiting an answer
+    pub fn withdraw_rent_change(
+        env: Env,
+        landlord: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::RentChanges)?;
+        rent_review::withdraw_rent_change(&env, landlord, agreement_id)
+    }
+
+    /// Put an accepted rent into force once its effective date has passed.
+    ///
+    /// Anyone may call this.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if applied, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `RentProposalNotFound` - If no accepted proposal is waiting to apply
+    /// * `InvalidDate` - If the effective date has not been reached
+    /// * `AgreementNotActive` - If the agreement is not Active
+    pub fn apply_rent_change(env: Env, agreement_id: String) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::RentChanges)?;
+        rent_review::apply_rent_change(&env, agreement_id)
+    }
+
+    /// Get the latest rent proposal made on an agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Option<RentProposal>` - The proposal if one has been made
+    pub fn get_rent_proposal(env: Env, agreement_id: String) -> Option<RentProposal> {
+        rent_review::get_rent_proposal(&env, agreement_id)
+    }
+
+    /// Name the tenants sharing a Draft agreement (landlord only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `landlord` - The address of the landlord
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `tenants` - Every tenant with their share of the rent in basis points,
+    ///   including the agreement's lead tenant
+    /// * `guarantor` - Optional address liable for unpaid shares
+    ///
+    /// # Returns
+    /// * `Result<(), RentalError>` - Ok if set, otherwise an error
+    ///
+    /// # Errors
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `Unauthorized` - If the caller is not the landlord
+    /// * `InvalidState` - If the agreement is not a Draft
+    /// * `InvalidShares` - If shares are zero, duplicated, don't total 10,000 bps,
+    ///   omit the lead tenant, exceed `MAX_CO_TENANTS`, or the guarantor is a party
+    pub fn set_co_tenants(
+        env: Env,
+        landlord: Address,
+        agreement_id: String,
+        tenants: Vec<TenantShare>,
+        guarantor: Option<Address>,
+    ) -> Result<(), RentalError> {
+        access::require_not_paused(&env, Operation::Agreements)?;
+        co_tenancy::set_co_tenants(&env, landlord, agreement_id, tenants, guarantor)
+    }
+
+    /// Get the co-tenants and guarantor of a shared agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Option<CoTenancy>` - The co-tenancy, or None for a sole tenant
+    pub fn get_co_tenancy(env: Env, agreement_id: String) -> Option<CoTenancy> {
+        co_tenancy::get_co_tenancy(&env, agreement_id)
+    }
+
+    /// Pay the caller's share of the current rent period.
+    ///
+    /// The platform fee is deducted from the share and sent to the fee
+    /// collector; the rest goes to the landlord. The period closes once every
+    /// share is paid. Shares are worked out from the rent in force when the
+    /// period's first share was paid.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `tenant` - The tenant paying their share
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<u32, RentalError>` - The period paid for, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `AgreementNotActive` - If the agreement is not Active
+    /// * `Expired` - If every period up to the end of the lease has been paid
+    /// * `NotTenant` - If the caller is not one of the tenants
+    /// * `ShareAlreadyPaid` - If the caller's share of the period is already paid
+    /// * `PaymentNotDue` - If the previous period has not fallen due yet
+    pub fn pay_rent_share(
+        env: Env,
+        tenant: Address,
+        agreement_id: String,
+    ) -> Result<u32, RentalError> {
+        access::require_not_paused(&env, Operation::Payments)?;
+        co_tenancy::pay_rent_share(&env, tenant, agreement_id)
+    }
+
+    /// Pay every share still outstanding once the current period is overdue
+    /// (guarantor only).
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `guarantor` - The agreement's guarantor
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<i128, RentalError>` - The amount covered, otherwise an error
+    ///
+    /// # Errors
+    /// * `InvalidState` - If contract state is missing
+    /// * `AgreementNotFound` - If the agreement doesn't exist
+    /// * `AgreementNotActive` - If the agreement is not Active
+    /// * `Expired` - If every period up to the end of the lease has been paid
+    /// * `Unauthorized` - If the caller is not the guarantor
+    /// * `NoShortfall` - If the period is not overdue or every share is paid
+    pub fn cover_shortfall(
+        env: Env,
+        guarantor: Address,
+        agreement_id: String,
+    ) -> Result<i128, RentalError> {
+        access::require_not_paused(&env, Operation::Payments)?;
+        co_tenancy::cover_shortfall(&env, guarantor, agreement_id)
+    }
+
+    /// Get the amount still unpaid on the current rent period, or zero once
+    /// every period of the lease has been paid.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    ///
+    /// # Returns
+    /// * `Result<i128, RentalError>` - The unpaid amount, otherwise an error
+    pub fn get_rent_shortfall(env: Env, agreement_id: String) -> Result<i128, RentalError> {
+        co_tenancy::get_rent_shortfall(&env, agreement_id)
+    }
+
+    /// Get the running totals for a rent period.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `period` - The period number, starting at 0
+    ///
+    /// # Returns
+    /// * `Option<RentPeriod>` - The totals if anything has been paid
+    pub fn get_rent_period(env: Env, agreement_id: String, period: u32) -> Option<RentPeriod> {
+        co_tenancy::get_rent_period(&env, agreement_id, period)
+    }
+
+    /// Get who paid a tenant's share of a rent period.
+    ///
+    /// # Arguments
+    /// * `env` - The environment
+    /// * `agreement_id` - The identifier of the agreement
+    /// * `period` - The period number, starting at 0
+    /// * `tenant` - The tenant whose share to look up
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The tenant or guarantor who paid, if paid
+    pub fn get_share_payer(
+        env: Env,
+        agreement_id: String,
+        period: u32,
+        tenant: Address,
+    ) -> Option<Address> {
+        co_tenancy::get_share_payer(&env, agreement_id, period, tenant)
     }
 }
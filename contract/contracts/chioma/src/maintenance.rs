@@ -0,0 +1,363 @@
+//! Maintenance request tracking for the Chioma/Rental contract.
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::co_tenancy;
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{
+    AgreementStatus, ContractState, MaintenancePriority, MaintenanceRequest, MaintenanceSla,
+    MaintenanceStatus, RentAgreement, SlaBreach,
+};
+use crate::MAX_PAGE_SIZE;
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+const DAY: u64 = 86400;
+
+/// Default response times for priorities without an admin-configured SLA
+pub fn default_sla(priority: &MaintenancePriority) -> MaintenanceSla {
+    let (acknowledge_days, complete_days) = match priority {
+        MaintenancePriority::Emergency => (1, 3),
+        MaintenancePriority::High => (2, 7),
+        MaintenancePriority::Medium => (3, 14),
+        MaintenancePriority::Low => (7, 30),
+    };
+
+    MaintenanceSla {
+        acknowledge_within: acknowledge_days * DAY,
+        complete_within: complete_days * DAY,
+    }
+}
+
+/// Set the response times owed for a priority (admin only)
+pub fn set_maintenance_sla(
+    env: &Env,
+    priority: MaintenancePriority,
+    sla: MaintenanceSla,
+) -> Result<(), RentalError> {
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(RentalError::InvalidState)?;
+
+    state.admin.require_auth();
+
+    // Work cannot be due before the request has to be acknowledged
+    if sla.acknowledge_within == 0 || sla.complete_within < sla.acknowledge_within {
+        return Err(RentalError::InvalidConfig);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::MaintenanceSla(priority.clone()), &sla);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+    events::maintenance_sla_set(env, state.admin, priority, sla);
+
+    Ok(())
+}
+
+/// Returns the response times that apply to new requests of a priority
+pub fn get_maintenance_sla(env: &Env, priority: MaintenancePriority) -> MaintenanceSla {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaintenanceSla(priority.clone()))
+        .unwrap_or_else(|| default_sla(&priority))
+}
+
+/// Open a maintenance request against an Active or Disputed agreement
+pub fn open_maintenance_request(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+    description_hash: String,
+    priority: MaintenancePriority,
+) -> Result<u32, RentalError> {
+    tenant.require_auth();
+
+    let agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)?;
+
+    // Any co-tenant may report a repair
+    if !co_tenancy::is_tenant(env, &agreement, &tenant) {
+        return Err(RentalError::NotTenant);
+    }
+
+    // Repairs are still owed while a dispute is open
+    if agreement.status != AgreementStatus::Active && agreement.status != AgreementStatus::Disputed
+    {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    if description_hash.is_empty() {
+        return Err(RentalError::InvalidMaintenanceRequest);
+    }
+
+    let now = env.ledger().timestamp();
+    let sla = get_maintenance_sla(env, priority.clone());
+    let request_id = get_maintenance_count(env) + 1;
+
+    let request = MaintenanceRequest {
+        id: request_id,
+        agreement_id: agreement_id.clone(),
+        tenant: tenant.clone(),
+        landlord: agreement.landlord.clone(),
+        description_hash: description_hash.clone(),
+        priority: priority.clone(),
+        status: MaintenanceStatus::Open,
+        opened_at: now,
+        acknowledge_by: now + sla.acknowledge_within,
+        complete_by: now + sla.complete_within,
+        acknowledged_at: None,
+        scheduled_for: None,
+        completed_at: None,
+        acknowledgement_breached_at: None,
+        completion_breached_at: None,
+    };
+    save_request(env, &request);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::MaintenanceCount, &request_id);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+    let index = get_agreement_maintenance_count(env, agreement_id.clone());
+    let index_key = DataKey::AgreementMaintenance(agreement_id.clone(), index);
+    env.storage().persistent().set(&index_key, &request_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&index_key, TTL_THRESHOLD, TTL_BUMP);
+
+    let count_key = DataKey::AgreementMaintenanceCount(agreement_id.clone());
+    env.storage().persistent().set(&count_key, &(index + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, TTL_THRESHOLD, TTL_BUMP);
+
+    events::maintenance_opened(
+        env,
+        request_id,
+        agreement_id,
+        tenant,
+        agreement.landlord,
+        priority,
+        description_hash,
+    );
+
+    Ok(request_id)
+}
+
+/// Acknowledge an open request (landlord only)
+pub fn acknowledge_maintenance(
+    env: &Env,
+    landlord: Address,
+    request_id: u32,
+) -> Result<(), RentalError> {
+    let mut request = load_for_landlord(env, &landlord, request_id)?;
+
+    if request.status != MaintenanceStatus::Open {
+        return Err(RentalError::InvalidState);
+    }
+
+    let now = env.ledger().timestamp();
+    record_breach(env, &mut request, now);
+
+    request.status = MaintenanceStatus::Acknowledged;
+    request.acknowledged_at = Some(now);
+    save_request(env, &request);
+
+    events::maintenance_acknowledged(env, request_id, landlord, request.tenant, now);
+
+    Ok(())
+}
+
+/// Schedule or reschedule the work on an acknowledged request (landlord only)
+pub fn schedule_maintenance(
+    env: &Env,
+    landlord: Address,
+    request_id: u32,
+    scheduled_for: u64,
+) -> Result<(), RentalError> {
+    let mut request = load_for_landlord(env, &landlord, request_id)?;
+
+    if request.status != MaintenanceStatus::Acknowledged
+        && request.status != MaintenanceStatus::Scheduled
+    {
+        return Err(RentalError::InvalidState);
+    }
+
+    let now = env.ledger().timestamp();
+    if scheduled_for < now {
+        return Err(RentalError::InvalidDate);
+    }
+
+    record_breach(env, &mut request, now);
+
+    request.status = MaintenanceStatus::Scheduled;
+    request.scheduled_for = Some(scheduled_for);
+    save_request(env, &request);
+
+    events::maintenance_scheduled(env, request_id, landlord, request.tenant, scheduled_for);
+
+    Ok(())
+}
+
+/// Mark the work on an acknowledged request as done (landlord only)
+pub fn complete_maintenance(
+    env: &Env,
+    landlord: Address,
+    request_id: u32,
+) -> Result<(), RentalError> {
+    let mut request = load_for_landlord(env, &landlord, request_id)?;
+
+    if request.status != MaintenanceStatus::Acknowledged
+        && request.status != MaintenanceStatus::Scheduled
+    {
+        return Err(RentalError::InvalidState);
+    }
+
+    let now = env.ledger().timestamp();
+    record_breach(env, &mut request, now);
+
+    request.status = MaintenanceStatus::Completed;
+    request.completed_at = Some(now);
+    save_request(env, &request);
+
+    events::maintenance_completed(env, request_id, landlord, request.tenant, now);
+
+    Ok(())
+}
+
+/// Record the SLA deadlines a request has missed that are not on record
+/// yet. Anyone may call this once a deadline has passed. Returns the latest
+/// deadline recorded.
+pub fn flag_sla_breach(env: &Env, request_id: u32) -> Result<SlaBreach, RentalError> {
+    let mut request =
+        get_maintenance_request(env, request_id).ok_or(RentalError::MaintenanceRequestNotFound)?;
+
+    // Fails both before any deadline passes and once every missed one is on record
+    let now = env.ledger().timestamp();
+    let breach = record_breach(env, &mut request, now).ok_or(RentalError::SlaNotBreached)?;
+    save_request(env, &request);
+
+    Ok(breach)
+}
+
+pub fn get_maintenance_request(env: &Env, request_id: u32) -> Option<MaintenanceRequest> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MaintenanceRequest(request_id))
+}
+
+/// Returns the total number of maintenance requests opened
+pub fn get_maintenance_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaintenanceCount)
+        .unwrap_or(0)
+}
+
+/// Returns the number of maintenance requests opened against an agreement
+pub fn get_agreement_maintenance_count(env: &Env, agreement_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgreementMaintenanceCount(agreement_id))
+        .unwrap_or(0)
+}
+
+/// Returns a page of an agreement's maintenance requests, oldest first
+pub fn list_agreement_maintenance(
+    env: &Env,
+    agreement_id: String,
+    start: u32,
+    limit: u32,
+) -> Vec<MaintenanceRequest> {
+    let mut requests = Vec::new(env);
+    let count = get_agreement_maintenance_count(env, agreement_id.clone());
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    for index in start..end {
+        let request = env
+            .storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::AgreementMaintenance(agreement_id.clone(), index))
+            .and_then(|request_id| get_maintenance_request(env, request_id));
+        if let Some(request) = request {
+            requests.push_back(request);
+        }
+    }
+
+    requests
+}
+
+fn load_for_landlord(
+    env: &Env,
+    landlord: &Address,
+    request_id: u32,
+) -> Result<MaintenanceRequest, RentalError> {
+    landlord.require_auth();
+
+    let request =
+        get_maintenance_request(env, request_id).ok_or(RentalError::MaintenanceRequestNotFound)?;
+
+    if request.landlord != *landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    Ok(request)
+}
+
+/// Flags every SLA deadline `request` has missed as of `now` that is not
+/// already on record, each with its own event.
+///
+/// Returns the latest deadline newly recorded, if any. The caller is
+/// responsible for persisting the updated request.
+fn record_breach(env: &Env, request: &mut MaintenanceRequest, now: u64) -> Option<SlaBreach> {
+    let mut recorded = None;
+
+    if request.acknowledgement_breached_at.is_none()
+        && request.acknowledged_at.is_none()
+        && now > request.acknowledge_by
+    {
+        request.acknowledgement_breached_at = Some(now);
+        emit_breach(env, request, SlaBreach::Acknowledgement, now);
+        recorded = Some(SlaBreach::Acknowledgement);
+    }
+
+    if request.completion_breached_at.is_none()
+        && request.completed_at.is_none()
+        && now > request.complete_by
+    {
+        request.completion_breached_at = Some(now);
+        emit_breach(env, request, SlaBreach::Completion, now);
+        recorded = Some(SlaBreach::Completion);
+    }
+
+    recorded
+}
+
+fn emit_breach(env: &Env, request: &MaintenanceRequest, breach: SlaBreach, now: u64) {
+    events::sla_breached(
+        env,
+        request.id,
+        request.agreement_id.clone(),
+        request.landlord.clone(),
+        request.tenant.clone(),
+        breach,
+        now,
+    );
+}
+
+fn save_request(env: &Env, request: &MaintenanceRequest) {
+    let key = DataKey::MaintenanceRequest(request.id);
+    env.storage().persistent().set(&key, request);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
@@ -0,0 +1,289 @@
+//! Pre-signing negotiation of agreement terms for the Chioma/Rental contract.
+use soroban_sdk::{Address, Env, String};
+
+use crate::agreement;
+use crate::co_tenancy;
+use crate::errors::RentalError;
+use crate::events;
+use crate::index;
+use crate::storage::DataKey;
+use crate::types::{
+    AgreementStatus, CounterOffer, LeaseDocument, PartyRole, RentAgreement, TermsChange,
+};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+fn load_agreement(env: &Env, agreement_id: &String) -> Result<RentAgreement, RentalError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)
+}
+
+/// Returns how many times an agreement's terms have changed since creation
+pub fn get_revision(env: &Env, agreement_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Revision(agreement_id))
+        .unwrap_or(0)
+}
+
+/// Overwrite the negotiable terms, bump the revision and drop every
+/// signature made over the old terms. Returns the new revision.
+fn apply_change(
+    env: &Env,
+    agreement: &mut RentAgreement,
+    change: TermsChange,
+) -> Result<u32, RentalError> {
+    agreement::validate_agreement_params(
+        env,
+        &change.monthly_rent,
+        &change.security_deposit,
+        &change.start_date,
+        &change.end_date,
+        &change.agent_commission_rate,
+    )?;
+
+    agreement.agent = change.agent;
+    agreement.monthly_rent = change.monthly_rent;
+    agreement.security_deposit = change.security_deposit;
+    agreement.start_date = change.start_date;
+    agreement.end_date = change.end_date;
+    agreement.agent_commission_rate = change.agent_commission_rate;
+    agreement.payment_token = change.payment_token;
+    agreement.next_payment_due = change.start_date;
+
+    let key = DataKey::Agreement(agreement.agreement_id.clone());
+    env.storage().persistent().set(&key, agreement);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+    agreement::set_lease_document(
+        env,
+        &agreement.agreement_id,
+        &LeaseDocument {
+            hash: change.document_hash,
+            cid: change.document_cid,
+        },
+    );
+
+    agreement::clear_signatures(env, agreement);
+    if let Some(agent) = &agreement.agent {
+        index::add(env, PartyRole::Agent, agent, &agreement.agreement_id);
+    }
+
+    let revision = get_revision(env, agreement.agreement_id.clone()) + 1;
+    let key = DataKey::Revision(agreement.agreement_id.clone());
+    env.storage().persistent().set(&key, &revision);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    Ok(revision)
+}
+
+/// Change the terms of a Draft agreement (landlord only).
+/// Returns the new revision.
+pub fn amend_agreement(
+    env: &Env,
+    landlord: Address,
+    agreement_id: String,
+    change: TermsChange,
+) -> Result<u32, RentalError> {
+    landlord.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.landlord != landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    if agreement.status != AgreementStatus::Draft {
+        return Err(RentalError::InvalidState);
+    }
+
+    let revision = apply_change(env, &mut agreement, change)?;
+
+    events::agreement_amended(
+        env,
+        agreement_id,
+        agreement.landlord,
+        agreement.tenant,
+        landlord,
+        revision,
+    );
+
+    Ok(revision)
+}
+
+/// Propose different terms for a Pending agreement (any tenant). The
+/// landlord may accept or reject; only one offer may be open at a time.
+pub fn propose_counter_offer(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+    change: TermsChange,
+) -> Result<(), RentalError> {
+    tenant.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if !co_tenancy::is_tenant(env, &agreement, &tenant) {
+        return Err(RentalError::NotTenant);
+    }
+
+    if agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
+
+    if get_counter_offer(env, agreement_id.clone()).is_some() {
+        return Err(RentalError::CounterOfferPending);
+    }
+
+    // Reject terms the landlord could never accept up front
+    agreement::validate_agreement_params(
+        env,
+        &change.monthly_rent,
+        &change.security_deposit,
+        &change.start_date,
+        &change.end_date,
+        &change.agent_commission_rate,
+    )?;
+
+    let offer = CounterOffer {
+        agreement_id: agreement_id.clone(),
+        proposer: tenant.clone(),
+        revision: get_revision(env, agreement_id.clone()),
+        change: change.clone(),
+        proposed_at: env.ledger().timestamp(),
+    };
+    let key = DataKey::CounterOffer(agreement_id.clone());
+    env.storage().persistent().set(&key, &offer);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    events::counter_offer_made(
+        env,
+        agreement_id,
+        agreement.landlord,
+        tenant,
+        change.monthly_rent,
+        change.start_date,
+        change.end_date,
+    );
+
+    Ok(())
+}
+
+/// Load the agreement and the open counter-offer on it for its landlord
+fn load_for_landlord(
+    env: &Env,
+    landlord: &Address,
+    agreement_id: &String,
+) -> Result<(RentAgreement, CounterOffer), RentalError> {
+    landlord.require_auth();
+
+    let agreement = load_agreement(env, agreement_id)?;
+
+    if agreement.landlord != *landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    let offer =
+        get_counter_offer(env, agreement_id.clone()).ok_or(RentalError::CounterOfferNotFound)?;
+
+    Ok((agreement, offer))
+}
+
+/// Accept the open counter-offer (landlord only). The agreement stays
+/// Pending and every party must sign the new terms. Returns the new revision.
+pub fn accept_counter_offer(
+    env: &Env,
+    landlord: Address,
+    agreement_id: String,
+) -> Result<u32, RentalError> {
+    let (mut agreement, offer) = load_for_landlord(env, &landlord, &agreement_id)?;
+
+    if agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
+
+    // The offer answered terms that have since been replaced
+    if offer.revision != get_revision(env, agreement_id.clone()) {
+        return Err(RentalError::TermsMismatch);
+    }
+
+    let revision = apply_change(env, &mut agreement, offer.change)?;
+
+    clear_counter_offer(env, &agreement_id);
+
+    events::agreement_amended(
+        env,
+        agreement_id,
+        agreement.landlord,
+        agreement.tenant,
+        offer.proposer,
+        revision,
+    );
+
+    Ok(revision)
+}
+
+/// Decline the open counter-offer, leaving the terms as they are (landlord only)
+pub fn reject_counter_offer(
+    env: &Env,
+    landlord: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    let (_, offer) = load_for_landlord(env, &landlord, &agreement_id)?;
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CounterOffer(agreement_id.clone()));
+
+    events::counter_offer_closed(env, agreement_id, landlord, offer.proposer, false);
+
+    Ok(())
+}
+
+/// Withdraw a counter-offer the landlord has not answered (proposer only)
+pub fn withdraw_counter_offer(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    tenant.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    let offer =
+        get_counter_offer(env, agreement_id.clone()).ok_or(RentalError::CounterOfferNotFound)?;
+
+    if offer.proposer != tenant {
+        return Err(RentalError::Unauthorized);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CounterOffer(agreement_id.clone()));
+
+    events::counter_offer_closed(env, agreement_id, agreement.landlord, tenant, true);
+
+    Ok(())
+}
+
+/// Drop any counter-offer left open on an agreement that is no longer being
+/// negotiated
+pub(crate) fn clear_counter_offer(env: &Env, agreement_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CounterOffer(agreement_id.clone()));
+}
+
+pub fn get_counter_offer(env: &Env, agreement_id: String) -> Option<CounterOffer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CounterOffer(agreement_id))
+}
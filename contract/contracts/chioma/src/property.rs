@@ -0,0 +1,164 @@
+//! Property registry checks and lease occupancy for the Chioma/Rental contract.
+use chioma_types::PropertyRegistryClient;
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{AgreementStatus, ContractState, RentAgreement};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Most leases a property may have running or signed ahead at once
+pub const MAX_CURRENT_LEASES: u32 = 12;
+
+/// Register the property registry that agreements are checked against (admin only)
+pub fn set_property_registry(env: &Env, property_registry: Address) -> Result<(), RentalError> {
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(RentalError::InvalidState)?;
+
+    state.admin.require_auth();
+
+    env.storage()
+        .instance()
+        .set(&DataKey::PropertyRegistry, &property_registry);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+    events::property_registry_set(env, state.admin, property_registry);
+
+    Ok(())
+}
+
+pub fn get_property_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PropertyRegistry)
+}
+
+/// Check that `landlord` owns a registered, verified property. Agreements
+/// cannot be created until the admin registers a property registry.
+pub(crate) fn verify_listing(
+    env: &Env,
+    property_id: &String,
+    landlord: &Address,
+) -> Result<(), RentalError> {
+    if property_id.is_empty() {
+        return Err(RentalError::PropertyNotFound);
+    }
+
+    let registry = get_property_registry(env).ok_or(RentalError::PropertyRegistryNotSet)?;
+
+    let property = PropertyRegistryClient::new(env, &registry)
+        .get_property(property_id)
+        .ok_or(RentalError::PropertyNotFound)?;
+
+    if !property.verified {
+        return Err(RentalError::PropertyNotVerified);
+    }
+
+    if property.landlord != *landlord {
+        return Err(RentalError::PropertyOwnerMismatch);
+    }
+
+    Ok(())
+}
+
+/// Returns the property an agreement lets, if it names one. Agreements
+/// created before properties were tracked have none.
+pub fn get_agreement_property(env: &Env, agreement_id: String) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgreementProperty(agreement_id))
+}
+
+pub(crate) fn set_agreement_property(env: &Env, agreement_id: &String, property_id: &String) {
+    let key = DataKey::AgreementProperty(agreement_id.clone());
+    env.storage().persistent().set(&key, property_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Returns the leases on a property that had not ended when the last one
+/// took effect, oldest first
+pub fn get_property_leases(env: &Env, property_id: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PropertyLeases(property_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Loads the property's leases that are in force and have not yet ended
+fn current_leases(env: &Env, property_id: &String) -> Vec<RentAgreement> {
+    let now = env.ledger().timestamp();
+    let mut leases = Vec::new(env);
+
+    for agreement_id in get_property_leases(env, property_id.clone()).iter() {
+        let Some(lease) = env
+            .storage()
+            .persistent()
+            .get::<_, RentAgreement>(&DataKey::Agreement(agreement_id))
+        else {
+            continue;
+        };
+
+        // A disputed lease still occupies the property
+        let in_force =
+            lease.status == AgreementStatus::Active || lease.status == AgreementStatus::Disputed;
+        if in_force && lease.end_date > now {
+            leases.push_back(lease);
+        }
+    }
+
+    leases
+}
+
+/// Fails if another agreement on `property_id` is in force for any part of
+/// `agreement`'s lease
+pub(crate) fn check_available(
+    env: &Env,
+    property_id: &String,
+    agreement: &RentAgreement,
+) -> Result<(), RentalError> {
+    for lease in current_leases(env, property_id).iter() {
+        if lease.agreement_id == agreement.agreement_id {
+            continue;
+        }
+
+        let overlaps =
+            lease.start_date < agreement.end_date && agreement.start_date < lease.end_date;
+        if overlaps {
+            return Err(RentalError::PropertyUnavailable);
+        }
+    }
+
+    Ok(())
+}
+
+/// Record that `agreement` now occupies `property_id`. Leases that have ended
+/// are dropped, and at most `MAX_CURRENT_LEASES` may be running or upcoming.
+pub(crate) fn record_lease(
+    env: &Env,
+    property_id: &String,
+    agreement: &RentAgreement,
+) -> Result<(), RentalError> {
+    let mut leases = Vec::new(env);
+    for lease in current_leases(env, property_id).iter() {
+        leases.push_back(lease.agreement_id);
+    }
+
+    if leases.len() >= MAX_CURRENT_LEASES {
+        return Err(RentalError::PropertyUnavailable);
+    }
+    leases.push_back(agreement.agreement_id.clone());
+
+    let key = DataKey::PropertyLeases(property_id.clone());
+    env.storage().persistent().set(&key, &leases);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    Ok(())
+}
@@ -0,0 +1,415 @@
+//! Rent review workflow for the Chioma/Rental contract.
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::co_tenancy;
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{
+    AgreementStatus, ContractState, RentAgreement, RentControl, RentProposal, RentProposalStatus,
+};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+const DAY: u64 = 86400;
+
+/// Notice required outside any rent-controlled jurisdiction
+pub const DEFAULT_NOTICE_PERIOD: u64 = 30 * DAY;
+
+/// Capped increases may take effect at most once per this many seconds
+pub const INCREASE_INTERVAL: u64 = 365 * DAY;
+
+fn load_state(env: &Env) -> Result<ContractState, RentalError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(RentalError::InvalidState)
+}
+
+fn load_agreement(env: &Env, agreement_id: &String) -> Result<RentAgreement, RentalError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)
+}
+
+fn save_agreement(env: &Env, agreement: &RentAgreement) {
+    let key = DataKey::Agreement(agreement.agreement_id.clone());
+    env.storage().persistent().set(&key, agreement);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+fn save_proposal(env: &Env, proposal: &RentProposal) {
+    let key = DataKey::RentProposal(proposal.agreement_id.clone());
+    env.storage().persistent().set(&key, proposal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Set the rent control rules for a jurisdiction (admin only)
+pub fn set_rent_control(
+    env: &Env,
+    jurisdiction: String,
+    control: RentControl,
+) -> Result<(), RentalError> {
+    let state = load_state(env)?;
+
+    state.admin.require_auth();
+
+    if jurisdiction.is_empty() || control.notice_period == 0 {
+        return Err(RentalError::InvalidConfig);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::RentControl(jurisdiction.clone()), &control);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+    events::rent_control_set(env, state.admin, jurisdiction, control);
+
+    Ok(())
+}
+
+/// Lift rent control in a jurisdiction (admin only)
+pub fn remove_rent_control(env: &Env, jurisdiction: String) -> Result<(), RentalError> {
+    let state = load_state(env)?;
+
+    state.admin.require_auth();
+
+    env.storage()
+        .instance()
+        .remove(&DataKey::RentControl(jurisdiction.clone()));
+
+    events::rent_control_removed(env, state.admin, jurisdiction);
+
+    Ok(())
+}
+
+pub fn get_rent_control(env: &Env, jurisdiction: String) -> Option<RentControl> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RentControl(jurisdiction))
+}
+
+/// Record the jurisdiction an agreement's property is in (admin only). The
+/// landlord cannot choose it, since it decides which rent caps apply.
+pub fn set_agreement_jurisdiction(
+    env: &Env,
+    agreement_id: String,
+    jurisdiction: String,
+) -> Result<(), RentalError> {
+    let state = load_state(env)?;
+
+    state.admin.require_auth();
+
+    load_agreement(env, &agreement_id)?;
+
+    if jurisdiction.is_empty() {
+        return Err(RentalError::InvalidConfig);
+    }
+
+    let key = DataKey::Jurisdiction(agreement_id.clone());
+    env.storage().persistent().set(&key, &jurisdiction);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    events::jurisdiction_set(env, state.admin, agreement_id, jurisdiction);
+
+    Ok(())
+}
+
+pub fn get_agreement_jurisdiction(env: &Env, agreement_id: String) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Jurisdiction(agreement_id))
+}
+
+/// Returns the rent control rules that apply to an agreement, if any
+fn agreement_rent_control(env: &Env, agreement_id: &String) -> Option<RentControl> {
+    get_agreement_jurisdiction(env, agreement_id.clone())
+        .and_then(|jurisdiction| get_rent_control(env, jurisdiction))
+}
+
+/// Whether `proposal` still blocks a new one: awaiting an answer before its
+/// effective date, or accepted but not yet applied
+fn is_outstanding(env: &Env, proposal: &RentProposal) -> bool {
+    match proposal.status {
+        RentProposalStatus::Pending => env.ledger().timestamp() < proposal.effective_from,
+        RentProposalStatus::Accepted => true,
+        _ => false,
+    }
+}
+
+/// Propose a new monthly rent for an Active agreement (landlord only)
+pub fn propose_rent_change(
+    env: &Env,
+    landlord: Address,
+    agreement_id: String,
+    proposed_rent: i128,
+    effective_from: u64,
+    terminate_on_reject: bool,
+) -> Result<(), RentalError> {
+    landlord.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.landlord != landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    if let Some(proposal) = get_rent_proposal(env, agreement_id.clone()) {
+        if is_outstanding(env, &proposal) {
+            return Err(RentalError::RentProposalPending);
+        }
+    }
+
+    if proposed_rent <= 0 || proposed_rent == agreement.monthly_rent {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    let control = agreement_rent_control(env, &agreement_id);
+    let notice_period = control
+        .as_ref()
+        .map_or(DEFAULT_NOTICE_PERIOD, |control| control.notice_period);
+
+    let now = env.ledger().timestamp();
+    if effective_from < now.saturating_add(notice_period) || effective_from >= agreement.end_date {
+        return Err(RentalError::InvalidDate);
+    }
+
+    // Decreases are never capped
+    if let Some(control) = control {
+        if proposed_rent > agreement.monthly_rent {
+            let increase = proposed_rent - agreement.monthly_rent;
+            if increase * 10_000 > agreement.monthly_rent * control.max_increase_bps as i128 {
+                return Err(RentalError::RentIncreaseExceedsCap);
+            }
+
+            let last_increase: Option<u64> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::LastRentIncrease(agreement_id.clone()));
+            if let Some(last_increase) = last_increase {
+                if effective_from < last_increase.saturating_add(INCREASE_INTERVAL) {
+                    return Err(RentalError::RentIncreaseTooSoon);
+                }
+            }
+        }
+    }
+
+    let proposal = RentProposal {
+        agreement_id: agreement_id.clone(),
+        current_rent: agreement.monthly_rent,
+        proposed_rent,
+        effective_from,
+        terminate_on_reject,
+        status: RentProposalStatus::Pending,
+        proposed_at: now,
+        responded_at: None,
+        accepted_by: Vec::new(env),
+    };
+    save_proposal(env, &proposal);
+
+    events::rent_change_proposed(
+        env,
+        agreement_id,
+        landlord,
+        agreement.tenant,
+        agreement.monthly_rent,
+        proposed_rent,
+        effective_from,
+        terminate_on_reject,
+    );
+
+    Ok(())
+}
+
+/// Load the agreement and its proposal awaiting an answer from `tenant`, who
+/// may be any of the agreement's tenants
+fn load_for_tenant(
+    env: &Env,
+    tenant: &Address,
+    agreement_id: &String,
+) -> Result<(RentAgreement, RentProposal), RentalError> {
+    tenant.require_auth();
+
+    let agreement = load_agreement(env, agreement_id)?;
+
+    if !co_tenancy::is_tenant(env, &agreement, tenant) {
+        return Err(RentalError::NotTenant);
+    }
+
+    let proposal =
+        get_rent_proposal(env, agreement_id.clone()).ok_or(RentalError::RentProposalNotFound)?;
+
+    if proposal.status != RentProposalStatus::Pending {
+        return Err(RentalError::RentProposalNotFound);
+    }
+
+    // An unanswered proposal lapses on its effective date
+    if env.ledger().timestamp() >= proposal.effective_from {
+        return Err(RentalError::Expired);
+    }
+
+    Ok((agreement, proposal))
+}
+
+/// Accept the pending rent proposal (tenants only). The new rent binds every
+/// tenant, so it is only agreed once each of them has accepted.
+pub fn accept_rent_change(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    let (agreement, mut proposal) = load_for_tenant(env, &tenant, &agreement_id)?;
+
+    if proposal.accepted_by.contains(&tenant) {
+        return Err(RentalError::InvalidState);
+    }
+
+    proposal.accepted_by.push_back(tenant.clone());
+    proposal.responded_at = Some(env.ledger().timestamp());
+
+    let accepted = co_tenancy::tenant_shares(env, &agreement)
+        .iter()
+        .all(|share| proposal.accepted_by.contains(&share.tenant));
+    if accepted {
+        proposal.status = RentProposalStatus::Accepted;
+    }
+    save_proposal(env, &proposal);
+
+    if accepted && proposal.proposed_rent > proposal.current_rent {
+        let key = DataKey::LastRentIncrease(agreement_id.clone());
+        env.storage()
+            .persistent()
+            .set(&key, &proposal.effective_from);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    events::rent_change_accepted(
+        env,
+        agreement_id,
+        agreement.landlord,
+        tenant,
+        proposal.proposed_rent,
+        proposal.effective_from,
+    );
+
+    Ok(())
+}
+
+/// Reject the pending rent proposal (any one tenant). If the landlord asked
+/// for it, the lease is shortened to end when the new rent would have applied.
+pub fn reject_rent_change(
+    env: &Env,
+    tenant: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    let (mut agreement, mut proposal) = load_for_tenant(env, &tenant, &agreement_id)?;
+
+    proposal.status = RentProposalStatus::Rejected;
+    proposal.responded_at = Some(env.ledger().timestamp());
+    save_proposal(env, &proposal);
+
+    if proposal.terminate_on_reject {
+        agreement.end_date = agreement.end_date.min(proposal.effective_from);
+        save_agreement(env, &agreement);
+    }
+
+    events::rent_change_rejected(
+        env,
+        agreement_id,
+        agreement.landlord,
+        tenant,
+        proposal.terminate_on_reject,
+        agreement.end_date,
+    );
+
+    Ok(())
+}
+
+/// Withdraw a proposal the tenant has not answered (landlord only)
+pub fn withdraw_rent_change(
+    env: &Env,
+    landlord: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    landlord.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.landlord != landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    let mut proposal =
+        get_rent_proposal(env, agreement_id.clone()).ok_or(RentalError::RentProposalNotFound)?;
+
+    if proposal.status != RentProposalStatus::Pending {
+        return Err(RentalError::RentProposalNotFound);
+    }
+
+    proposal.status = RentProposalStatus::Withdrawn;
+    save_proposal(env, &proposal);
+
+    events::rent_change_withdrawn(env, agreement_id, landlord, agreement.tenant);
+
+    Ok(())
+}
+
+/// Put an accepted rent into force once its effective date has passed.
+/// Anyone may call this.
+pub fn apply_rent_change(env: &Env, agreement_id: String) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+
+    let mut proposal =
+        get_rent_proposal(env, agreement_id.clone()).ok_or(RentalError::RentProposalNotFound)?;
+
+    if proposal.status != RentProposalStatus::Accepted {
+        return Err(RentalError::RentProposalNotFound);
+    }
+
+    if env.ledger().timestamp() < proposal.effective_from {
+        return Err(RentalError::InvalidDate);
+    }
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    let old_rent = agreement.monthly_rent;
+    agreement.monthly_rent = proposal.proposed_rent;
+    save_agreement(env, &agreement);
+
+    proposal.status = RentProposalStatus::Applied;
+    save_proposal(env, &proposal);
+
+    events::rent_change_applied(
+        env,
+        agreement_id,
+        agreement.landlord,
+        agreement.tenant,
+        old_rent,
+        proposal.proposed_rent,
+    );
+
+    Ok(())
+}
+
+/// Returns the latest rent proposal made on an agreement
+pub fn get_rent_proposal(env: &Env, agreement_id: String) -> Option<RentProposal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RentProposal(agreement_id))
+}
@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, String};
+use soroban_sdk::{contracttype, Address, String};
+
+use crate::types::{MaintenancePriority, PartyRole};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -7,4 +9,35 @@ pub enum DataKey {
     AgreementCount,
     State,
     Initialized,
+    DisputeContract,
+    MaintenanceRequest(u32),
+    MaintenanceCount,
+    AgreementMaintenanceCount(String),
+    AgreementMaintenance(String, u32),
+    MaintenanceSla(MaintenancePriority),
+    Jurisdiction(String),
+    RentControl(String),
+    RentProposal(String),
+    LastRentIncrease(String),
+    CoTenancy(String),
+    Signature(String, Address),
+    RentPeriod(String, u32),
+    SharePaid(String, u32, Address),
+    Revision(String),
+    CounterOffer(String),
+    PropertyRegistry,
+    PropertyLeases(String),
+    AgreementProperty(String),
+    AgreementDocument(String),
+    Addendum(String, u32),
+    AddendumCount(String),
+    AddendumSignature(String, u32, Address),
+    PartyAgreementCount(PartyRole, Address),
+    PartyAgreement(PartyRole, Address, u32),
+    PartyIndexed(PartyRole, Address, String),
+    Payment(String, u32),
+    SchemaVersion,
+    ConfigChange(u32),
+    ConfigChangeCount,
+    LastExecutedConfigChange,
 }
@@ -1,7 +1,10 @@
 use super::*;
+use chioma_types::PropertyDetails;
 use soroban_sdk::{
+    contract, contractimpl,
     testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
-    Address, Env, IntoVal, String,
+    xdr::ToXdr,
+    Address, BytesN, Env, IntoVal, String, Symbol, Val,
 };
 
 #[test]
@@ -17,7 +20,6 @@ fn test_successful_initialization() {
     let config = Config {
         fee_bps: 100,
         fee_collector: fee_collector.clone(),
-        paused: false,
     };
 
     let result = client.try_initialize(&admin, &config);
@@ -27,7 +29,6 @@ fn test_successful_initialization() {
     assert_eq!(state.admin, admin);
     assert_eq!(state.config.fee_bps, 100);
     assert_eq!(state.config.fee_collector, fee_collector);
-    assert!(!state.config.paused);
     assert!(state.initialized);
 }
 
@@ -45,7 +46,6 @@ fn test_initialize_fails_without_admin_auth() {
     let config = Config {
         fee_bps: 100,
         fee_collector: fee_collector.clone(),
-        paused: false,
     };
 
     client.initialize(&admin, &config);
@@ -66,7 +66,6 @@ fn test_double_initialization_fails() {
     let config = Config {
         fee_bps: 100,
         fee_collector: fee_collector.clone(),
-        paused: false,
     };
 
     client.initialize(&admin, &config);
@@ -88,7 +87,6 @@ fn test_invalid_fee_bps() {
     let config = Config {
         fee_bps: 10001,
         fee_collector,
-        paused: false,
     };
 
     client.initialize(&admin, &config);
@@ -105,7 +103,6 @@ fn test_initialize_fee_collector_no_auth_needed() {
     let config = Config {
         fee_bps: 100,
         fee_collector: fee_collector.clone(),
-        paused: false,
     };
 
     // ONLY admin authorizes here using MockAuth
@@ -126,16 +123,64 @@ fn test_initialize_fee_collector_no_auth_needed() {
     assert_eq!(state.admin, admin);
 }
 
+/// Property registry stand-in that reports listed properties as verified.
+#[contract]
+pub struct MockPropertyRegistry;
+
+#[contractimpl]
+impl MockPropertyRegistry {
+    pub fn get_property(env: Env, property_id: String) -> Option<PropertyDetails> {
+        env.storage().instance().get(&property_id)
+    }
+
+    /// Lists `property_id` as a verified property of `landlord`.
+    pub fn list(env: Env, property_id: String, landlord: Address) {
+        let property = PropertyDetails {
+            property_id: property_id.clone(),
+            landlord,
+            metadata_hash: String::from_str(&env, "QmProperty"),
+            verified: true,
+            registered_at: 0,
+            verified_at: Some(0),
+        };
+        env.storage().instance().set(&property_id, &property);
+    }
+}
+
+/// Registers the contract with a mock property registry already set.
 fn create_contract(env: &Env) -> ContractClient<'_> {
     let contract_id = env.register(Contract, ());
+    let registry_id = env.register(MockPropertyRegistry, ());
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&storage::DataKey::PropertyRegistry, &registry_id);
+    });
     ContractClient::new(env, &contract_id)
 }
 
+/// Lists `property_id` in the mock registry as owned by `landlord`.
+fn list_property(env: &Env, client: &ContractClient, property_id: &String, landlord: &Address) {
+    let registry = client.get_property_registry().unwrap();
+    MockPropertyRegistryClient::new(env, &registry).list(property_id, landlord);
+}
+
+/// Lists the property in the mock registry for the landlord, then creates
+/// the agreement.
+fn create_listed_agreement(
+    env: &Env,
+    client: &ContractClient,
+    initiator: &Address,
+    terms: &AgreementTerms,
+) {
+    list_property(env, client, &terms.property_id, &terms.landlord);
+    client.create_agreement(initiator, terms);
+}
+
 fn initialize_contract_state(env: &Env, client: &ContractClient<'_>, admin: &Address) {
     let config = Config {
         fee_bps: 100,
         fee_collector: Address::generate(env),
-        paused: false,
     };
     client
         .mock_auths(&[MockAuth {
@@ -151,7 +196,7 @@ fn initialize_contract_state(env: &Env, client: &ContractClient<'_>, admin: &Add
 }
 
 #[test]
-fn test_update_config_success() {
+fn test_config_change_waits_for_timelock() {
     let env = Env::default();
     env.mock_all_auths();
     let client = create_contract(&env);
@@ -160,77 +205,353 @@ fn test_update_config_success() {
     let initial_config = Config {
         fee_bps: 100,
         fee_collector: Address::generate(&env),
-        paused: false,
     };
     client.initialize(&admin, &initial_config);
 
-    let new_config = Config {
-        fee_bps: 250,
-        fee_collector: Address::generate(&env),
-        paused: true,
-    };
+    let new_collector = Address::generate(&env);
+    let change_id = client.schedule_config_change(&admin, &250, &new_collector);
+    assert_eq!(client.get_config_change_count(), 1);
+
+    let change = client.get_config_change(&change_id).unwrap();
+    assert_eq!(change.status, ConfigChangeStatus::Scheduled);
+    assert_eq!(change.executable_at, change.scheduled_at + CONFIG_TIMELOCK);
+
+    // Nothing changes until the delay has passed
+    assert_eq!(client.get_state().unwrap().config, initial_config);
+    let res = client.try_execute_config_change(&change_id);
+    assert_eq!(res, Err(Ok(RentalError::TimelockNotElapsed)));
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = change.executable_at);
+    client.execute_config_change(&change_id);
+
+    let config = client.get_state().unwrap().config;
+    assert_eq!(config.fee_bps, 250);
+    assert_eq!(config.fee_collector, new_collector);
+
+    let change = client.get_config_change(&change_id).unwrap();
+    assert_eq!(change.status, ConfigChangeStatus::Executed);
+    let res = client.try_execute_config_change(&change_id);
+    assert_eq!(res, Err(Ok(RentalError::ConfigChangeNotFound)));
+}
+
+#[test]
+fn test_cancelled_config_change_cannot_execute() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let first = client.schedule_config_change(&admin, &300, &Address::generate(&env));
+    let second = client.schedule_config_change(&admin, &150, &Address::generate(&env));
+    client.cancel_config_change(&admin, &first);
+
+    let res = client.try_cancel_config_change(&admin, &first);
+    assert_eq!(res, Err(Ok(RentalError::ConfigChangeNotFound)));
+    let res = client.try_cancel_config_change(&admin, &7);
+    assert_eq!(res, Err(Ok(RentalError::ConfigChangeNotFound)));
+
+    env.ledger().with_mut(|l| l.timestamp += CONFIG_TIMELOCK);
+    let res = client.try_execute_config_change(&first);
+    assert_eq!(res, Err(Ok(RentalError::ConfigChangeNotFound)));
+    client.execute_config_change(&second);
+    assert_eq!(client.get_state().unwrap().config.fee_bps, 150);
+
+    let changes = client.list_config_changes(&0, &10);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(
+        changes.get(0).unwrap().status,
+        ConfigChangeStatus::Cancelled
+    );
+    assert_eq!(changes.get(1).unwrap().status, ConfigChangeStatus::Executed);
+}
+
+#[test]
+fn test_executed_config_change_supersedes_older_ones() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let raise = client.schedule_config_change(&admin, &1_000, &Address::generate(&env));
+    let cut = client.schedule_config_change(&admin, &200, &Address::generate(&env));
+
+    env.ledger().with_mut(|l| l.timestamp += CONFIG_TIMELOCK);
+    client.execute_config_change(&cut);
+    assert_eq!(client.get_last_executed_change(), Some(cut));
+
+    // The older raise lost its notice when the newer change ran
+    let res = client.try_execute_config_change(&raise);
+    assert_eq!(res, Err(Ok(RentalError::ConfigChangeSuperseded)));
+    assert_eq!(client.get_state().unwrap().config.fee_bps, 200);
+}
+
+#[test]
+fn test_config_change_expires_after_execution_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
 
-    client.update_config(&new_config);
+    let stale = client.schedule_config_change(&admin, &1_000, &Address::generate(&env));
+    let change = client.get_config_change(&stale).unwrap();
 
-    let updated_state = client.get_state().unwrap();
-    assert_eq!(updated_state.config, new_config);
+    env.ledger().with_mut(|l| {
+        l.timestamp = change.executable_at + CONFIG_EXECUTION_WINDOW + 1;
+    });
+    let res = client.try_execute_config_change(&stale);
+    assert_eq!(res, Err(Ok(RentalError::ConfigChangeExpired)));
+    assert_eq!(client.get_state().unwrap().config.fee_bps, 100);
+
+    // The last moment of the window still counts
+    let fresh = client.schedule_config_change(&admin, &300, &Address::generate(&env));
+    let change = client.get_config_change(&fresh).unwrap();
+    env.ledger().with_mut(|l| {
+        l.timestamp = change.executable_at + CONFIG_EXECUTION_WINDOW;
+    });
+    client.execute_config_change(&fresh);
+    assert_eq!(client.get_state().unwrap().config.fee_bps, 300);
 }
 
 #[test]
 #[should_panic]
-fn test_update_config_unauthorized() {
+fn test_schedule_config_change_unauthorized() {
     let env = Env::default();
     let client = create_contract(&env);
 
     let admin = Address::generate(&env);
     initialize_contract_state(&env, &client, &admin);
 
-    let attacker = Address::generate(&env);
-    let new_config = Config {
-        fee_bps: 300,
-        fee_collector: Address::generate(&env),
-        paused: false,
-    };
+    let collector = Address::generate(&env);
 
     client
         .mock_auths(&[MockAuth {
-            address: &attacker,
+            address: &admin,
             invoke: &MockAuthInvoke {
                 contract: &client.address,
-                fn_name: "update_config",
-                args: (new_config.clone(),).into_val(&env),
+                fn_name: "schedule_config_change",
+                args: (admin.clone(), 300u32, collector.clone()).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .update_config(&new_config);
+        .schedule_config_change(&Address::generate(&env), &300, &collector);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #3)")]
-fn test_update_config_invalid_fee_bps() {
+#[should_panic]
+fn test_upgrade_unauthorized() {
     let env = Env::default();
     let client = create_contract(&env);
 
     let admin = Address::generate(&env);
     initialize_contract_state(&env, &client, &admin);
 
-    let bad_config = Config {
-        fee_bps: 10_001,
-        fee_collector: Address::generate(&env),
-        paused: false,
-    };
+    let attacker = Address::generate(&env);
+    let wasm_hash = BytesN::from_array(&env, &[9; 32]);
 
     client
         .mock_auths(&[MockAuth {
-            address: &admin,
+            address: &attacker,
             invoke: &MockAuthInvoke {
                 contract: &client.address,
-                fn_name: "update_config",
-                args: (bad_config.clone(),).into_val(&env),
+                fn_name: "upgrade",
+                args: (wasm_hash.clone(),).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .update_config(&bad_config);
+        .upgrade(&wasm_hash);
+}
+
+#[test]
+fn test_migrate_schema_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(client.try_migrate(), Err(Ok(RentalError::InvalidState)));
+
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(client.migrate(), SCHEMA_VERSION);
+
+    // A deployment from before schema versioning has no version key and
+    // keeps the contract-wide pause flag in its config
+    let fee_collector = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .remove(&storage::DataKey::SchemaVersion);
+        env.storage().instance().set(
+            &storage::DataKey::State,
+            &LegacyContractState {
+                admin: admin.clone(),
+                config: LegacyConfig {
+                    fee_bps: 250,
+                    fee_collector: fee_collector.clone(),
+                    paused: true,
+                },
+                initialized: true,
+            },
+        );
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(client.migrate(), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    // The contract-wide pause becomes a pause on every operation
+    let state = client.get_state().unwrap();
+    assert_eq!(state.admin, admin);
+    assert_eq!(
+        state.config,
+        Config {
+            fee_bps: 250,
+            fee_collector,
+        }
+    );
+    assert!(client.is_paused(&Operation::Agreements));
+    assert!(client.is_paused(&Operation::Maintenance));
+    assert!(client.is_paused(&Operation::RentChanges));
+    assert!(client.is_paused(&Operation::Payments));
+    assert_eq!(client.migrate(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_admin_transfer_requires_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(RentalError::NoPendingAdmin))
+    );
+
+    client.propose_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_state().unwrap().admin, admin);
+
+    client.accept_admin();
+    assert_eq!(
+        env.auths()[0].0,
+        new_admin,
+        "only the nominee authorizes the handover"
+    );
+    assert_eq!(client.get_state().unwrap().admin, new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert!(client.has_role(&Role::Admin, &new_admin));
+    assert!(!client.has_role(&Role::Admin, &admin));
+}
+
+#[test]
+fn test_roles_gate_pause_and_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let fee_manager = Address::generate(&env);
+    let collector = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let res = client.try_pause(&pauser, &Operation::Payments);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+    let res = client.try_grant_role(&Role::Verifier, &pauser);
+    assert_eq!(res, Err(Ok(RentalError::InvalidRole)));
+    let res = client.try_grant_role(&Role::Admin, &pauser);
+    assert_eq!(res, Err(Ok(RentalError::InvalidRole)));
+
+    client.grant_role(&Role::Pauser, &pauser);
+    client.grant_role(&Role::FeeManager, &fee_manager);
+    assert!(client.has_role(&Role::Pauser, &pauser));
+    assert!(client.has_role(&Role::Pauser, &admin));
+
+    client.pause(&pauser, &Operation::Payments);
+    assert!(client.is_paused(&Operation::Payments));
+
+    // A pauser cannot touch fees, nor a fee manager the pause switches
+    let res = client.try_schedule_config_change(&pauser, &200, &collector);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+    let res = client.try_unpause(&fee_manager, &Operation::Payments);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+
+    let res = client.try_schedule_config_change(&fee_manager, &10_001, &collector);
+    assert_eq!(res, Err(Ok(RentalError::InvalidConfig)));
+    let change_id = client.schedule_config_change(&fee_manager, &200, &collector);
+    env.ledger().with_mut(|l| l.timestamp += CONFIG_TIMELOCK);
+    client.execute_config_change(&change_id);
+    let config = client.get_state().unwrap().config;
+    assert_eq!(config.fee_bps, 200);
+    assert_eq!(config.fee_collector, collector);
+    assert!(client.is_paused(&Operation::Payments));
+
+    client.revoke_role(&Role::Pauser, &pauser);
+    let res = client.try_unpause(&pauser, &Operation::Payments);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+    client.unpause(&admin, &Operation::Payments);
+    assert!(!client.is_paused(&Operation::Payments));
+}
+
+#[test]
+fn test_guardian_pauses_single_operation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let res = client.try_pause(&guardian, &Operation::Agreements);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+
+    client.grant_role(&Role::Guardian, &guardian);
+    client.pause(&guardian, &Operation::Agreements);
+    assert!(client.is_paused(&Operation::Agreements));
+    assert!(!client.is_paused(&Operation::Payments));
+
+    let terms = AgreementTerms {
+        agreement_id: String::from_str(&env, "PAUSE_OP_001"),
+        property_id: String::from_str(&env, "PAUSE_OP_001"),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        monthly_rent: 1000,
+        security_deposit: 2000,
+        start_date: 100,
+        end_date: 1000000,
+        agent_commission_rate: 0,
+        payment_token: Address::generate(&env),
+        document_hash: BytesN::from_array(&env, &[1; 32]),
+        document_cid: None,
+    };
+    let res = client.try_create_agreement(&landlord, &terms);
+    assert_eq!(res, Err(Ok(RentalError::ContractPaused)));
+
+    // A guardian can stop things but not restart them
+    let res = client.try_unpause(&guardian, &Operation::Agreements);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+
+    client.unpause(&admin, &Operation::Agreements);
+    assert!(!client.is_paused(&Operation::Agreements));
+
+    // Pausing payments leaves agreements open
+    client.pause(&guardian, &Operation::Payments);
+    create_listed_agreement(&env, &client, &landlord, &terms);
 }
 
 #[test]
@@ -246,17 +567,25 @@ fn test_create_agreement_success() {
 
     let agreement_id = String::from_str(&env, "AGREEMENT_001");
 
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &agent,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &10,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: agent.clone(),
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 10,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 
     let events = env.events().all();
@@ -280,17 +609,25 @@ fn test_create_agreement_with_agent() {
 
     let agreement_id = String::from_str(&env, "AGREEMENT_WITH_AGENT");
 
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &Some(agent.clone()),
-        &1500,
-        &3000,
-        &1000,
-        &2000,
-        &5,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: Some(agent.clone()),
+            monthly_rent: 1500,
+            security_deposit: 3000,
+            start_date: 1000,
+            end_date: 2000,
+            agent_commission_rate: 5,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 }
 
@@ -306,17 +643,25 @@ fn test_create_agreement_without_agent() {
 
     let agreement_id = String::from_str(&env, "AGREEMENT_NO_AGENT");
 
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &1200,
-        &2400,
-        &500,
-        &1500,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1200,
+            security_deposit: 2400,
+            start_date: 500,
+            end_date: 1500,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 }
 
@@ -333,17 +678,25 @@ fn test_negative_rent_rejected() {
 
     let agreement_id = String::from_str(&env, "BAD_RENT");
 
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &-100,
-        &1000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: -100,
+            security_deposit: 1000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 }
 
@@ -360,17 +713,25 @@ fn test_zero_monthly_rent_rejected() {
 
     let agreement_id = String::from_str(&env, "ZERO_RENT");
 
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &0,
-        &1000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 0,
+            security_deposit: 1000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 }
 
@@ -387,17 +748,25 @@ fn test_invalid_dates_rejected() {
 
     let agreement_id = String::from_str(&env, "BAD_DATES");
 
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &200,
-        &100,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 200,
+            end_date: 100,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 }
 
@@ -420,17 +789,25 @@ fn test_backdated_agreement_rejected() {
     });
 
     // Try to create agreement with start_date more than 1 day in the past
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &900000, // More than 1 day (86400 seconds) before current time
-        &2000000,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 900000, // More than 1 day (86400 seconds) before current time
+            end_date: 2000000,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 }
 
@@ -452,17 +829,25 @@ fn test_agreement_within_grace_period_accepted() {
     });
 
     // Create agreement with start_date within grace period (less than 1 day ago)
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &950000, // Within 1 day grace period
-        &2000000,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 950000, // Within 1 day grace period
+            end_date: 2000000,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 
     assert!(client.has_agreement(&agreement_id));
@@ -481,30 +866,46 @@ fn test_duplicate_agreement_id() {
 
     let agreement_id = String::from_str(&env, "DUPLICATE_ID");
 
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 }
 
@@ -521,20 +922,55 @@ fn test_invalid_commission_rate() {
 
     let agreement_id = String::from_str(&env, "BAD_COMMISSION");
 
-    client.create_agreement(
-        &agreement_id,
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &101,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 101,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 }
 
+const YEAR: u64 = 365 * 86400;
+
+/// Terms for a three-year lease at a monthly rent of 1000, on a property
+/// named after the agreement.
+fn draft_terms(
+    env: &Env,
+    agreement_id: &str,
+    tenant: &Address,
+    landlord: &Address,
+) -> AgreementTerms {
+    AgreementTerms {
+        agreement_id: String::from_str(env, agreement_id),
+        property_id: String::from_str(env, agreement_id),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        monthly_rent: 1000,
+        security_deposit: 2000,
+        start_date: 100,
+        end_date: 3 * YEAR,
+        agent_commission_rate: 0,
+        payment_token: Address::generate(env),
+        document_hash: BytesN::from_array(env, &[1; 32]),
+        document_cid: None,
+    }
+}
+
 fn create_pending_agreement(
     env: &Env,
     client: &ContractClient,
@@ -542,17 +978,11 @@ fn create_pending_agreement(
     tenant: &Address,
     landlord: &Address,
 ) {
-    client.create_agreement(
-        &String::from_str(env, agreement_id),
+    create_listed_agreement(
+        env,
+        client,
         landlord,
-        tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &1000000,
-        &0,
-        &Address::generate(env),
+        &draft_terms(env, agreement_id, tenant, landlord),
     );
 
     let mut agreement = client
@@ -577,17 +1007,34 @@ fn test_sign_agreement_success() {
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
-    let agreement_id = "SIGN_001";
-    create_pending_agreement(&env, &client, agreement_id, &tenant, &landlord);
+    let agreement_id = String::from_str(&env, "SIGN_001");
+    create_pending_agreement(&env, &client, "SIGN_001", &tenant, &landlord);
+    let terms_hash = client.get_terms_hash(&agreement_id);
 
-    client.sign_agreement(&tenant, &String::from_str(&env, agreement_id));
+    client.sign_agreement(&tenant, &agreement_id, &terms_hash);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().status,
+        AgreementStatus::Pending
+    );
 
-    let agreement = client
-        .get_agreement(&String::from_str(&env, agreement_id))
-        .unwrap();
+    env.ledger().with_mut(|l| l.timestamp = 500);
+    client.sign_agreement(&landlord, &agreement_id, &terms_hash);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
     assert_eq!(agreement.status, AgreementStatus::Active);
-    assert!(agreement.signed_at.is_some());
+    assert_eq!(agreement.signed_at, Some(500));
     assert_eq!(agreement.tenant, tenant);
+
+    let signature = client.get_signature(&agreement_id, &landlord).unwrap();
+    assert_eq!(signature.terms_hash, terms_hash);
+    assert_eq!(signature.signed_at, 500);
+    assert_eq!(
+        client
+            .get_signature(&agreement_id, &tenant)
+            .unwrap()
+            .signed_at,
+        0
+    );
 }
 
 #[test]
@@ -599,7 +1046,11 @@ fn test_sign_agreement_not_found() {
     let client = create_contract(&env);
     let tenant = Address::generate(&env);
 
-    client.sign_agreement(&tenant, &String::from_str(&env, "NONEXISTENT"));
+    client.sign_agreement(
+        &tenant,
+        &String::from_str(&env, "NONEXISTENT"),
+        &BytesN::from_array(&env, &[0; 32]),
+    );
 }
 
 #[test]
@@ -616,7 +1067,11 @@ fn test_sign_agreement_not_tenant() {
     let agreement_id = "SIGN_002";
     create_pending_agreement(&env, &client, agreement_id, &tenant, &landlord);
 
-    client.sign_agreement(&impostor, &String::from_str(&env, agreement_id));
+    client.sign_agreement(
+        &impostor,
+        &String::from_str(&env, agreement_id),
+        &client.get_terms_hash(&String::from_str(&env, agreement_id)),
+    );
 }
 
 #[test]
@@ -631,20 +1086,32 @@ fn test_sign_agreement_invalid_state() {
 
     let agreement_id = "SIGN_003";
 
-    client.create_agreement(
-        &String::from_str(&env, agreement_id),
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &1000000,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: String::from_str(&env, agreement_id),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 1000000,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 
-    client.sign_agreement(&tenant, &String::from_str(&env, agreement_id));
+    client.sign_agreement(
+        &tenant,
+        &String::from_str(&env, agreement_id),
+        &client.get_terms_hash(&String::from_str(&env, agreement_id)),
+    );
 }
 
 #[test]
@@ -659,17 +1126,25 @@ fn test_sign_agreement_expired() {
 
     let agreement_id = "SIGN_004";
 
-    client.create_agreement(
-        &String::from_str(&env, agreement_id),
+    create_listed_agreement(
+        &env,
+        &client,
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
+        &AgreementTerms {
+            agreement_id: String::from_str(&env, agreement_id),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
     );
 
     let mut agreement = client
@@ -686,7 +1161,11 @@ fn test_sign_agreement_expired() {
 
     env.ledger().with_mut(|li| li.timestamp = 300);
 
-    client.sign_agreement(&tenant, &String::from_str(&env, agreement_id));
+    client.sign_agreement(
+        &tenant,
+        &String::from_str(&env, agreement_id),
+        &client.get_terms_hash(&String::from_str(&env, agreement_id)),
+    );
 }
 
 #[test]
@@ -702,9 +1181,17 @@ fn test_sign_agreement_already_signed() {
     let agreement_id = "SIGN_005";
     create_pending_agreement(&env, &client, agreement_id, &tenant, &landlord);
 
-    client.sign_agreement(&tenant, &String::from_str(&env, agreement_id));
+    client.sign_agreement(
+        &tenant,
+        &String::from_str(&env, agreement_id),
+        &client.get_terms_hash(&String::from_str(&env, agreement_id)),
+    );
 
-    client.sign_agreement(&tenant, &String::from_str(&env, agreement_id));
+    client.sign_agreement(
+        &tenant,
+        &String::from_str(&env, agreement_id),
+        &client.get_terms_hash(&String::from_str(&env, agreement_id)),
+    );
 }
 
 #[test]
@@ -721,14 +1208,18 @@ fn test_sign_agreement_event_emission() {
 
     let events_before = env.events().all().len();
 
-    client.sign_agreement(&tenant, &String::from_str(&env, agreement_id));
+    client.sign_agreement(
+        &tenant,
+        &String::from_str(&env, agreement_id),
+        &client.get_terms_hash(&String::from_str(&env, agreement_id)),
+    );
 
     let events_after = env.events().all();
     assert!(events_after.len() > events_before);
 }
 
 #[test]
-fn test_submit_agreement_success() {
+fn test_sign_agreement_terms_mismatch() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -736,259 +1227,516 @@ fn test_submit_agreement_success() {
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
-    let agreement_id = String::from_str(&env, "SUBMIT_001");
-
-    client.create_agreement(
-        &agreement_id,
-        &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
-    );
-
-    let agreement_before = client.get_agreement(&agreement_id).unwrap();
-    assert_eq!(agreement_before.status, AgreementStatus::Draft);
+    let agreement_id = String::from_str(&env, "SIGN_007");
+    create_pending_agreement(&env, &client, "SIGN_007", &tenant, &landlord);
 
-    client.submit_agreement(&landlord, &agreement_id);
+    // A hash over different rent does not match the terms on record
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    let mut terms = env.as_contract(&client.address, || agreement_terms(&env, &agreement));
+    terms.monthly_rent = 900;
+    let stale_hash: BytesN<32> = env.crypto().sha256(&terms.to_xdr(&env)).into();
 
-    let agreement_after = client.get_agreement(&agreement_id).unwrap();
-    assert_eq!(agreement_after.status, AgreementStatus::Pending);
+    let res = client.try_sign_agreement(&tenant, &agreement_id, &stale_hash);
+    assert_eq!(res, Err(Ok(RentalError::TermsMismatch)));
+    assert_eq!(client.get_signature(&agreement_id, &tenant), None);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #13)")]
-fn test_submit_agreement_not_found() {
+fn test_tenant_can_initiate_agreement() {
     let env = Env::default();
     env.mock_all_auths();
 
     let client = create_contract(&env);
+    let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
-    client.submit_agreement(&landlord, &String::from_str(&env, "NONEXISTENT"));
-}
+    let agreement_id = String::from_str(&env, "TENANT_DRAFT");
+    let terms = AgreementTerms {
+        agreement_id: agreement_id.clone(),
+        property_id: String::from_str(&env, "PROPERTY_001"),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        monthly_rent: 1000,
+        security_deposit: 2000,
+        start_date: 100,
+        end_date: 1000000,
+        agent_commission_rate: 0,
+        payment_token: Address::generate(&env),
+        document_hash: BytesN::from_array(&env, &[1; 32]),
+        document_cid: None,
+    };
 
-#[test]
-#[should_panic(expected = "Error(Contract, #18)")]
-fn test_submit_agreement_unauthorized() {
-    let env = Env::default();
-    env.mock_all_auths();
+    let res = client.try_create_agreement(&Address::generate(&env), &terms);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
 
-    let client = create_contract(&env);
-    let tenant = Address::generate(&env);
-    let landlord = Address::generate(&env);
-    let non_landlord = Address::generate(&env);
+    create_listed_agreement(&env, &client, &tenant, &terms);
+    client.submit_agreement(&tenant, &agreement_id);
 
-    let agreement_id = String::from_str(&env, "SUBMIT_UNAUTH");
+    let terms_hash = client.get_terms_hash(&agreement_id);
+    assert_eq!(
+        terms_hash,
+        BytesN::from(
+            env.crypto()
+                .sha256(&(terms.clone(), None::<CoTenancy>).to_xdr(&env))
+        )
+    );
+    client.sign_agreement(&tenant, &agreement_id, &terms_hash);
+    client.sign_agreement(&landlord, &agreement_id, &terms_hash);
 
-    client.create_agreement(
-        &agreement_id,
-        &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().status,
+        AgreementStatus::Active
     );
+}
 
-    client.submit_agreement(&non_landlord, &agreement_id);
+fn create_property_registry<'a>(
+    env: &Env,
+    client: &ContractClient,
+    admin: &Address,
+) -> property_registry::PropertyRegistryContractClient<'a> {
+    let registry_id = env.register(property_registry::PropertyRegistryContract, ());
+    let registry = property_registry::PropertyRegistryContractClient::new(env, &registry_id);
+    registry.initialize(admin);
+    client.set_property_registry(&registry_id);
+    registry
+}
+
+fn lease_terms(
+    env: &Env,
+    agreement_id: &str,
+    property_id: &str,
+    landlord: &Address,
+    start_date: u64,
+    end_date: u64,
+) -> AgreementTerms {
+    AgreementTerms {
+        property_id: String::from_str(env, property_id),
+        start_date,
+        end_date,
+        ..draft_terms(env, agreement_id, &Address::generate(env), landlord)
+    }
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #15)")]
-fn test_submit_agreement_invalid_state() {
+fn test_create_agreement_checks_property_registry() {
     let env = Env::default();
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    let tenant = Address::generate(&env);
+    let admin = Address::generate(&env);
     let landlord = Address::generate(&env);
+    let impostor = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    let registry = create_property_registry(&env, &client, &admin);
+    assert_eq!(
+        client.get_property_registry(),
+        Some(registry.address.clone())
+    );
 
-    let agreement_id = "SUBMIT_INVALID";
-    create_pending_agreement(&env, &client, agreement_id, &tenant, &landlord);
+    let property_id = String::from_str(&env, "PROP-001");
+    let terms = lease_terms(&env, "LISTED_001", "PROP-001", &landlord, 100, 1000);
 
-    client.submit_agreement(&landlord, &String::from_str(&env, agreement_id));
+    let res = client.try_create_agreement(&landlord, &terms);
+    assert_eq!(res, Err(Ok(RentalError::PropertyNotFound)));
+
+    registry.register_property(
+        &landlord,
+        &property_id,
+        &String::from_str(&env, "QmProperty"),
+    );
+    let res = client.try_create_agreement(&landlord, &terms);
+    assert_eq!(res, Err(Ok(RentalError::PropertyNotVerified)));
+
+    registry.verify_property(&admin, &property_id);
+    let forged = lease_terms(&env, "LISTED_002", "PROP-001", &impostor, 100, 1000);
+    let res = client.try_create_agreement(&impostor, &forged);
+    assert_eq!(res, Err(Ok(RentalError::PropertyOwnerMismatch)));
+
+    client.create_agreement(&landlord, &terms);
+    assert_eq!(
+        client.get_agreement_property(&terms.agreement_id),
+        Some(property_id)
+    );
 }
 
 #[test]
-fn test_cancel_agreement_success_draft() {
+fn test_create_agreement_requires_property_registry() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
-    let tenant = Address::generate(&env);
+    let client = ContractClient::new(&env, &env.register(Contract, ()));
     let landlord = Address::generate(&env);
 
-    let agreement_id = String::from_str(&env, "CANCEL_DRAFT");
+    let terms = lease_terms(&env, "UNLISTED_001", "PROP-001", &landlord, 100, 1000);
+    let res = client.try_create_agreement(&landlord, &terms);
+    assert_eq!(res, Err(Ok(RentalError::PropertyRegistryNotSet)));
+}
 
-    client.create_agreement(
-        &agreement_id,
-        &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
-    );
+/// Creates, submits and fully signs a lease so it takes effect.
+fn activate_lease(env: &Env, client: &ContractClient, terms: &AgreementTerms) {
+    create_listed_agreement(env, client, &terms.landlord, terms);
+    client.submit_agreement(&terms.landlord, &terms.agreement_id);
+    let terms_hash = client.get_terms_hash(&terms.agreement_id);
+    client.sign_agreement(&terms.landlord, &terms.agreement_id, &terms_hash);
+    client.sign_agreement(&terms.tenant, &terms.agreement_id, &terms_hash);
+}
 
-    client.cancel_agreement(&landlord, &agreement_id);
+#[test]
+fn test_property_leases_are_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    let agreement = client.get_agreement(&agreement_id).unwrap();
-    assert_eq!(agreement.status, AgreementStatus::Cancelled);
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+
+    let ids: [&str; MAX_CURRENT_LEASES as usize] = [
+        "UNIT_B_0",
+        "UNIT_B_1",
+        "UNIT_B_2",
+        "UNIT_B_3",
+        "UNIT_B_4",
+        "UNIT_B_5",
+        "UNIT_B_6",
+        "UNIT_B_7",
+        "UNIT_B_8",
+        "UNIT_B_9",
+        "UNIT_B_10",
+        "UNIT_B_11",
+    ];
+    for (i, id) in ids.iter().enumerate() {
+        let start = 1_000 * (i as u64 + 1);
+        let terms = lease_terms(&env, id, "UNIT_B", &landlord, start, start + 1_000);
+        activate_lease(&env, &client, &terms);
+    }
+    assert_eq!(
+        client
+            .get_property_leases(&String::from_str(&env, "UNIT_B"))
+            .len(),
+        MAX_CURRENT_LEASES
+    );
+
+    // The window is full of upcoming leases
+    let start = 1_000 * (MAX_CURRENT_LEASES as u64 + 1);
+    let extra = lease_terms(&env, "UNIT_B_X", "UNIT_B", &landlord, start, start + 1_000);
+    create_listed_agreement(&env, &client, &landlord, &extra);
+    client.submit_agreement(&landlord, &extra.agreement_id);
+    let terms_hash = client.get_terms_hash(&extra.agreement_id);
+    client.sign_agreement(&landlord, &extra.agreement_id, &terms_hash);
+    let res = client.try_sign_agreement(&extra.tenant, &extra.agreement_id, &terms_hash);
+    assert_eq!(res, Err(Ok(RentalError::PropertyUnavailable)));
+
+    // Once the first lease ends it is dropped and frees a slot
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.sign_agreement(&extra.tenant, &extra.agreement_id, &terms_hash);
+
+    let leases = client.get_property_leases(&extra.property_id);
+    assert_eq!(leases.len(), MAX_CURRENT_LEASES);
+    assert!(!leases.contains(String::from_str(&env, ids[0])));
+    assert_eq!(leases.last(), Some(extra.agreement_id));
 }
 
 #[test]
-fn test_cancel_agreement_success_pending() {
+fn test_overlapping_leases_rejected() {
     let env = Env::default();
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
-    let agreement_id = "CANCEL_PENDING";
-    create_pending_agreement(&env, &client, agreement_id, &tenant, &landlord);
+    // Competing drafts on the same unit may coexist until one takes effect
+    let first = lease_terms(&env, "UNIT_A_1", "UNIT_A", &landlord, 100, 1000);
+    let second = lease_terms(&env, "UNIT_A_2", "UNIT_A", &landlord, 500, 1500);
+    for terms in [&first, &second] {
+        create_listed_agreement(&env, &client, &landlord, terms);
+        client.submit_agreement(&landlord, &terms.agreement_id);
+    }
 
-    client.cancel_agreement(&landlord, &String::from_str(&env, agreement_id));
+    for terms in [&first, &second] {
+        let terms_hash = client.get_terms_hash(&terms.agreement_id);
+        client.sign_agreement(&landlord, &terms.agreement_id, &terms_hash);
+    }
+    let terms_hash = client.get_terms_hash(&first.agreement_id);
+    client.sign_agreement(&first.tenant, &first.agreement_id, &terms_hash);
+
+    let terms_hash = client.get_terms_hash(&second.agreement_id);
+    let res = client.try_sign_agreement(&second.tenant, &second.agreement_id, &terms_hash);
+    assert_eq!(res, Err(Ok(RentalError::PropertyUnavailable)));
+    assert_eq!(
+        client.get_agreement(&second.agreement_id).unwrap().status,
+        AgreementStatus::Pending
+    );
 
-    let agreement = client
-        .get_agreement(&String::from_str(&env, agreement_id))
-        .unwrap();
-    assert_eq!(agreement.status, AgreementStatus::Cancelled);
+    let overlapping = lease_terms(&env, "UNIT_A_3", "UNIT_A", &landlord, 900, 2000);
+    let res = client.try_create_agreement(&landlord, &overlapping);
+    assert_eq!(res, Err(Ok(RentalError::PropertyUnavailable)));
+
+    // A lease starting when the first ends does not overlap it
+    let renewal = lease_terms(&env, "UNIT_A_4", "UNIT_A", &landlord, 1000, 2000);
+    create_listed_agreement(&env, &client, &landlord, &renewal);
+
+    let mut leases = soroban_sdk::Vec::new(&env);
+    leases.push_back(first.agreement_id.clone());
+    assert_eq!(client.get_property_leases(&first.property_id), leases);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #13)")]
-fn test_cancel_agreement_not_found() {
+fn test_signatures_cover_lease_document() {
     let env = Env::default();
     env.mock_all_auths();
 
     let client = create_contract(&env);
     let landlord = Address::generate(&env);
 
-    client.cancel_agreement(&landlord, &String::from_str(&env, "NONEXISTENT"));
+    let mut terms = lease_terms(&env, "DOC_001", "PROPERTY_DOC", &landlord, 100, 1000);
+    terms.document_hash = BytesN::from_array(&env, &[9; 32]);
+    terms.document_cid = Some(String::from_str(&env, "QmLeasePdf"));
+    create_listed_agreement(&env, &client, &landlord, &terms);
+
+    let document = client.get_lease_document(&terms.agreement_id).unwrap();
+    assert_eq!(document.hash, terms.document_hash);
+    assert_eq!(document.cid, terms.document_cid);
+
+    // Swapping the document changes what the parties sign
+    let signed_hash = client.get_terms_hash(&terms.agreement_id);
+    terms.document_hash = BytesN::from_array(&env, &[8; 32]);
+    let other_hash: BytesN<32> = env.crypto().sha256(&terms.to_xdr(&env)).into();
+    assert_ne!(signed_hash, other_hash);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #18)")]
-fn test_cancel_agreement_unauthorized() {
+fn test_addendum_requires_every_signature() {
     let env = Env::default();
     env.mock_all_auths();
 
     let client = create_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
-    let non_landlord = Address::generate(&env);
 
-    let agreement_id = String::from_str(&env, "CANCEL_UNAUTH");
+    create_pending_agreement(&env, &client, "ADDENDUM_001", &tenant, &landlord);
+    let agreement_id = String::from_str(&env, "ADDENDUM_001");
+    let pet_clause = BytesN::from_array(&env, &[3; 32]);
 
-    client.create_agreement(
-        &agreement_id,
+    let res = client.try_propose_addendum(&tenant, &agreement_id, &pet_clause, &None);
+    assert_eq!(res, Err(Ok(RentalError::AgreementNotActive)));
+
+    let terms_hash = client.get_terms_hash(&agreement_id);
+    client.sign_agreement(&landlord, &agreement_id, &terms_hash);
+    client.sign_agreement(&tenant, &agreement_id, &terms_hash);
+
+    let res =
+        client.try_propose_addendum(&Address::generate(&env), &agreement_id, &pet_clause, &None);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+
+    let cid = Some(String::from_str(&env, "QmPetClause"));
+    assert_eq!(
+        client.propose_addendum(&tenant, &agreement_id, &pet_clause, &cid),
+        0
+    );
+    let addendum = client.get_addendum(&agreement_id, &0).unwrap();
+    assert_eq!(addendum.proposed_by, tenant);
+    assert_eq!(addendum.document_cid, cid);
+    assert_eq!(addendum.executed_at, None);
+    assert!(client
+        .get_addendum_signature(&agreement_id, &0, &tenant)
+        .is_some());
+
+    let res = client.try_sign_addendum(&tenant, &agreement_id, &0, &pet_clause);
+    assert_eq!(res, Err(Ok(RentalError::InvalidState)));
+    let res = client.try_sign_addendum(
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
+        &agreement_id,
         &0,
-        &Address::generate(&env),
+        &BytesN::from_array(&env, &[4; 32]),
+    );
+    assert_eq!(res, Err(Ok(RentalError::TermsMismatch)));
+    let res = client.try_sign_addendum(&landlord, &agreement_id, &1, &pet_clause);
+    assert_eq!(res, Err(Ok(RentalError::AddendumNotFound)));
+
+    env.ledger().with_mut(|l| l.timestamp = 200);
+    client.sign_addendum(&landlord, &agreement_id, &0, &pet_clause);
+    assert_eq!(
+        client.get_addendum(&agreement_id, &0).unwrap().executed_at,
+        Some(200)
     );
 
-    client.cancel_agreement(&non_landlord, &agreement_id);
+    // Addenda accumulate; earlier ones are never replaced
+    let parking = BytesN::from_array(&env, &[5; 32]);
+    assert_eq!(
+        client.propose_addendum(&landlord, &agreement_id, &parking, &None),
+        1
+    );
+    assert_eq!(client.get_addendum_count(&agreement_id), 2);
+    let addenda = client.list_addenda(&agreement_id, &0, &10);
+    assert_eq!(addenda.len(), 2);
+    assert_eq!(addenda.get(0).unwrap().document_hash, pet_clause);
+    assert_eq!(addenda.get(1).unwrap().document_hash, parking);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #15)")]
-fn test_cancel_agreement_invalid_state() {
+fn test_list_agreements_by_party() {
     let env = Env::default();
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let agent = Address::generate(&env);
 
-    let agreement_id = "CANCEL_INVALID";
-    create_pending_agreement(&env, &client, agreement_id, &tenant, &landlord);
-
-    client.sign_agreement(&tenant, &String::from_str(&env, agreement_id));
+    create_active_agreement(&env, &client, "LIST_001", &tenant, &landlord);
+    create_pending_agreement(&env, &client, "LIST_002", &tenant, &landlord);
+    let mut terms = lease_terms(&env, "LIST_003", "PROPERTY_LIST", &landlord, 100, 1000);
+    terms.agent = Some(agent.clone());
+    create_listed_agreement(&env, &client, &landlord, &terms);
 
-    // Status is now Active
+    let ids = |page: &AgreementPage| {
+        let mut ids = soroban_sdk::Vec::new(&env);
+        for agreement in page.agreements.iter() {
+            ids.push_back(agreement.agreement_id);
+        }
+        ids
+    };
+    let expected = |names: &[&str]| {
+        let mut ids = soroban_sdk::Vec::new(&env);
+        for name in names {
+            ids.push_back(String::from_str(&env, name));
+        }
+        ids
+    };
 
-    client.cancel_agreement(&landlord, &String::from_str(&env, agreement_id));
+    let page = client.list_agreements_by_landlord(&landlord, &None, &0, &2);
+    assert_eq!(ids(&page), expected(&["LIST_001", "LIST_002"]));
+    assert_eq!(page.next_start, Some(2));
+    let page = client.list_agreements_by_landlord(&landlord, &None, &2, &2);
+    assert_eq!(ids(&page), expected(&["LIST_003"]));
+    assert_eq!(page.next_start, None);
+
+    let page =
+        client.list_agreements_by_landlord(&landlord, &Some(AgreementStatus::Draft), &0, &10);
+    assert_eq!(ids(&page), expected(&["LIST_003"]));
+
+    let page = client.list_agreements_by_tenant(&tenant, &Some(AgreementStatus::Active), &0, &10);
+    assert_eq!(ids(&page), expected(&["LIST_001"]));
+    let page = client.list_agreements_by_agent(&agent, &None, &0, &10);
+    assert_eq!(ids(&page), expected(&["LIST_003"]));
+    assert_eq!(
+        client
+            .list_agreements_by_agent(&tenant, &None, &0, &10)
+            .agreements
+            .len(),
+        0
+    );
 }
 
 #[test]
-fn test_get_agreement() {
+fn test_filtered_listing_stops_at_scan_budget() {
     let env = Env::default();
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
+    let tenant = Address::generate(&env);
 
-    let agreement_id = String::from_str(&env, "GET_001");
-
-    client.create_agreement(
-        &agreement_id,
-        &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
+    for i in 0..MAX_SCAN {
+        let bytes = [
+            b'S',
+            b'C',
+            b'A',
+            b'N',
+            b'_',
+            b'0' + (i / 10) as u8,
+            b'0' + (i % 10) as u8,
+        ];
+        let agreement_id = core::str::from_utf8(&bytes).unwrap();
+        create_listed_agreement(
+            &env,
+            &client,
+            &landlord,
+            &draft_terms(&env, agreement_id, &Address::generate(&env), &landlord),
+        );
+    }
+    create_active_agreement(&env, &client, "SCAN_ACTIVE", &tenant, &landlord);
+
+    // The only match lies past the budget, so the first call comes back
+    // empty with a cursor instead of reading the whole index
+    let active = Some(AgreementStatus::Active);
+    let page = client.list_agreements_by_landlord(&landlord, &active, &0, &10);
+    assert_eq!(page.agreements.len(), 0);
+    assert_eq!(page.next_start, Some(MAX_SCAN));
+
+    let page = client.list_agreements_by_landlord(&landlord, &active, &MAX_SCAN, &10);
+    assert_eq!(page.agreements.len(), 1);
+    assert_eq!(
+        page.agreements.get(0).unwrap().agreement_id,
+        String::from_str(&env, "SCAN_ACTIVE")
     );
+    assert_eq!(page.next_start, None);
 
-    let agreement = client.get_agreement(&agreement_id).unwrap();
-    assert_eq!(agreement.monthly_rent, 1000);
-    assert_eq!(agreement.landlord, landlord);
-    assert_eq!(agreement.tenant, tenant);
+    // Pages are capped at the scan budget too
+    let page = client.list_agreements_by_landlord(&landlord, &None, &0, &100);
+    assert_eq!(page.agreements.len(), MAX_SCAN);
+    assert_eq!(page.next_start, Some(MAX_SCAN));
 }
 
 #[test]
-fn test_has_agreement() {
+fn test_list_agreements_tracks_co_tenants() {
     let env = Env::default();
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    let tenant = Address::generate(&env);
+    let admin = Address::generate(&env);
     let landlord = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
 
-    let agreement_id = String::from_str(&env, "HAS_001");
-
-    assert!(!client.has_agreement(&agreement_id));
-
-    client.create_agreement(
-        &agreement_id,
+    let (agreement_id, _) = create_shared_agreement(
+        &env,
+        &client,
+        "LIST_SHARED",
         &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
+        &[(alice.clone(), 5_000), (bob.clone(), 5_000)],
+        None,
     );
+    let page = client.list_agreements_by_tenant(&bob, &None, &0, &10);
+    assert_eq!(page.agreements.get(0).unwrap().agreement_id, agreement_id);
+
+    // Bob is replaced before signing and drops out of his listing
+    let mut tenants = soroban_sdk::Vec::new(&env);
+    for tenant in [&alice, &carol] {
+        tenants.push_back(TenantShare {
+            tenant: tenant.clone(),
+            share_bps: 5_000,
+        });
+    }
+    client.set_co_tenants(&landlord, &agreement_id, &tenants, &None);
+
+    let page = client.list_agreements_by_tenant(&bob, &None, &0, &10);
+    assert_eq!(page.agreements.len(), 0);
+    assert_eq!(page.next_start, None);
+    let page = client.list_agreements_by_tenant(&carol, &None, &0, &10);
+    assert_eq!(page.agreements.len(), 1);
+    let page = client.list_agreements_by_tenant(&alice, &None, &0, &10);
+    assert_eq!(page.agreements.len(), 1);
+}
 
-    assert!(client.has_agreement(&agreement_id));
+fn terms_change(env: &Env, monthly_rent: i128) -> TermsChange {
+    TermsChange {
+        agent: None,
+        monthly_rent,
+        security_deposit: 2000,
+        start_date: 100,
+        end_date: 1000000,
+        agent_commission_rate: 0,
+        payment_token: Address::generate(env),
+        document_hash: BytesN::from_array(env, &[1; 32]),
+        document_cid: None,
+    }
 }
 
 #[test]
-fn test_get_agreement_count() {
+fn test_amend_draft_agreement() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -996,179 +1744,1868 @@ fn test_get_agreement_count() {
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
-    assert_eq!(client.get_agreement_count(), 0);
+    let agreement_id = String::from_str(&env, "AMEND_001");
+    create_pending_agreement(&env, &client, "AMEND_001", &tenant, &landlord);
 
-    client.create_agreement(
-        &String::from_str(&env, "COUNT_001"),
-        &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
-    );
+    // Pending agreements are negotiated through counter-offers instead
+    let res = client.try_amend_agreement(&landlord, &agreement_id, &terms_change(&env, 1100));
+    assert_eq!(res, Err(Ok(RentalError::InvalidState)));
 
-    assert_eq!(client.get_agreement_count(), 1);
+    env.as_contract(&client.address, || {
+        let mut agreement: RentAgreement = env
+            .storage()
+            .persistent()
+            .get(&storage::DataKey::Agreement(agreement_id.clone()))
+            .unwrap();
+        agreement.status = AgreementStatus::Draft;
+        env.storage().persistent().set(
+            &storage::DataKey::Agreement(agreement_id.clone()),
+            &agreement,
+        );
+    });
 
-    client.create_agreement(
-        &String::from_str(&env, "COUNT_002"),
-        &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &2000,
-        &100,
-        &200,
-        &0,
-        &Address::generate(&env),
-    );
+    let res = client.try_amend_agreement(&tenant, &agreement_id, &terms_change(&env, 1100));
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+    let res = client.try_amend_agreement(&landlord, &agreement_id, &terms_change(&env, 0));
+    assert_eq!(res, Err(Ok(RentalError::InvalidAmount)));
 
-    assert_eq!(client.get_agreement_count(), 2);
+    assert_eq!(client.get_revision(&agreement_id), 0);
+    let change = terms_change(&env, 1100);
+    assert_eq!(client.amend_agreement(&landlord, &agreement_id, &change), 1);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.monthly_rent, 1100);
+    assert_eq!(agreement.payment_token, change.payment_token);
+    assert_eq!(agreement.status, AgreementStatus::Draft);
+    assert_eq!(client.get_revision(&agreement_id), 1);
 }
 
-use proptest::prelude::*;
+#[test]
+fn test_accepted_counter_offer_resets_signatures() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-proptest! {
-    #[test]
-    fn test_fuzz_create_agreement_parameters(
-        monthly_rent in -10000i128..10000i128,
-        security_deposit in -10000i128..10000i128,
-        start_date in 0u64..10000u64,
-        end_date in 0u64..10000u64,
-        agent_commission_rate in 0u32..200u32
-    ) {
-        let env = Env::default();
-        env.mock_all_auths();
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
 
-        let client = create_contract(&env);
-        let tenant = Address::generate(&env);
-        let landlord = Address::generate(&env);
-        let payment_token = Address::generate(&env);
-        let agreement_id = String::from_str(&env, "FUZZ_AGREEMENT");
+    let agreement_id = String::from_str(&env, "COUNTER_001");
+    create_pending_agreement(&env, &client, "COUNTER_001", &tenant, &landlord);
+    let old_hash = client.get_terms_hash(&agreement_id);
+    client.sign_agreement(&landlord, &agreement_id, &old_hash);
 
-        // Disable panic catching since we expect some combinations to fail
-        let result = client.try_create_agreement(
-            &agreement_id,
-            &landlord,
-            &tenant,
-            &None,
-            &monthly_rent,
-            &security_deposit,
-            &start_date,
-            &end_date,
-            &agent_commission_rate,
-            &payment_token,
-        );
+    let res = client.try_propose_counter_offer(&landlord, &agreement_id, &terms_change(&env, 900));
+    assert_eq!(res, Err(Ok(RentalError::NotTenant)));
 
-        let is_valid_rent = monthly_rent > 0;
-        let is_valid_deposit = security_deposit >= 0;
-        let is_valid_dates = start_date < end_date;
-        let is_valid_commission = agent_commission_rate <= 100;
+    client.propose_counter_offer(&tenant, &agreement_id, &terms_change(&env, 900));
+    let res = client.try_propose_counter_offer(&tenant, &agreement_id, &terms_change(&env, 950));
+    assert_eq!(res, Err(Ok(RentalError::CounterOfferPending)));
 
-        let should_succeed = is_valid_rent && is_valid_deposit && is_valid_dates && is_valid_commission;
+    let offer = client.get_counter_offer(&agreement_id).unwrap();
+    assert_eq!(offer.proposer, tenant);
+    assert_eq!(offer.revision, 0);
+    assert_eq!(offer.change.monthly_rent, 900);
 
-        if should_succeed {
-            prop_assert!(result.is_ok());
-        } else {
-            prop_assert!(result.is_err());
-        }
-    }
+    let res = client.try_accept_counter_offer(&tenant, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+    assert_eq!(client.accept_counter_offer(&landlord, &agreement_id), 1);
+    assert_eq!(client.get_counter_offer(&agreement_id), None);
+
+    // The landlord signed the old terms and must sign again
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.monthly_rent, 900);
+    assert_eq!(agreement.status, AgreementStatus::Pending);
+    assert_eq!(client.get_signature(&agreement_id, &landlord), None);
+
+    let res = client.try_sign_agreement(&tenant, &agreement_id, &old_hash);
+    assert_eq!(res, Err(Ok(RentalError::TermsMismatch)));
+
+    let new_hash = client.get_terms_hash(&agreement_id);
+    client.sign_agreement(&tenant, &agreement_id, &new_hash);
+    client.sign_agreement(&landlord, &agreement_id, &new_hash);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().status,
+        AgreementStatus::Active
+    );
 }
 
 #[test]
-fn test_contract_paused_operations() {
+fn test_counter_offer_reject_and_withdraw() {
     let env = Env::default();
     env.mock_all_auths();
-    let client = create_contract(&env);
 
-    let admin = Address::generate(&env);
-    let fee_collector = Address::generate(&env);
+    let client = create_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
-    let payment_token = Address::generate(&env);
 
-    let config = Config {
-        fee_bps: 100,
-        fee_collector: fee_collector.clone(),
-        paused: false,
-    };
-    client.initialize(&admin, &config);
+    let agreement_id = String::from_str(&env, "COUNTER_002");
+    create_pending_agreement(&env, &client, "COUNTER_002", &tenant, &landlord);
+    let terms_hash = client.get_terms_hash(&agreement_id);
+    client.sign_agreement(&tenant, &agreement_id, &terms_hash);
 
-    // Pause contract
-    let paused_config = Config {
-        fee_bps: 100,
-        fee_collector: fee_collector.clone(),
-        paused: true,
-    };
-    client.update_config(&paused_config);
+    let res = client.try_reject_counter_offer(&landlord, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::CounterOfferNotFound)));
 
-    // Verify state is paused
-    let state = client.get_state().unwrap();
-    assert!(state.config.paused);
+    client.propose_counter_offer(&tenant, &agreement_id, &terms_change(&env, 900));
+    client.reject_counter_offer(&landlord, &agreement_id);
+    assert_eq!(client.get_counter_offer(&agreement_id), None);
 
-    // Try create agreement (should fail with ContractPaused = 17)
-    let res = client.try_create_agreement(
-        &String::from_str(&env, "agreement-paused"),
-        &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &500,
-        &100,
-        &200,
-        &10,
-        &payment_token,
-    );
-    assert_eq!(res, Err(Ok(RentalError::ContractPaused)));
+    client.propose_counter_offer(&tenant, &agreement_id, &terms_change(&env, 950));
+    let res = client.try_withdraw_counter_offer(&landlord, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+    client.withdraw_counter_offer(&tenant, &agreement_id);
+    assert_eq!(client.get_counter_offer(&agreement_id), None);
 
-    // Unpause
-    let unpaused_config = Config {
-        fee_bps: 100,
-        fee_collector: fee_collector.clone(),
-        paused: false,
-    };
-    client.update_config(&unpaused_config);
+    // Neither closed offer touched the terms or the tenant's signature
+    assert_eq!(client.get_revision(&agreement_id), 0);
+    assert_eq!(client.get_terms_hash(&agreement_id), terms_hash);
+    assert!(client.get_signature(&agreement_id, &tenant).is_some());
+}
 
-    // Create agreement
-    let agreement_id_str = "agreement-active";
-    let agreement_id = String::from_str(&env, agreement_id_str);
-    client.create_agreement(
-        &agreement_id,
-        &landlord,
-        &tenant,
-        &None,
-        &1000,
-        &500,
-        &100,
-        &200,
-        &10,
-        &payment_token,
-    );
+#[test]
+fn test_stale_counter_offer_is_rejected_and_cleared() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // Manually set to Pending (as create_agreement sets it to Draft)
-    let mut agreement = client.get_agreement(&agreement_id).unwrap();
-    agreement.status = AgreementStatus::Pending;
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
 
-    // We need to use env.as_contract to write to storage
+    let agreement_id = String::from_str(&env, "COUNTER_003");
+    create_pending_agreement(&env, &client, "COUNTER_003", &tenant, &landlord);
+    client.propose_counter_offer(&tenant, &agreement_id, &terms_change(&env, 900));
+
+    // The terms were revised after the offer was made against them
     env.as_contract(&client.address, || {
-        env.storage().persistent().set(
-            &storage::DataKey::Agreement(agreement_id.clone()),
-            &agreement,
-        );
+        env.storage()
+            .persistent()
+            .set(&storage::DataKey::Revision(agreement_id.clone()), &1u32);
     });
+    let res = client.try_accept_counter_offer(&landlord, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::TermsMismatch)));
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().monthly_rent,
+        1000
+    );
 
-    // Pause again
-    client.update_config(&paused_config);
+    // Signing the current terms closes negotiation and the offer with it
+    let terms_hash = client.get_terms_hash(&agreement_id);
+    client.sign_agreement(&tenant, &agreement_id, &terms_hash);
+    client.sign_agreement(&landlord, &agreement_id, &terms_hash);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().status,
+        AgreementStatus::Active
+    );
+    assert_eq!(client.get_counter_offer(&agreement_id), None);
 
-    // Try sign agreement (should fail)
-    let res_sign = client.try_sign_agreement(&tenant, &agreement_id);
-    assert_eq!(res_sign, Err(Ok(RentalError::ContractPaused)));
+    let agreement_id = String::from_str(&env, "COUNTER_004");
+    create_pending_agreement(&env, &client, "COUNTER_004", &tenant, &landlord);
+    client.propose_counter_offer(&tenant, &agreement_id, &terms_change(&env, 900));
+    client.cancel_agreement(&landlord, &agreement_id);
+    assert_eq!(client.get_counter_offer(&agreement_id), None);
+}
 
-    // Unpause and verify success
-    client.update_config(&unpaused_config);
-    let res_sign_success = client.try_sign_agreement(&tenant, &agreement_id);
-    assert!(res_sign_success.is_ok());
+#[test]
+fn test_submit_agreement_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "SUBMIT_001");
+
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    let agreement_before = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement_before.status, AgreementStatus::Draft);
+
+    client.submit_agreement(&landlord, &agreement_id);
+
+    let agreement_after = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement_after.status, AgreementStatus::Pending);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_submit_agreement_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+
+    client.submit_agreement(&landlord, &String::from_str(&env, "NONEXISTENT"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_submit_agreement_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let non_landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "SUBMIT_UNAUTH");
+
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    client.submit_agreement(&non_landlord, &agreement_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_submit_agreement_invalid_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = "SUBMIT_INVALID";
+    create_pending_agreement(&env, &client, agreement_id, &tenant, &landlord);
+
+    client.submit_agreement(&landlord, &String::from_str(&env, agreement_id));
+}
+
+#[test]
+fn test_cancel_agreement_success_draft() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "CANCEL_DRAFT");
+
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    client.cancel_agreement(&landlord, &agreement_id);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Cancelled);
+}
+
+#[test]
+fn test_cancel_agreement_success_pending() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = "CANCEL_PENDING";
+    create_pending_agreement(&env, &client, agreement_id, &tenant, &landlord);
+
+    // The tenant may back out as well as the landlord
+    client.cancel_agreement(&tenant, &String::from_str(&env, agreement_id));
+
+    let event = env.events().all().last().unwrap();
+    let topics: soroban_sdk::Vec<Val> = (
+        Symbol::new(&env, "agr_cancel"),
+        landlord.clone(),
+        tenant.clone(),
+    )
+        .into_val(&env);
+    assert_eq!(event.1, topics);
+
+    let agreement = client
+        .get_agreement(&String::from_str(&env, agreement_id))
+        .unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Cancelled);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_cancel_agreement_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+
+    client.cancel_agreement(&landlord, &String::from_str(&env, "NONEXISTENT"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_cancel_agreement_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let non_landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "CANCEL_UNAUTH");
+
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    client.cancel_agreement(&non_landlord, &agreement_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_cancel_agreement_invalid_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = "CANCEL_INVALID";
+    create_active_agreement(&env, &client, agreement_id, &tenant, &landlord);
+
+    client.cancel_agreement(&landlord, &String::from_str(&env, agreement_id));
+}
+
+#[test]
+fn test_get_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "GET_001");
+
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.monthly_rent, 1000);
+    assert_eq!(agreement.landlord, landlord);
+    assert_eq!(agreement.tenant, tenant);
+}
+
+#[test]
+fn test_has_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "HAS_001");
+
+    assert!(!client.has_agreement(&agreement_id));
+
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    assert!(client.has_agreement(&agreement_id));
+}
+
+#[test]
+fn test_get_agreement_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    assert_eq!(client.get_agreement_count(), 0);
+
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: String::from_str(&env, "COUNT_001"),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    assert_eq!(client.get_agreement_count(), 1);
+
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: String::from_str(&env, "COUNT_002"),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    assert_eq!(client.get_agreement_count(), 2);
+}
+
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_fuzz_create_agreement_parameters(
+        monthly_rent in -10000i128..10000i128,
+        security_deposit in -10000i128..10000i128,
+        start_date in 0u64..10000u64,
+        end_date in 0u64..10000u64,
+        agent_commission_rate in 0u32..200u32
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client = create_contract(&env);
+        let tenant = Address::generate(&env);
+        let landlord = Address::generate(&env);
+        let payment_token = Address::generate(&env);
+        let agreement_id = String::from_str(&env, "FUZZ_AGREEMENT");
+        let property_id = String::from_str(&env, "PROPERTY_001");
+        list_property(&env, &client, &property_id, &landlord);
+
+        // Disable panic catching since we expect some combinations to fail
+        let result = client.try_create_agreement(
+            &landlord,
+            &AgreementTerms {
+                agreement_id: agreement_id.clone(),
+                property_id,
+                landlord: landlord.clone(),
+                tenant: tenant.clone(),
+                agent: None,
+                monthly_rent,
+                security_deposit,
+                start_date,
+                end_date,
+                agent_commission_rate,
+                payment_token: payment_token.clone(),
+                document_hash: BytesN::from_array(&env, &[1; 32]),
+                document_cid: None,
+            },
+        );
+
+        let is_valid_rent = monthly_rent > 0;
+        let is_valid_deposit = security_deposit >= 0;
+        let is_valid_dates = start_date < end_date;
+        let is_valid_commission = agent_commission_rate <= 100;
+
+        let should_succeed = is_valid_rent && is_valid_deposit && is_valid_dates && is_valid_commission;
+
+        if should_succeed {
+            prop_assert!(result.is_ok());
+        } else {
+            prop_assert!(result.is_err());
+        }
+    }
+}
+
+#[test]
+fn test_contract_paused_operations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let fee_collector = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+
+    let config = Config {
+        fee_bps: 100,
+        fee_collector: fee_collector.clone(),
+    };
+    client.initialize(&admin, &config);
+
+    // Pause agreements
+    client.pause(&admin, &Operation::Agreements);
+    assert!(client.is_paused(&Operation::Agreements));
+
+    // Try create agreement (should fail with ContractPaused = 17)
+    let res = client.try_create_agreement(
+        &landlord,
+        &AgreementTerms {
+            agreement_id: String::from_str(&env, "agreement-paused"),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 500,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 10,
+            payment_token: payment_token.clone(),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+    assert_eq!(res, Err(Ok(RentalError::ContractPaused)));
+
+    // Unpause
+    client.unpause(&admin, &Operation::Agreements);
+
+    // Create agreement
+    let agreement_id_str = "agreement-active";
+    let agreement_id = String::from_str(&env, agreement_id_str);
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 500,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 10,
+            payment_token: payment_token.clone(),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    // Manually set to Pending (as create_agreement sets it to Draft)
+    let mut agreement = client.get_agreement(&agreement_id).unwrap();
+    agreement.status = AgreementStatus::Pending;
+
+    // We need to use env.as_contract to write to storage
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &storage::DataKey::Agreement(agreement_id.clone()),
+            &agreement,
+        );
+    });
+
+    // Pause again
+    client.pause(&admin, &Operation::Agreements);
+
+    // Try sign agreement (should fail)
+    let res_sign = client.try_sign_agreement(
+        &tenant,
+        &agreement_id,
+        &client.get_terms_hash(&agreement_id),
+    );
+    assert_eq!(res_sign, Err(Ok(RentalError::ContractPaused)));
+
+    // Unpause and verify success
+    client.unpause(&admin, &Operation::Agreements);
+    let res_sign_success = client.try_sign_agreement(
+        &tenant,
+        &agreement_id,
+        &client.get_terms_hash(&agreement_id),
+    );
+    assert!(res_sign_success.is_ok());
+}
+
+fn create_active_agreement(
+    env: &Env,
+    client: &ContractClient,
+    agreement_id: &str,
+    tenant: &Address,
+    landlord: &Address,
+) {
+    create_pending_agreement(env, client, agreement_id, tenant, landlord);
+    let agreement_id = String::from_str(env, agreement_id);
+    let terms_hash = client.get_terms_hash(&agreement_id);
+    client.sign_agreement(landlord, &agreement_id, &terms_hash);
+    client.sign_agreement(tenant, &agreement_id, &terms_hash);
+}
+
+#[test]
+fn test_set_dispute_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    assert_eq!(client.get_dispute_contract(), None);
+
+    let dispute_contract = Address::generate(&env);
+    client.set_dispute_contract(&dispute_contract);
+
+    assert_eq!(client.get_dispute_contract(), Some(dispute_contract));
+}
+
+#[test]
+fn test_mark_disputed_and_restore() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    client.set_dispute_contract(&Address::generate(&env));
+
+    let agreement_id = String::from_str(&env, "DISPUTE_001");
+    create_active_agreement(&env, &client, "DISPUTE_001", &tenant, &landlord);
+
+    client.mark_disputed(&agreement_id);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().status,
+        AgreementStatus::Disputed
+    );
+
+    client.resolve_dispute(&agreement_id, &false);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().status,
+        AgreementStatus::Active
+    );
+}
+
+#[test]
+fn test_mark_disputed_and_terminate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    client.set_dispute_contract(&Address::generate(&env));
+
+    let agreement_id = String::from_str(&env, "DISPUTE_002");
+    create_active_agreement(&env, &client, "DISPUTE_002", &tenant, &landlord);
+
+    client.mark_disputed(&agreement_id);
+    client.resolve_dispute(&agreement_id, &true);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().status,
+        AgreementStatus::Terminated
+    );
+}
+
+#[test]
+fn test_mark_disputed_requires_dispute_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let agreement_id = String::from_str(&env, "DISPUTE_003");
+    create_active_agreement(&env, &client, "DISPUTE_003", &tenant, &landlord);
+
+    let res = client.try_mark_disputed(&agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::DisputeContractNotSet)));
+}
+
+#[test]
+fn test_mark_disputed_rejects_inactive_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    client.set_dispute_contract(&Address::generate(&env));
+
+    let agreement_id = String::from_str(&env, "DISPUTE_004");
+    create_pending_agreement(&env, &client, "DISPUTE_004", &tenant, &landlord);
+
+    let res = client.try_mark_disputed(&agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::AgreementNotActive)));
+
+    let res = client.try_resolve_dispute(&agreement_id, &false);
+    assert_eq!(res, Err(Ok(RentalError::InvalidState)));
+}
+
+fn open_repair(
+    env: &Env,
+    client: &ContractClient,
+    agreement_id: &str,
+    tenant: &Address,
+    priority: MaintenancePriority,
+) -> u32 {
+    client.open_maintenance_request(
+        tenant,
+        &String::from_str(env, agreement_id),
+        &String::from_str(env, "QmLeakingRoof"),
+        &priority,
+    )
+}
+
+#[test]
+fn test_maintenance_request_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "MAINT_001", &tenant, &landlord);
+
+    let request_id = open_repair(
+        &env,
+        &client,
+        "MAINT_001",
+        &tenant,
+        MaintenancePriority::High,
+    );
+    assert_eq!(request_id, 1);
+
+    let request = client.get_maintenance_request(&request_id).unwrap();
+    let sla = default_sla(&MaintenancePriority::High);
+    assert_eq!(request.status, MaintenanceStatus::Open);
+    assert_eq!(request.landlord, landlord);
+    assert_eq!(request.acknowledge_by, sla.acknowledge_within);
+    assert_eq!(request.complete_by, sla.complete_within);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.acknowledge_maintenance(&landlord, &request_id);
+    client.schedule_maintenance(&landlord, &request_id, &5_000);
+
+    env.ledger().with_mut(|l| l.timestamp = 5_000);
+    client.complete_maintenance(&landlord, &request_id);
+
+    let request = client.get_maintenance_request(&request_id).unwrap();
+    assert_eq!(request.status, MaintenanceStatus::Completed);
+    assert_eq!(request.acknowledged_at, Some(1_000));
+    assert_eq!(request.scheduled_for, Some(5_000));
+    assert_eq!(request.completed_at, Some(5_000));
+    assert_eq!(request.acknowledgement_breached_at, None);
+    assert_eq!(request.completion_breached_at, None);
+
+    let res = client.try_flag_sla_breach(&request_id);
+    assert_eq!(res, Err(Ok(RentalError::SlaNotBreached)));
+}
+
+#[test]
+fn test_open_maintenance_request_restrictions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "MAINT_002", &tenant, &landlord);
+    create_pending_agreement(&env, &client, "MAINT_003", &tenant, &landlord);
+
+    let res = client.try_open_maintenance_request(
+        &landlord,
+        &String::from_str(&env, "MAINT_002"),
+        &String::from_str(&env, "QmLeakingRoof"),
+        &MaintenancePriority::Low,
+    );
+    assert_eq!(res, Err(Ok(RentalError::NotTenant)));
+
+    let res = client.try_open_maintenance_request(
+        &tenant,
+        &String::from_str(&env, "MAINT_002"),
+        &String::from_str(&env, ""),
+        &MaintenancePriority::Low,
+    );
+    assert_eq!(res, Err(Ok(RentalError::InvalidMaintenanceRequest)));
+
+    let res = client.try_open_maintenance_request(
+        &tenant,
+        &String::from_str(&env, "MAINT_003"),
+        &String::from_str(&env, "QmLeakingRoof"),
+        &MaintenancePriority::Low,
+    );
+    assert_eq!(res, Err(Ok(RentalError::AgreementNotActive)));
+}
+
+#[test]
+fn test_maintenance_landlord_actions_restricted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "MAINT_004", &tenant, &landlord);
+
+    let request_id = open_repair(
+        &env,
+        &client,
+        "MAINT_004",
+        &tenant,
+        MaintenancePriority::Low,
+    );
+
+    let res = client.try_acknowledge_maintenance(&tenant, &request_id);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+
+    let res = client.try_acknowledge_maintenance(&landlord, &99);
+    assert_eq!(res, Err(Ok(RentalError::MaintenanceRequestNotFound)));
+
+    // Work cannot be scheduled or completed before it is acknowledged
+    let res = client.try_schedule_maintenance(&landlord, &request_id, &500);
+    assert_eq!(res, Err(Ok(RentalError::InvalidState)));
+    let res = client.try_complete_maintenance(&landlord, &request_id);
+    assert_eq!(res, Err(Ok(RentalError::InvalidState)));
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    client.acknowledge_maintenance(&landlord, &request_id);
+
+    let res = client.try_acknowledge_maintenance(&landlord, &request_id);
+    assert_eq!(res, Err(Ok(RentalError::InvalidState)));
+
+    let res = client.try_schedule_maintenance(&landlord, &request_id, &500);
+    assert_eq!(res, Err(Ok(RentalError::InvalidDate)));
+}
+
+#[test]
+fn test_flag_unanswered_maintenance_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "MAINT_005", &tenant, &landlord);
+
+    let request_id = open_repair(
+        &env,
+        &client,
+        "MAINT_005",
+        &tenant,
+        MaintenancePriority::Emergency,
+    );
+    let request = client.get_maintenance_request(&request_id).unwrap();
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = request.acknowledge_by);
+    let res = client.try_flag_sla_breach(&request_id);
+    assert_eq!(res, Err(Ok(RentalError::SlaNotBreached)));
+
+    let flagged_at = request.acknowledge_by + 1;
+    env.ledger().with_mut(|l| l.timestamp = flagged_at);
+    assert_eq!(
+        client.flag_sla_breach(&request_id),
+        SlaBreach::Acknowledgement
+    );
+
+    // The breach stays on record once the landlord responds, and a late
+    // completion is recorded beside it
+    client.acknowledge_maintenance(&landlord, &request_id);
+    env.ledger()
+        .with_mut(|l| l.timestamp = request.complete_by + 1);
+    client.complete_maintenance(&landlord, &request_id);
+
+    let completed = client.get_maintenance_request(&request_id).unwrap();
+    assert_eq!(completed.status, MaintenanceStatus::Completed);
+    assert_eq!(completed.acknowledgement_breached_at, Some(flagged_at));
+    assert_eq!(
+        completed.completion_breached_at,
+        Some(request.complete_by + 1)
+    );
+
+    let res = client.try_flag_sla_breach(&request_id);
+    assert_eq!(res, Err(Ok(RentalError::SlaNotBreached)));
+}
+
+#[test]
+fn test_late_completion_records_breach() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "MAINT_006", &tenant, &landlord);
+
+    client.set_maintenance_sla(
+        &MaintenancePriority::Medium,
+        &MaintenanceSla {
+            acknowledge_within: 100,
+            complete_within: 1_000,
+        },
+    );
+    let request_id = open_repair(
+        &env,
+        &client,
+        "MAINT_006",
+        &tenant,
+        MaintenancePriority::Medium,
+    );
+
+    client.acknowledge_maintenance(&landlord, &request_id);
+    env.ledger().with_mut(|l| l.timestamp = 1_001);
+    client.complete_maintenance(&landlord, &request_id);
+
+    let request = client.get_maintenance_request(&request_id).unwrap();
+    assert_eq!(request.complete_by, 1_000);
+    assert_eq!(request.acknowledgement_breached_at, None);
+    assert_eq!(request.completion_breached_at, Some(1_001));
+}
+
+#[test]
+fn test_flag_records_every_missed_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "MAINT_007", &tenant, &landlord);
+
+    let request_id = open_repair(
+        &env,
+        &client,
+        "MAINT_007",
+        &tenant,
+        MaintenancePriority::Emergency,
+    );
+    let request = client.get_maintenance_request(&request_id).unwrap();
+
+    // A request left unanswered past both deadlines records both at once
+    let flagged_at = request.complete_by + 1;
+    env.ledger().with_mut(|l| l.timestamp = flagged_at);
+    assert_eq!(client.flag_sla_breach(&request_id), SlaBreach::Completion);
+    assert_eq!(env.events().all().len(), 2);
+
+    let request = client.get_maintenance_request(&request_id).unwrap();
+    assert_eq!(request.acknowledgement_breached_at, Some(flagged_at));
+    assert_eq!(request.completion_breached_at, Some(flagged_at));
+
+    let res = client.try_flag_sla_breach(&request_id);
+    assert_eq!(res, Err(Ok(RentalError::SlaNotBreached)));
+}
+
+#[test]
+fn test_set_maintenance_sla_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    assert_eq!(
+        client.get_maintenance_sla(&MaintenancePriority::Low),
+        default_sla(&MaintenancePriority::Low)
+    );
+
+    let res = client.try_set_maintenance_sla(
+        &MaintenancePriority::Low,
+        &MaintenanceSla {
+            acknowledge_within: 0,
+            complete_within: 100,
+        },
+    );
+    assert_eq!(res, Err(Ok(RentalError::InvalidConfig)));
+
+    let res = client.try_set_maintenance_sla(
+        &MaintenancePriority::Low,
+        &MaintenanceSla {
+            acknowledge_within: 200,
+            complete_within: 100,
+        },
+    );
+    assert_eq!(res, Err(Ok(RentalError::InvalidConfig)));
+}
+
+#[test]
+fn test_list_agreement_maintenance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "MAINT_007", &tenant, &landlord);
+    create_active_agreement(&env, &client, "MAINT_008", &tenant, &landlord);
+
+    open_repair(
+        &env,
+        &client,
+        "MAINT_007",
+        &tenant,
+        MaintenancePriority::Low,
+    );
+    open_repair(
+        &env,
+        &client,
+        "MAINT_008",
+        &tenant,
+        MaintenancePriority::Low,
+    );
+    open_repair(
+        &env,
+        &client,
+        "MAINT_007",
+        &tenant,
+        MaintenancePriority::High,
+    );
+
+    let agreement_id = String::from_str(&env, "MAINT_007");
+    assert_eq!(client.get_maintenance_count(), 3);
+    assert_eq!(client.get_agreement_maintenance_count(&agreement_id), 2);
+
+    let page = client.list_agreement_maintenance(&agreement_id, &0, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(page.get(1).unwrap().id, 3);
+
+    let page = client.list_agreement_maintenance(&agreement_id, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().priority, MaintenancePriority::High);
+}
+
+#[test]
+fn test_rent_change_accept_and_apply() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "RENT_001", &tenant, &landlord);
+    let agreement_id = String::from_str(&env, "RENT_001");
+
+    let effective_from = DEFAULT_NOTICE_PERIOD;
+    client.propose_rent_change(&landlord, &agreement_id, &1100, &effective_from, &false);
+
+    let proposal = client.get_rent_proposal(&agreement_id).unwrap();
+    assert_eq!(proposal.current_rent, 1000);
+    assert_eq!(proposal.proposed_rent, 1100);
+    assert_eq!(proposal.status, RentProposalStatus::Pending);
+
+    // Only one proposal may be outstanding
+    let res =
+        client.try_propose_rent_change(&landlord, &agreement_id, &1200, &effective_from, &false);
+    assert_eq!(res, Err(Ok(RentalError::RentProposalPending)));
+
+    client.accept_rent_change(&tenant, &agreement_id);
+    let res = client.try_apply_rent_change(&agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::InvalidDate)));
+
+    env.ledger().with_mut(|l| l.timestamp = effective_from);
+    client.apply_rent_change(&agreement_id);
+
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().monthly_rent,
+        1100
+    );
+    assert_eq!(
+        client.get_rent_proposal(&agreement_id).unwrap().status,
+        RentProposalStatus::Applied
+    );
+}
+
+#[test]
+fn test_rent_change_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "RENT_002", &tenant, &landlord);
+    let agreement_id = String::from_str(&env, "RENT_002");
+
+    let res = client.try_propose_rent_change(
+        &tenant,
+        &agreement_id,
+        &1100,
+        &DEFAULT_NOTICE_PERIOD,
+        &false,
+    );
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+
+    let res = client.try_propose_rent_change(
+        &landlord,
+        &agreement_id,
+        &1000,
+        &DEFAULT_NOTICE_PERIOD,
+        &false,
+    );
+    assert_eq!(res, Err(Ok(RentalError::InvalidAmount)));
+
+    let res = client.try_propose_rent_change(
+        &landlord,
+        &agreement_id,
+        &1100,
+        &(DEFAULT_NOTICE_PERIOD - 1),
+        &false,
+    );
+    assert_eq!(res, Err(Ok(RentalError::InvalidDate)));
+
+    let res = client.try_propose_rent_change(&landlord, &agreement_id, &1100, &(3 * YEAR), &false);
+    assert_eq!(res, Err(Ok(RentalError::InvalidDate)));
+
+    let res = client.try_accept_rent_change(&tenant, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::RentProposalNotFound)));
+}
+
+#[test]
+fn test_reject_rent_change_with_termination_notice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "RENT_003", &tenant, &landlord);
+    let agreement_id = String::from_str(&env, "RENT_003");
+
+    let effective_from = 2 * DEFAULT_NOTICE_PERIOD;
+    client.propose_rent_change(&landlord, &agreement_id, &1500, &effective_from, &true);
+    client.reject_rent_change(&tenant, &agreement_id);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.end_date, effective_from);
+    assert_eq!(agreement.monthly_rent, 1000);
+    assert_eq!(
+        client.get_rent_proposal(&agreement_id).unwrap().status,
+        RentProposalStatus::Rejected
+    );
+}
+
+#[test]
+fn test_rent_change_lapses_and_can_be_withdrawn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "RENT_004", &tenant, &landlord);
+    let agreement_id = String::from_str(&env, "RENT_004");
+
+    client.propose_rent_change(
+        &landlord,
+        &agreement_id,
+        &1100,
+        &DEFAULT_NOTICE_PERIOD,
+        &true,
+    );
+    client.withdraw_rent_change(&landlord, &agreement_id);
+    let res = client.try_reject_rent_change(&tenant, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::RentProposalNotFound)));
+
+    client.propose_rent_change(
+        &landlord,
+        &agreement_id,
+        &1100,
+        &DEFAULT_NOTICE_PERIOD,
+        &true,
+    );
+    env.ledger()
+        .with_mut(|l| l.timestamp = DEFAULT_NOTICE_PERIOD);
+    let res = client.try_accept_rent_change(&tenant, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::Expired)));
+
+    // A lapsed proposal no longer blocks a new one
+    client.propose_rent_change(
+        &landlord,
+        &agreement_id,
+        &1100,
+        &(2 * DEFAULT_NOTICE_PERIOD),
+        &false,
+    );
+}
+
+#[test]
+fn test_rent_control_caps_increases() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let lagos = String::from_str(&env, "NG-LA");
+    let control = RentControl {
+        max_increase_bps: 1_000,
+        notice_period: 60 * 86400,
+    };
+    client.set_rent_control(&lagos, &control);
+    assert_eq!(client.get_rent_control(&lagos), Some(control.clone()));
+
+    create_active_agreement(&env, &client, "RENT_005", &tenant, &landlord);
+    let agreement_id = String::from_str(&env, "RENT_005");
+
+    // Only the admin, not the landlord, can take the lease out of rent control
+    client.set_agreement_jurisdiction(&agreement_id, &lagos);
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(
+        client.get_agreement_jurisdiction(&agreement_id),
+        Some(lagos.clone())
+    );
+
+    let res = client.try_propose_rent_change(
+        &landlord,
+        &agreement_id,
+        &1100,
+        &DEFAULT_NOTICE_PERIOD,
+        &false,
+    );
+    assert_eq!(res, Err(Ok(RentalError::InvalidDate)));
+
+    let first = control.notice_period;
+    let res = client.try_propose_rent_change(&landlord, &agreement_id, &1101, &first, &false);
+    assert_eq!(res, Err(Ok(RentalError::RentIncreaseExceedsCap)));
+
+    client.propose_rent_change(&landlord, &agreement_id, &1100, &first, &false);
+    client.accept_rent_change(&tenant, &agreement_id);
+    env.ledger().with_mut(|l| l.timestamp = first);
+    client.apply_rent_change(&agreement_id);
+
+    let res = client.try_propose_rent_change(
+        &landlord,
+        &agreement_id,
+        &1200,
+        &(first + YEAR - 1),
+        &false,
+    );
+    assert_eq!(res, Err(Ok(RentalError::RentIncreaseTooSoon)));
+
+    // Decreases are not capped
+    client.propose_rent_change(&landlord, &agreement_id, &900, &(2 * first), &false);
+    client.withdraw_rent_change(&landlord, &agreement_id);
+
+    client.propose_rent_change(&landlord, &agreement_id, &1210, &(first + YEAR), &false);
+}
+
+/// Creates a shared Draft agreement at a monthly rent of 1000 paid in a fresh
+/// token, and mints 10_000 of it to each tenant and the guarantor.
+fn create_shared_agreement(
+    env: &Env,
+    client: &ContractClient,
+    agreement_id: &str,
+    landlord: &Address,
+    shares: &[(Address, u32)],
+    guarantor: Option<Address>,
+) -> (String, Address) {
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let minter = soroban_sdk::token::StellarAssetClient::new(env, &token);
+
+    create_listed_agreement(
+        env,
+        client,
+        landlord,
+        &AgreementTerms {
+            payment_token: token.clone(),
+            ..draft_terms(env, agreement_id, &shares[0].0, landlord)
+        },
+    );
+    let agreement_id = String::from_str(env, agreement_id);
+
+    let mut tenants = soroban_sdk::Vec::new(env);
+    for (tenant, share_bps) in shares {
+        tenants.push_back(TenantShare {
+            tenant: tenant.clone(),
+            share_bps: *share_bps,
+        });
+        minter.mint(tenant, &10_000);
+    }
+    if let Some(guarantor) = &guarantor {
+        minter.mint(guarantor, &10_000);
+    }
+    client.set_co_tenants(landlord, &agreement_id, &tenants, &guarantor);
+
+    (agreement_id, token)
+}
+
+#[test]
+fn test_co_tenants_must_all_sign() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let guarantor = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let (agreement_id, _) = create_shared_agreement(
+        &env,
+        &client,
+        "SHARED_001",
+        &landlord,
+        &[(alice.clone(), 6_000), (bob.clone(), 4_000)],
+        Some(guarantor.clone()),
+    );
+    client.submit_agreement(&landlord, &agreement_id);
+
+    let terms_hash = client.get_terms_hash(&agreement_id);
+    client.sign_agreement(&bob, &agreement_id, &terms_hash);
+    client.sign_agreement(&alice, &agreement_id, &terms_hash);
+    client.sign_agreement(&landlord, &agreement_id, &terms_hash);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().status,
+        AgreementStatus::Pending
+    );
+    assert!(client.get_signature(&agreement_id, &bob).is_some());
+    assert_eq!(client.get_signature(&agreement_id, &guarantor), None);
+
+    let res = client.try_sign_agreement(&bob, &agreement_id, &terms_hash);
+    assert_eq!(res, Err(Ok(RentalError::InvalidState)));
+    let res = client.try_sign_agreement(&Address::generate(&env), &agreement_id, &terms_hash);
+    assert_eq!(res, Err(Ok(RentalError::NotTenant)));
+
+    client.sign_agreement(&guarantor, &agreement_id, &terms_hash);
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Active);
+    assert_eq!(agreement.signed_at, Some(0));
+}
+
+#[test]
+fn test_terms_hash_covers_co_tenancy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &draft_terms(&env, "SHARED_HASH", &alice, &landlord),
+    );
+    let agreement_id = String::from_str(&env, "SHARED_HASH");
+    let sole = client.get_terms_hash(&agreement_id);
+
+    let shares = |alice_bps: u32| {
+        soroban_sdk::vec![
+            &env,
+            TenantShare {
+                tenant: alice.clone(),
+                share_bps: alice_bps,
+            },
+            TenantShare {
+                tenant: bob.clone(),
+                share_bps: 10_000 - alice_bps,
+            },
+        ]
+    };
+
+    client.set_co_tenants(&landlord, &agreement_id, &shares(6_000), &None);
+    let shared = client.get_terms_hash(&agreement_id);
+    assert_ne!(shared, sole);
+
+    client.set_co_tenants(&landlord, &agreement_id, &shares(5_000), &None);
+    let resplit = client.get_terms_hash(&agreement_id);
+    assert_ne!(resplit, shared);
+
+    client.set_co_tenants(
+        &landlord,
+        &agreement_id,
+        &shares(5_000),
+        &Some(Address::generate(&env)),
+    );
+    assert_ne!(client.get_terms_hash(&agreement_id), resplit);
+}
+
+#[test]
+fn test_rent_change_needs_every_co_tenant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let (agreement_id, _) = create_shared_agreement(
+        &env,
+        &client,
+        "SHARED_RENT",
+        &landlord,
+        &[(alice.clone(), 5_000), (bob.clone(), 5_000)],
+        None,
+    );
+    client.submit_agreement(&landlord, &agreement_id);
+    let terms_hash = client.get_terms_hash(&agreement_id);
+    for signer in [&landlord, &alice, &bob] {
+        client.sign_agreement(signer, &agreement_id, &terms_hash);
+    }
+
+    let effective_from = DEFAULT_NOTICE_PERIOD;
+    client.propose_rent_change(&landlord, &agreement_id, &1100, &effective_from, &false);
+
+    // The lead tenant alone cannot bind the co-tenant
+    client.accept_rent_change(&alice, &agreement_id);
+    let proposal = client.get_rent_proposal(&agreement_id).unwrap();
+    assert_eq!(proposal.status, RentProposalStatus::Pending);
+    assert_eq!(proposal.accepted_by, soroban_sdk::vec![&env, alice.clone()]);
+    let res = client.try_accept_rent_change(&alice, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::InvalidState)));
+
+    env.ledger().with_mut(|l| l.timestamp = effective_from - 1);
+    client.accept_rent_change(&bob, &agreement_id);
+    assert_eq!(
+        client.get_rent_proposal(&agreement_id).unwrap().status,
+        RentProposalStatus::Accepted
+    );
+    client.pay_rent_share(&alice, &agreement_id);
+
+    env.ledger().with_mut(|l| l.timestamp = effective_from);
+    client.apply_rent_change(&agreement_id);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().monthly_rent,
+        1100
+    );
+
+    // The period Alice started keeps the rent she paid against
+    assert_eq!(client.get_rent_shortfall(&agreement_id), 500);
+    client.pay_rent_share(&bob, &agreement_id);
+    let period = client.get_rent_period(&agreement_id, &0).unwrap();
+    assert_eq!(period.rent, 1000);
+    assert_eq!(period.amount_paid, 1000);
+    assert_eq!(client.get_rent_shortfall(&agreement_id), 1100);
+}
+
+#[test]
+fn test_set_co_tenants_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let agreement_id = String::from_str(&env, "SHARED_002");
+    create_listed_agreement(
+        &env,
+        &client,
+        &landlord,
+        &AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id: String::from_str(&env, "PROPERTY_001"),
+            landlord: landlord.clone(),
+            tenant: alice.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 3 * YEAR,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(&env),
+            document_hash: BytesN::from_array(&env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+
+    let share = |tenant: &Address, share_bps: u32| TenantShare {
+        tenant: tenant.clone(),
+        share_bps,
+    };
+    let invalid = [
+        // Shares must total 10,000 bps
+        soroban_sdk::vec![&env, share(&alice, 5_000), share(&bob, 4_000)],
+        // The lead tenant must be included
+        soroban_sdk::vec![&env, share(&bob, 10_000)],
+        // No tenant may appear twice
+        soroban_sdk::vec![&env, share(&alice, 5_000), share(&alice, 5_000)],
+        // Every tenant must pay something
+        soroban_sdk::vec![&env, share(&alice, 10_000), share(&bob, 0)],
+    ];
+    for tenants in invalid {
+        let res = client.try_set_co_tenants(&landlord, &agreement_id, &tenants, &None);
+        assert_eq!(res, Err(Ok(RentalError::InvalidShares)));
+    }
+
+    let tenants = soroban_sdk::vec![&env, share(&alice, 5_000), share(&bob, 5_000)];
+    let res = client.try_set_co_tenants(&landlord, &agreement_id, &tenants, &Some(bob.clone()));
+    assert_eq!(res, Err(Ok(RentalError::InvalidShares)));
+
+    let res = client.try_set_co_tenants(&alice, &agreement_id, &tenants, &None);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+
+    client.submit_agreement(&landlord, &agreement_id);
+    let res = client.try_set_co_tenants(&landlord, &agreement_id, &tenants, &None);
+    assert_eq!(res, Err(Ok(RentalError::InvalidState)));
+}
+
+#[test]
+fn test_co_tenants_pay_shares_independently() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    let fee_collector = client.get_state().unwrap().config.fee_collector;
+
+    let (agreement_id, token) = create_shared_agreement(
+        &env,
+        &client,
+        "SHARED_003",
+        &landlord,
+        &[
+            (alice.clone(), 3_334),
+            (bob.clone(), 3_333),
+            (carol.clone(), 3_333),
+        ],
+        None,
+    );
+    client.submit_agreement(&landlord, &agreement_id);
+    for signer in [&landlord, &alice, &bob, &carol] {
+        client.sign_agreement(signer, &agreement_id, &client.get_terms_hash(&agreement_id));
+    }
+
+    assert_eq!(client.pay_rent_share(&bob, &agreement_id), 0);
+    let res = client.try_pay_rent_share(&bob, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::ShareAlreadyPaid)));
+    let res = client.try_pay_rent_share(&landlord, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::NotTenant)));
+
+    // The lead tenant carries the rounding remainder
+    assert_eq!(client.get_rent_shortfall(&agreement_id), 667);
+    client.pay_rent_share(&carol, &agreement_id);
+    client.pay_rent_share(&alice, &agreement_id);
+
+    let token_client = soroban_sdk::token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&alice), 10_000 - 334);
+    assert_eq!(token_client.balance(&bob), 10_000 - 333);
+
+    let agreement = client.get_agreement(&agreement_id).unwrap();
+    assert_eq!(agreement.payment_count, 1);
+    assert_eq!(agreement.total_rent_paid, 1000);
+    assert_eq!(agreement.next_payment_due, 100 + RENT_PERIOD);
+
+    let split = client.get_payment_split(&agreement_id, &0);
+    assert_eq!(split.landlord_amount + split.platform_amount, 1000);
+    assert_eq!(token_client.balance(&landlord), split.landlord_amount);
+    assert_eq!(token_client.balance(&fee_collector), split.platform_amount);
+    assert_eq!(
+        client.list_payments(&agreement_id, &0, &10),
+        soroban_sdk::vec![&env, split]
+    );
+    assert!(agreement.payment_history.is_empty());
+
+    // The next period opens once every share is in, and can be paid from
+    // the previous due date on
+    assert_eq!(client.get_rent_shortfall(&agreement_id), 1000);
+    let res = client.try_pay_rent_share(&bob, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::PaymentNotDue)));
+    env.ledger().with_mut(|l| l.timestamp = 100);
+    assert_eq!(client.pay_rent_share(&bob, &agreement_id), 1);
+}
+
+#[test]
+fn test_payment_lookups_distinguish_missing_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    create_active_agreement(&env, &client, "UNPAID_001", &tenant, &landlord);
+    let agreement_id = String::from_str(&env, "UNPAID_001");
+
+    let res = client.try_get_payment_split(&agreement_id, &0);
+    assert_eq!(res, Err(Ok(RentalError::PaymentNotFound)));
+    assert!(client.list_payments(&agreement_id, &0, &10).is_empty());
+
+    let missing = String::from_str(&env, "MISSING");
+    let res = client.try_get_payment_split(&missing, &0);
+    assert_eq!(res, Err(Ok(RentalError::AgreementNotFound)));
+    let res = client.try_list_payments(&missing, &0, &10);
+    assert_eq!(res, Err(Ok(RentalError::AgreementNotFound)));
+}
+
+#[test]
+fn test_guarantor_covers_overdue_shortfall() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let guarantor = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let (agreement_id, token) = create_shared_agreement(
+        &env,
+        &client,
+        "SHARED_004",
+        &landlord,
+        &[(alice.clone(), 5_000), (bob.clone(), 5_000)],
+        Some(guarantor.clone()),
+    );
+    client.submit_agreement(&landlord, &agreement_id);
+    for signer in [&landlord, &alice, &bob, &guarantor] {
+        client.sign_agreement(signer, &agreement_id, &client.get_terms_hash(&agreement_id));
+    }
+
+    client.pay_rent_share(&alice, &agreement_id);
+
+    // Bob still has until the due date
+    let res = client.try_cover_shortfall(&guarantor, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::NoShortfall)));
+
+    env.ledger().with_mut(|l| l.timestamp = 101);
+    let res = client.try_cover_shortfall(&alice, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::Unauthorized)));
+
+    assert_eq!(client.cover_shortfall(&guarantor, &agreement_id), 500);
+    assert_eq!(
+        client.get_share_payer(&agreement_id, &0, &bob),
+        Some(guarantor.clone())
+    );
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &token).balance(&guarantor),
+        9_500
+    );
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().payment_count,
+        1
+    );
+
+    let period = client.get_rent_period(&agreement_id, &0).unwrap();
+    assert_eq!(period.amount_paid, 1000);
+    assert_eq!(period.shares_paid, 2);
+}
+
+#[test]
+fn test_no_rent_owed_past_end_of_lease() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let guarantor = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let (agreement_id, token) = create_shared_agreement(
+        &env,
+        &client,
+        "SHARED_005",
+        &landlord,
+        &[(alice.clone(), 5_000), (bob.clone(), 5_000)],
+        Some(guarantor.clone()),
+    );
+    client.submit_agreement(&landlord, &agreement_id);
+    for signer in [&landlord, &alice, &bob, &guarantor] {
+        client.sign_agreement(signer, &agreement_id, &client.get_terms_hash(&agreement_id));
+    }
+
+    // Shorten the lease to two rent periods
+    env.as_contract(&client.address, || {
+        let key = storage::DataKey::Agreement(agreement_id.clone());
+        let mut agreement: RentAgreement = env.storage().persistent().get(&key).unwrap();
+        agreement.end_date = agreement.start_date + 2 * RENT_PERIOD;
+        env.storage().persistent().set(&key, &agreement);
+    });
+
+    client.pay_rent_share(&alice, &agreement_id);
+    client.pay_rent_share(&bob, &agreement_id);
+
+    // The last period is still owed, and the guarantor still answers for it
+    env.ledger().with_mut(|l| l.timestamp = 100);
+    assert_eq!(client.pay_rent_share(&alice, &agreement_id), 1);
+    env.ledger().with_mut(|l| l.timestamp = 101 + RENT_PERIOD);
+    assert_eq!(client.cover_shortfall(&guarantor, &agreement_id), 500);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().payment_count,
+        2
+    );
+
+    // The period after it falls outside the lease
+    assert_eq!(client.get_rent_shortfall(&agreement_id), 0);
+    env.ledger()
+        .with_mut(|l| l.timestamp = 101 + 2 * RENT_PERIOD);
+    let res = client.try_pay_rent_share(&alice, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::Expired)));
+    let res = client.try_cover_shortfall(&guarantor, &agreement_id);
+    assert_eq!(res, Err(Ok(RentalError::Expired)));
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &token).balance(&guarantor),
+        9_500
+    );
 }
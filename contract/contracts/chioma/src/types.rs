@@ -1,21 +1,44 @@
-use soroban_sdk::{contracttype, Address, Map, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
+pub use chioma_types::{
+    AgreementStatus, CoTenancy, MaintenancePriority, MaintenanceRequest, MaintenanceStatus,
+    PaymentSplit, RentAgreement, Role, SlaBreach, TenantShare,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Config {
+    pub fee_bps: u32,
+    pub fee_collector: Address,
+}
+
+/// `Config` as schema versions before 2 stored it, with a contract-wide
+/// pause flag
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum AgreementStatus {
-    Draft,
-    Pending,
-    Active,
-    Completed,
-    Cancelled,
-    Terminated,
-    Disputed,
+pub struct LegacyConfig {
+    pub fee_bps: u32,
+    pub fee_collector: Address,
+    pub paused: bool,
+}
+
+/// `ContractState` as schema versions before 2 stored it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyContractState {
+    pub admin: Address,
+    pub config: LegacyConfig,
+    pub initialized: bool,
 }
 
+/// The terms every party signs. Signers pass the SHA-256 of this struct's XDR
+/// encoding to `sign_agreement`, so a signature binds exactly these values,
+/// including the hash of the lease document itself.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct RentAgreement {
+pub struct AgreementTerms {
     pub agreement_id: String,
+    pub property_id: String,
     pub landlord: Address,
     pub tenant: Address,
     pub agent: Option<Address>,
@@ -24,31 +47,111 @@ pub struct RentAgreement {
     pub start_date: u64,
     pub end_date: u64,
     pub agent_commission_rate: u32,
-    pub status: AgreementStatus,
-    pub total_rent_paid: i128,
-    pub payment_count: u32,
-    pub signed_at: Option<u64>,
     pub payment_token: Address,
-    pub next_payment_due: u64,
-    pub payment_history: Map<u32, PaymentSplit>,
+    /// SHA-256 of the lease document
+    pub document_hash: BytesN<32>,
+    /// IPFS CID of the lease document, if pinned
+    pub document_cid: Option<String>,
 }
 
+/// The lease document the parties sign, stored beside its agreement.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PaymentSplit {
-    pub landlord_amount: i128,
-    pub platform_amount: i128,
-    pub token: Address,
-    pub payment_date: u64,
-    pub payer: Address,
+pub struct LeaseDocument {
+    /// SHA-256 of the lease document
+    pub hash: BytesN<32>,
+    /// Where the lease document is pinned on IPFS, if anywhere
+    pub cid: Option<String>,
 }
 
+/// The terms that may still change before an agreement is signed. The id
+/// and the parties are fixed once the agreement is created.
 #[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-pub struct Config {
-    pub fee_bps: u32,
-    pub fee_collector: Address,
-    pub paused: bool,
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TermsChange {
+    pub agent: Option<Address>,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub agent_commission_rate: u32,
+    pub payment_token: Address,
+    pub document_hash: BytesN<32>,
+    pub document_cid: Option<String>,
+}
+
+/// A document appended to an Active agreement, such as a pet or parking
+/// clause. It binds the parties once every signer of the agreement has
+/// signed its hash; addenda are never removed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Addendum {
+    pub agreement_id: String,
+    pub index: u32,
+    /// SHA-256 of the addendum document
+    pub document_hash: BytesN<32>,
+    /// IPFS CID of the addendum document, if pinned
+    pub document_cid: Option<String>,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    /// Set once the last signer signs
+    pub executed_at: Option<u64>,
+}
+
+/// The capacity in which an address is party to agreements, for the
+/// per-party agreement indexes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PartyRole {
+    Landlord,
+    /// The lead tenant or any co-tenant
+    Tenant,
+    Agent,
+}
+
+/// One page of a party's agreements.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgreementPage {
+    pub agreements: Vec<RentAgreement>,
+    /// Pass as `start` to fetch the next page; `None` once the index is exhausted
+    pub next_start: Option<u32>,
+}
+
+/// A tenant's proposal to sign on different terms than the landlord's.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CounterOffer {
+    pub agreement_id: String,
+    pub proposer: Address,
+    /// Revision of the terms the offer was made against
+    pub revision: u32,
+    pub change: TermsChange,
+    pub proposed_at: u64,
+}
+
+/// A party's signature over an agreement's terms.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgreementSignature {
+    pub signer: Address,
+    pub terms_hash: BytesN<32>,
+    pub signed_at: u64,
+}
+
+/// A group of state-changing calls that can be paused on its own. Reads,
+/// admin calls and dispute handling are never paused.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    /// Creating, negotiating, signing, amending and cancelling agreements
+    Agreements,
+    /// Opening and progressing maintenance requests
+    Maintenance,
+    /// Proposing, answering and applying rent changes
+    RentChanges,
+    /// Paying rent shares and covering shortfalls
+    Payments,
 }
 
 #[contracttype]
@@ -58,3 +161,85 @@ pub struct ContractState {
     pub config: Config,
     pub initialized: bool,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigChangeStatus {
+    Scheduled,
+    Executed,
+    Cancelled,
+}
+
+/// A fee change waiting out the timelock. It can be executed by anyone
+/// once `executable_at` has passed, or cancelled before then.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigChange {
+    pub change_id: u32,
+    pub fee_bps: u32,
+    pub fee_collector: Address,
+    pub proposed_by: Address,
+    pub scheduled_at: u64,
+    pub executable_at: u64,
+    pub status: ConfigChangeStatus,
+}
+
+/// Response times a landlord owes a maintenance request of a given priority,
+/// in seconds from when the request is opened.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceSla {
+    pub acknowledge_within: u64,
+    pub complete_within: u64,
+}
+
+/// Rent control rules an admin has set for a jurisdiction.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentControl {
+    /// Largest increase allowed in any 12 months, in basis points of the current rent
+    pub max_increase_bps: u32,
+    /// Minimum seconds between proposing a new rent and it taking effect
+    pub notice_period: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RentProposalStatus {
+    Pending,
+    Accepted,
+    Rejected,
+    Withdrawn,
+    /// Accepted and now in force as the agreement's `monthly_rent`
+    Applied,
+}
+
+/// A landlord's proposal to change the rent of an Active agreement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentProposal {
+    pub agreement_id: String,
+    pub current_rent: i128,
+    pub proposed_rent: i128,
+    pub effective_from: u64,
+    /// End the lease at `effective_from` if the tenant rejects
+    pub terminate_on_reject: bool,
+    pub status: RentProposalStatus,
+    pub proposed_at: u64,
+    pub responded_at: Option<u64>,
+    /// Tenants who have accepted; the proposal is Accepted once every tenant has
+    pub accepted_by: Vec<Address>,
+}
+
+/// Running totals for one rent period of an agreement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentPeriod {
+    /// Rent owed for the period, fixed when its first share is paid so a
+    /// rent change mid-period doesn't alter the remaining shares
+    pub rent: i128,
+    pub amount_paid: i128,
+    pub landlord_amount: i128,
+    pub platform_amount: i128,
+    pub shares_paid: u32,
+}
@@ -0,0 +1,118 @@
+//! Code upgrades and storage schema migrations for the Chioma/Rental contract.
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{Config, ContractState, LegacyContractState, Operation};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+pub const SCHEMA_VERSION: u32 = 2;
+
+fn load_state(env: &Env) -> Result<ContractState, RentalError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(RentalError::InvalidState)
+}
+
+/// Returns the schema version of the stored data. Zero means the data was
+/// written before schema versioning was introduced.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+pub(crate) fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Replace the contract's code with an uploaded WASM (admin only). Storage
+/// is kept; call `migrate` afterwards if the new code changed its schema.
+pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), RentalError> {
+    let state = load_state(env)?;
+
+    state.admin.require_auth();
+
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+
+    events::contract_upgraded(env, state.admin, new_wasm_hash);
+
+    Ok(())
+}
+
+/// Bring stored data up to `SCHEMA_VERSION` (admin only). A no-op when the
+/// data is already current. Returns the schema version after migrating.
+pub fn migrate(env: &Env) -> Result<u32, RentalError> {
+    let from_version = get_schema_version(env);
+
+    // Before version 2 the state still carries the contract-wide pause flag
+    // and only decodes in its old shape.
+    let admin = if from_version < 2 {
+        load_legacy_state(env)?.admin
+    } else {
+        load_state(env)?.admin
+    };
+
+    admin.require_auth();
+
+    if from_version >= SCHEMA_VERSION {
+        return Ok(from_version);
+    }
+
+    if from_version < 2 {
+        fold_contract_pause(env, &admin)?;
+    }
+
+    set_schema_version(env, SCHEMA_VERSION);
+
+    events::contract_migrated(env, admin, from_version, SCHEMA_VERSION);
+
+    Ok(SCHEMA_VERSION)
+}
+
+fn load_legacy_state(env: &Env) -> Result<LegacyContractState, RentalError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(RentalError::InvalidState)
+}
+
+/// Version 2 dropped `Config.paused`. A contract paused as a whole gets every
+/// operation paused instead, so it stays paused until each is resumed.
+fn fold_contract_pause(env: &Env, admin: &Address) -> Result<(), RentalError> {
+    let legacy = load_legacy_state(env)?;
+
+    if legacy.config.paused {
+        for operation in [
+            Operation::Agreements,
+            Operation::Maintenance,
+            Operation::RentChanges,
+            Operation::Payments,
+        ] {
+            chioma_types::access::set_paused(env, operation.clone(), true);
+            events::operation_paused(env, operation, admin.clone());
+        }
+    }
+
+    let state = ContractState {
+        admin: legacy.admin,
+        config: Config {
+            fee_bps: legacy.config.fee_bps,
+            fee_collector: legacy.config.fee_collector,
+        },
+        initialized: legacy.initialized,
+    };
+    env.storage().instance().set(&DataKey::State, &state);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! Access control for the Dispute Resolution contract. Admin transfer, roles and
+//! pause flags are handled by `chioma_types::access`; this module binds them to
+//! the admin in `ContractState` and emits this contract's pause events.
+use chioma_types::access::{self as shared, AccessControl};
+use soroban_sdk::{Address, Env};
+
+use crate::errors::DisputeError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{ContractState, Operation, Role};
+
+pub(crate) struct Access;
+
+impl AccessControl for Access {
+    type Error = DisputeError;
+
+    fn admin(env: &Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get::<_, ContractState>(&DataKey::State)
+            .map(|state| state.admin)
+    }
+
+    fn set_admin(env: &Env, admin: &Address) {
+        if let Some(mut state) = env
+            .storage()
+            .instance()
+            .get::<_, ContractState>(&DataKey::State)
+        {
+            state.admin = admin.clone();
+            env.storage().instance().set(&DataKey::State, &state);
+        }
+    }
+
+    fn is_grantable(role: &Role) -> bool {
+        matches!(role, Role::ArbiterManager | Role::Pauser | Role::Guardian)
+    }
+}
+
+/// Require `admin` to be the contract admin and to have authorized the call
+pub(crate) fn require_admin(env: &Env, admin: &Address) -> Result<(), DisputeError> {
+    shared::require_admin::<Access>(env, admin)
+}
+
+/// Require `caller` to hold `role` and to have authorized the call. The
+/// admin holds every role.
+pub(crate) fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), DisputeError> {
+    shared::require_role::<Access>(env, caller, role)
+}
+
+/// Whether `account` holds `role`. The admin holds every role.
+pub fn has_role(env: &Env, role: Role, account: Address) -> bool {
+    shared::has_role::<Access>(env, role, account)
+}
+
+pub fn is_paused(env: &Env, operation: Operation) -> bool {
+    shared::is_paused(env, operation)
+}
+
+/// Pause one operation. Pausing an already paused operation is a no-op.
+pub fn pause(env: &Env, caller: Address, operation: Operation) -> Result<(), DisputeError> {
+    if shared::pause::<Access, _>(env, &caller, operation.clone())? {
+        events::operation_paused(env, operation, caller);
+    }
+
+    Ok(())
+}
+
+/// Resume a paused operation. Guardians can't unpause.
+pub fn unpause(env: &Env, caller: Address, operation: Operation) -> Result<(), DisputeError> {
+    if shared::unpause::<Access, _>(env, &caller, operation.clone())? {
+        events::operation_unpaused(env, operation, caller);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn require_not_paused(env: &Env, operation: Operation) -> Result<(), DisputeError> {
+    shared::require_not_paused::<Access, _>(env, operation)
+}
@@ -0,0 +1,229 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::access;
+use crate::category;
+use crate::dispute;
+use crate::errors::DisputeError;
+use crate::events;
+use crate::panel;
+use crate::staking;
+use crate::storage::DataKey;
+use crate::types::{AppealConfig, ContractState, Dispute, DisputeOutcome, Operation};
+use crate::upgrade;
+
+pub fn set_appeal_config(
+    env: &Env,
+    admin: Address,
+    config: AppealConfig,
+) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    admin.require_auth();
+
+    if admin != state.admin {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    // The appeal panel must be larger than a first-round panel
+    if config.appeal_period == 0
+        || config.bond < 0
+        || config.panel_size <= panel::get_panel_size(env, &state)
+    {
+        return Err(DisputeError::InvalidAppealConfig);
+    }
+
+    // Bonds are posted in the staking token
+    if config.bond > 0 && staking::get_staking_config(env).is_none() {
+        return Err(DisputeError::StakingNotConfigured);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::AppealConfig, &config);
+    env.storage().instance().extend_ttl(500000, 500000);
+
+    events::appeal_config_set(env, admin, config);
+
+    Ok(())
+}
+
+pub fn get_appeal_config(env: &Env) -> Option<AppealConfig> {
+    env.storage().instance().get(&DataKey::AppealConfig)
+}
+
+/// Opens the appeal window on a freshly resolved first-round dispute.
+///
+/// Returns `false` when the outcome cannot be appealed and should be
+/// enforced immediately.
+pub(crate) fn open_window(env: &Env, dispute: &mut Dispute) -> bool {
+    if dispute.round > 0 {
+        return false;
+    }
+
+    let Some(config) = get_appeal_config(env) else {
+        return false;
+    };
+
+    dispute.appeal_deadline = env.ledger().timestamp() + config.appeal_period;
+    true
+}
+
+/// Settles an appeal bond once the appeal round resolves, returning the amount
+/// forfeited to the appeal panel. For first-round disputes this is the filing fee.
+pub(crate) fn settle_bond(env: &Env, dispute: &Dispute) -> i128 {
+    if dispute.round == 0 {
+        return dispute.fee;
+    }
+
+    if dispute.outcome == dispute.appealed_outcome {
+        return dispute.appeal_bond;
+    }
+
+    // Appeal succeeded: the bond goes back to the appellant
+    if let (Some(appellant), Some(config)) =
+        (dispute.appellant.clone(), staking::get_staking_config(env))
+    {
+        if dispute.appeal_bond > 0 {
+            token::Client::new(env, &config.token).transfer(
+                &env.current_contract_address(),
+                &appellant,
+                &dispute.appeal_bond,
+            );
+        }
+    }
+
+    0
+}
+
+pub fn appeal(env: &Env, appellant: Address, dispute_id: u32) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Disputes)?;
+
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    appellant.require_auth();
+
+    let config = get_appeal_config(env).ok_or(DisputeError::NotAppealable)?;
+
+    let dispute_key = DataKey::Dispute(dispute_id);
+    let mut dispute: Dispute = env
+        .storage()
+        .persistent()
+        .get(&dispute_key)
+        .ok_or(DisputeError::DisputeNotFound)?;
+
+    if !dispute.resolved {
+        return Err(DisputeError::DisputeNotResolved);
+    }
+
+    if dispute.round > 0 || dispute.enforced {
+        return Err(DisputeError::NotAppealable);
+    }
+
+    if env.ledger().timestamp() > dispute.appeal_deadline {
+        return Err(DisputeError::AppealWindowClosed);
+    }
+
+    let is_loser = match dispute.outcome {
+        DisputeOutcome::FavorLandlord => appellant == dispute.tenant,
+        DisputeOutcome::FavorTenant => appellant == dispute.landlord,
+        DisputeOutcome::Split => appellant == dispute.tenant || appellant == dispute.landlord,
+        DisputeOutcome::Inconclusive | DisputeOutcome::Settled => false,
+    };
+    if !is_loser {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    if config.bond > 0 {
+        let staking_config =
+            staking::get_staking_config(env).ok_or(DisputeError::StakingNotConfigured)?;
+        token::Client::new(env, &staking_config.token).transfer(
+            &appellant,
+            env.current_contract_address(),
+            &config.bond,
+        );
+    }
+
+    // Second round: a fresh, larger panel that excludes the first one
+    let now = env.ledger().timestamp();
+    dispute.prior_panel.append(&dispute.panel);
+    let seats = config.panel_size.max(dispute.required_votes);
+    dispute.panel = panel::draw_arbiters(env, &dispute, seats);
+    for member in dispute.panel.iter() {
+        panel::update_seat(env, &member, true);
+    }
+
+    dispute.round = 1;
+    dispute.appellant = Some(appellant.clone());
+    dispute.appeal_bond = config.bond;
+    dispute.appealed_outcome = dispute.outcome.clone();
+    dispute.outcome = DisputeOutcome::Inconclusive;
+    dispute.resolved = false;
+    dispute.resolved_at = None;
+    dispute.votes_favor_landlord = 0;
+    dispute.votes_favor_tenant = 0;
+    dispute.voting_deadline =
+        now + category::effective_config(env, &state, &dispute.category).voting_period;
+    dispute.extensions = 0;
+    dispute.escalated = false;
+
+    env.storage().persistent().set(&dispute_key, &dispute);
+    env.storage()
+        .persistent()
+        .extend_ttl(&dispute_key, 500000, 500000);
+
+    events::dispute_appealed(env, dispute_id, appellant, config.bond, dispute.panel);
+
+    Ok(())
+}
+
+pub fn enforce_resolution(env: &Env, dispute_id: u32) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Resolution)?;
+
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    let dispute_key = DataKey::Dispute(dispute_id);
+    let mut dispute: Dispute = env
+        .storage()
+        .persistent()
+        .get(&dispute_key)
+        .ok_or(DisputeError::DisputeNotFound)?;
+
+    if !dispute.resolved {
+        return Err(DisputeError::DisputeNotResolved);
+    }
+
+    if dispute.enforced {
+        return Err(DisputeError::DisputeAlreadyResolved);
+    }
+
+    if env.ledger().timestamp() <= dispute.appeal_deadline {
+        return Err(DisputeError::AppealWindowOpen);
+    }
+
+    dispute::enforce_outcome(env, &state, &mut dispute)?;
+
+    env.storage().persistent().set(&dispute_key, &dispute);
+    env.storage()
+        .persistent()
+        .extend_ttl(&dispute_key, 500000, 500000);
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use soroban_sdk::{token, Address, Env, Vec};
+
+use crate::access;
+use crate::errors::DisputeError;
+use crate::events;
+use crate::staking;
+use crate::storage::DataKey;
+use crate::types::{Arbiter, ArbiterStats, Role};
+use crate::upgrade;
+
+/// Maximum number of arbiters returned by a single `list_arbiters` call.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+fn load_arbiter(env: &Env, arbiter: &Address) -> Result<Arbiter, DisputeError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Arbiter(arbiter.clone()))
+        .ok_or(DisputeError::ArbiterNotFound)
+}
+
+pub(crate) fn save_arbiter(env: &Env, arbiter_info: &Arbiter) {
+    let key = DataKey::Arbiter(arbiter_info.address.clone());
+    env.storage().persistent().set(&key, arbiter_info);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+}
+
+pub fn add_arbiter(env: &Env, caller: Address, arbiter: Address) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_role(env, &caller, Role::ArbiterManager)?;
+
+    let key = DataKey::Arbiter(arbiter.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(DisputeError::ArbiterAlreadyExists);
+    }
+
+    // With staking configured, arbiters become active once they stake
+    let arbiter_info = Arbiter {
+        address: arbiter.clone(),
+        added_at: env.ledger().timestamp(),
+        active: staking::min_stake(env) == 0,
+        suspended: false,
+        stake: 0,
+        open_disputes: 0,
+        disputes_voted: 0,
+        majority_votes: 0,
+    };
+
+    save_arbiter(env, &arbiter_info);
+
+    let count_key = DataKey::ArbiterCount;
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+
+    set_index(env, count, &arbiter);
+
+    env.storage().persistent().set(&count_key, &(count + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, 500000, 500000);
+
+    events::arbiter_added(env, caller, arbiter);
+
+    Ok(())
+}
+
+pub fn deactivate_arbiter(
+    env: &Env,
+    caller: Address,
+    arbiter: Address,
+) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_role(env, &caller, Role::ArbiterManager)?;
+
+    let mut arbiter_info = load_arbiter(env, &arbiter)?;
+    arbiter_info.active = false;
+    arbiter_info.suspended = true;
+    save_arbiter(env, &arbiter_info);
+
+    events::arbiter_deactivated(env, caller, arbiter);
+
+    Ok(())
+}
+
+pub fn reactivate_arbiter(
+    env: &Env,
+    caller: Address,
+    arbiter: Address,
+) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_role(env, &caller, Role::ArbiterManager)?;
+
+    let mut arbiter_info = load_arbiter(env, &arbiter)?;
+    if arbiter_info.stake < staking::min_stake(env) {
+        return Err(DisputeError::InsufficientStake);
+    }
+
+    arbiter_info.active = true;
+    arbiter_info.suspended = false;
+    save_arbiter(env, &arbiter_info);
+
+    events::arbiter_reactivated(env, caller, arbiter);
+
+    Ok(())
+}
+
+pub fn remove_arbiter(env: &Env, caller: Address, arbiter: Address) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_role(env, &caller, Role::ArbiterManager)?;
+
+    let arbiter_info = load_arbiter(env, &arbiter)?;
+
+    // Stake backs open panel seats; deactivate first and remove once they settle
+    if arbiter_info.open_disputes > 0 {
+        return Err(DisputeError::StakeLocked);
+    }
+
+    // Swap the last arbiter into the removed slot to keep the index dense
+    let count_key = DataKey::ArbiterCount;
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    let index_key = DataKey::ArbiterIndex(arbiter.clone());
+    if let Some(index) = env.storage().persistent().get::<_, u32>(&index_key) {
+        let last = count - 1;
+        if index != last {
+            let moved: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ArbiterAt(last))
+                .ok_or(DisputeError::ArbiterNotFound)?;
+            set_index(env, index, &moved);
+        }
+        env.storage().persistent().remove(&DataKey::ArbiterAt(last));
+        env.storage().persistent().remove(&index_key);
+
+        env.storage().persistent().set(&count_key, &last);
+        env.storage()
+            .persistent()
+            .extend_ttl(&count_key, 500000, 500000);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Arbiter(arbiter.clone()));
+
+    if arbiter_info.stake > 0 {
+        if let Some(config) = staking::get_staking_config(env) {
+            token::Client::new(env, &config.token).transfer(
+                &env.current_contract_address(),
+                &arbiter,
+                &arbiter_info.stake,
+            );
+        }
+    }
+
+    events::arbiter_removed(env, caller, arbiter);
+
+    Ok(())
+}
+
+pub(crate) fn set_index(env: &Env, index: u32, arbiter: &Address) {
+    let at_key = DataKey::ArbiterAt(index);
+    env.storage().persistent().set(&at_key, arbiter);
+    env.storage()
+        .persistent()
+        .extend_ttl(&at_key, 500000, 500000);
+
+    let index_key = DataKey::ArbiterIndex(arbiter.clone());
+    env.storage().persistent().set(&index_key, &index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&index_key, 500000, 500000);
+}
+
+pub fn get_arbiter(env: &Env, arbiter: Address) -> Option<Arbiter> {
+    let key = DataKey::Arbiter(arbiter);
+    env.storage().persistent().get(&key)
+}
+
+pub fn get_arbiter_count(env: &Env) -> u32 {
+    let key = DataKey::ArbiterCount;
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn get_arbiter_stats(env: &Env, arbiter: Address) -> Option<ArbiterStats> {
+    get_arbiter(env, arbiter).map(|arbiter_info| arbiter_info.stats())
+}
+
+/// Returns up to `limit` arbiters (capped at `MAX_PAGE_SIZE`) in registration
+/// order, starting at position `start`. Removals move the last arbiter into
+/// the freed position.
+pub fn list_arbiters(env: &Env, start: u32, limit: u32) -> Vec<Arbiter> {
+    let count = get_arbiter_count(env);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    let mut arbiters = Vec::new(env);
+    for index in start..end {
+        let arbiter_info = env
+            .storage()
+            .persistent()
+            .get::<_, Address>(&DataKey::ArbiterAt(index))
+            .and_then(|address| get_arbiter(env, address));
+        if let Some(arbiter_info) = arbiter_info {
+            arbiters.push_back(arbiter_info);
+        }
+    }
+
+    arbiters
+}
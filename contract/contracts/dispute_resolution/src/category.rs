@@ -0,0 +1,108 @@
+use soroban_sdk::{vec, Address, Env};
+
+use crate::dispute;
+use crate::errors::DisputeError;
+use crate::events;
+use crate::staking;
+use crate::storage::DataKey;
+use crate::types::{CategoryConfig, ContractState, DisputeCategory, DisputeOutcome};
+use crate::upgrade;
+
+pub fn set_category_config(
+    env: &Env,
+    admin: Address,
+    category: DisputeCategory,
+    config: CategoryConfig,
+) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    admin.require_auth();
+
+    if admin != state.admin {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    // Evidence must close before voting does, as for the global rules
+    if config.required_votes == 0
+        || config.voting_period == 0
+        || config.voting_period <= dispute::get_voting_rules(env).evidence_period
+        || config.filing_fee < 0
+        || config.allowed_outcomes.is_empty()
+    {
+        return Err(DisputeError::InvalidCategoryConfig);
+    }
+
+    // Only arbiter decisions can be allowed; settlements are always possible
+    for outcome in config.allowed_outcomes.iter() {
+        if outcome == DisputeOutcome::Inconclusive || outcome == DisputeOutcome::Settled {
+            return Err(DisputeError::InvalidCategoryConfig);
+        }
+    }
+
+    // Fees are paid in the staking token
+    if config.filing_fee > 0 && staking::get_staking_config(env).is_none() {
+        return Err(DisputeError::StakingNotConfigured);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::CategoryConfig(category.clone()), &config);
+    env.storage().instance().extend_ttl(500000, 500000);
+
+    events::category_config_set(env, admin, category, config);
+
+    Ok(())
+}
+
+/// Whether every stored category config still votes for longer than
+/// `evidence_period` lasts
+pub(crate) fn fits_evidence_period(env: &Env, evidence_period: u64) -> bool {
+    [
+        DisputeCategory::DepositDeduction,
+        DisputeCategory::UnpaidRent,
+        DisputeCategory::Maintenance,
+        DisputeCategory::EarlyTermination,
+        DisputeCategory::Other,
+    ]
+    .into_iter()
+    .filter_map(|category| get_category_config(env, category))
+    .all(|config| config.voting_period > evidence_period)
+}
+
+pub fn get_category_config(env: &Env, category: DisputeCategory) -> Option<CategoryConfig> {
+    env.storage()
+        .instance()
+        .get(&DataKey::CategoryConfig(category))
+}
+
+/// Returns the rules that apply to a dispute in `category`.
+///
+/// Categories without their own config use the contract-wide quorum, voting
+/// period and filing fee, and allow every arbiter decision.
+pub(crate) fn effective_config(
+    env: &Env,
+    state: &ContractState,
+    category: &DisputeCategory,
+) -> CategoryConfig {
+    if let Some(config) = get_category_config(env, category.clone()) {
+        return config;
+    }
+
+    CategoryConfig {
+        required_votes: state.min_votes_required,
+        voting_period: dispute::get_voting_rules(env).voting_period,
+        filing_fee: staking::get_staking_config(env).map_or(0, |config| config.filing_fee),
+        allowed_outcomes: vec![
+            env,
+            DisputeOutcome::FavorLandlord,
+            DisputeOutcome::FavorTenant,
+            DisputeOutcome::Split,
+        ],
+    }
+}
@@ -1,54 +1,58 @@
-use soroban_sdk::{contracttype, Address, Env, Map, String};
+use chioma_types::{AgreementStatus, ChiomaClient, EscrowClient, EscrowStatus};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
+use crate::access;
+use crate::appeal;
+use crate::arbiter::MAX_PAGE_SIZE;
+use crate::category;
 use crate::errors::DisputeError;
 use crate::events;
+use crate::evidence;
+use crate::panel;
+use crate::staking;
 use crate::storage::DataKey;
-use crate::types::{Arbiter, ContractState, Dispute, DisputeOutcome, Vote};
-
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum AgreementStatus {
-    Draft,
-    Pending,
-    Active,
-    Completed,
-    Cancelled,
-    Terminated,
-    Disputed,
-}
+use crate::types::{
+    Arbiter, ContractState, Dispute, DisputeCategory, DisputeOutcome, EvidenceKind, Operation,
+    TieRule, Vote, VotingRules,
+};
+use crate::upgrade;
+
+pub fn set_escrow_contract(
+    env: &Env,
+    admin: Address,
+    escrow_contract: Address,
+) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct RentAgreement {
-    pub agreement_id: String,
-    pub landlord: Address,
-    pub tenant: Address,
-    pub agent: Option<Address>,
-    pub monthly_rent: i128,
-    pub security_deposit: i128,
-    pub start_date: u64,
-    pub end_date: u64,
-    pub agent_commission_rate: u32,
-    pub status: AgreementStatus,
-    pub total_rent_paid: i128,
-    pub payment_count: u32,
-    pub signed_at: Option<u64>,
-    pub payment_token: Address,
-    pub next_payment_due: u64,
-    pub payment_history: Map<u32, PaymentSplit>,
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    admin.require_auth();
+
+    if admin != state.admin {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::EscrowContract, &escrow_contract);
+    env.storage().instance().extend_ttl(500000, 500000);
+
+    events::escrow_contract_set(env, admin, escrow_contract);
+
+    Ok(())
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PaymentSplit {
-    pub landlord_amount: i128,
-    pub platform_amount: i128,
-    pub token: Address,
-    pub payment_date: u64,
-    pub payer: Address,
+pub fn get_escrow_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::EscrowContract)
 }
 
-pub fn add_arbiter(env: &Env, admin: Address, arbiter: Address) -> Result<(), DisputeError> {
+pub fn set_voting_rules(env: &Env, admin: Address, rules: VotingRules) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
     let state: ContractState = env
         .storage()
         .instance()
@@ -61,38 +65,46 @@ pub fn add_arbiter(env: &Env, admin: Address, arbiter: Address) -> Result<(), Di
         return Err(DisputeError::Unauthorized);
     }
 
-    let key = DataKey::Arbiter(arbiter.clone());
-    if env.storage().persistent().has(&key) {
-        return Err(DisputeError::ArbiterAlreadyExists);
+    if rules.voting_period == 0
+        || rules.evidence_period >= rules.voting_period
+        || rules.supermajority_bps < 5000
+        || rules.supermajority_bps >= 10_000
+    {
+        return Err(DisputeError::InvalidVotingRules);
     }
 
-    let arbiter_info = Arbiter {
-        address: arbiter.clone(),
-        added_at: env.ledger().timestamp(),
-        active: true,
-    };
-
-    env.storage().persistent().set(&key, &arbiter_info);
-    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+    // Categories with their own voting period must still outlast evidence
+    if !category::fits_evidence_period(env, rules.evidence_period) {
+        return Err(DisputeError::InvalidVotingRules);
+    }
 
-    let count_key = DataKey::ArbiterCount;
-    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
-    env.storage().persistent().set(&count_key, &(count + 1));
-    env.storage()
-        .persistent()
-        .extend_ttl(&count_key, 500000, 500000);
+    env.storage().instance().set(&DataKey::VotingRules, &rules);
+    env.storage().instance().extend_ttl(500000, 500000);
 
-    events::arbiter_added(env, admin, arbiter);
+    events::voting_rules_updated(env, admin, rules);
 
     Ok(())
 }
 
+pub fn get_voting_rules(env: &Env) -> VotingRules {
+    env.storage()
+        .instance()
+        .get(&DataKey::VotingRules)
+        .unwrap_or_default()
+}
+
 pub fn raise_dispute(
     env: &Env,
     raiser: Address,
     agreement_id: String,
+    category: DisputeCategory,
     details_hash: String,
-) -> Result<(), DisputeError> {
+    escrow_id: Option<BytesN<32>>,
+) -> Result<u32, DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Disputes)?;
+
     raiser.require_auth();
 
     let state: ContractState = env
@@ -105,19 +117,21 @@ pub fn raise_dispute(
         return Err(DisputeError::InvalidDetailsHash);
     }
 
-    let key = DataKey::Dispute(agreement_id.clone());
-    if env.storage().persistent().has(&key) {
+    // Only one dispute per agreement may be open at a time
+    if latest_dispute(env, &agreement_id).is_some_and(|dispute| !dispute.enforced) {
         return Err(DisputeError::DisputeAlreadyExists);
     }
 
-    // Cross-contract call to get agreement from chioma contract
-    let agreement: Option<RentAgreement> = env.invoke_contract(
-        &state.chioma_contract,
-        &soroban_sdk::symbol_short!("get_agr"),
-        soroban_sdk::vec![env, agreement_id.clone().into()],
-    );
+    let escrow_contract = match escrow_id {
+        Some(_) => Some(get_escrow_contract(env).ok_or(DisputeError::EscrowNotConfigured)?),
+        None => None,
+    };
 
-    let agreement = agreement.ok_or(DisputeError::AgreementNotFound)?;
+    // Cross-contract call to get agreement from chioma contract
+    let chioma = ChiomaClient::new(env, &state.chioma_contract);
+    let agreement = chioma
+        .get_agreement(&agreement_id)
+        .ok_or(DisputeError::AgreementNotFound)?;
 
     // Validate agreement is in Active status
     if agreement.status != AgreementStatus::Active {
@@ -129,30 +143,177 @@ pub fn raise_dispute(
         return Err(DisputeError::Unauthorized);
     }
 
-    let dispute = Dispute {
+    // Co-tenants may file evidence alongside the lead tenant, and neither
+    // they nor the guarantor may sit on the panel
+    let (co_tenants, guarantor) =
+        co_tenancy_parties(env, &chioma, &agreement_id, &agreement.tenant);
+
+    // The outcome can only be enforced on an escrow this contract arbitrates
+    // that holds this agreement's deposit
+    if let (Some(escrow_contract), Some(escrow_id)) = (&escrow_contract, &escrow_id) {
+        let escrow = EscrowClient::new(env, escrow_contract)
+            .try_get_escrow(escrow_id)
+            .ok()
+            .and_then(|escrow| escrow.ok())
+            .ok_or(DisputeError::InvalidEscrow)?;
+
+        if escrow.depositor != agreement.tenant
+            || escrow.beneficiary != agreement.landlord
+            || escrow.arbiter != env.current_contract_address()
+            || escrow.status != EscrowStatus::Funded
+        {
+            return Err(DisputeError::InvalidEscrow);
+        }
+    }
+
+    let dispute_id = get_dispute_count(env) + 1;
+    let now = env.ledger().timestamp();
+    let rules = get_voting_rules(env);
+    let category_config = category::effective_config(env, &state, &category);
+    let fee = staking::collect_filing_fee(env, &raiser, category_config.filing_fee)?;
+    let mut dispute = Dispute {
+        id: dispute_id,
         agreement_id: agreement_id.clone(),
+        category: category.clone(),
+        required_votes: category_config.required_votes,
+        allowed_outcomes: category_config.allowed_outcomes,
+        landlord: agreement.landlord,
+        tenant: agreement.tenant,
+        agent: agreement.agent,
+        co_tenants,
+        guarantor,
+        raiser: raiser.clone(),
+        escrow_id: escrow_id.clone(),
+        fee,
         details_hash: details_hash.clone(),
-        raised_at: env.ledger().timestamp(),
+        raised_at: now,
+        evidence_deadline: now + rules.evidence_period,
+        evidence_count: 0,
         resolved: false,
         resolved_at: None,
         votes_favor_landlord: 0,
         votes_favor_tenant: 0,
+        voting_deadline: now + category_config.voting_period,
+        extensions: 0,
+        escalated: false,
+        outcome: DisputeOutcome::Inconclusive,
+        panel: Vec::new(env),
+        recused: Vec::new(env),
+        round: 0,
+        enforced: false,
+        appeal_deadline: 0,
+        appellant: None,
+        appeal_bond: 0,
+        appealed_outcome: DisputeOutcome::Inconclusive,
+        prior_panel: Vec::new(env),
     };
+    // The panel must be able to reach the category's quorum
+    let seats = panel::get_panel_size(env, &state).max(dispute.required_votes);
+    dispute.panel = panel::draw_arbiters(env, &dispute, seats);
+    for member in dispute.panel.iter() {
+        panel::update_seat(env, &member, true);
+    }
+
+    evidence::append(
+        env,
+        &mut dispute,
+        raiser.clone(),
+        EvidenceKind::Statement,
+        details_hash.clone(),
+        String::from_str(env, ""),
+    );
 
+    let key = DataKey::Dispute(dispute_id);
     env.storage().persistent().set(&key, &dispute);
     env.storage().persistent().extend_ttl(&key, 500000, 500000);
+    index_dispute(env, &agreement_id, dispute_id);
+
+    // Freeze the agreement on the chioma contract
+    chioma.mark_disputed(&agreement_id);
+
+    // Freeze the security deposit held in escrow, if one was provided
+    if let (Some(escrow_contract), Some(escrow_id)) = (escrow_contract, escrow_id) {
+        EscrowClient::new(env, &escrow_contract).initiate_dispute(
+            &escrow_id,
+            &raiser,
+            &details_hash,
+        );
+    }
 
-    events::dispute_raised(env, agreement_id, details_hash);
+    events::dispute_raised(env, dispute_id, agreement_id, category, details_hash);
+    events::panel_assigned(env, dispute_id, dispute.panel);
 
-    Ok(())
+    Ok(dispute_id)
+}
+
+/// The co-tenants other than `tenant` and the guarantor on `agreement_id`.
+pub(crate) fn co_tenancy_parties(
+    env: &Env,
+    chioma: &ChiomaClient,
+    agreement_id: &String,
+    tenant: &Address,
+) -> (Vec<Address>, Option<Address>) {
+    let mut co_tenants = Vec::new(env);
+    let Some(co_tenancy) = chioma.get_co_tenancy(agreement_id) else {
+        return (co_tenants, None);
+    };
+
+    for share in co_tenancy.tenants.iter() {
+        if share.tenant != *tenant {
+            co_tenants.push_back(share.tenant);
+        }
+    }
+
+    (co_tenants, co_tenancy.guarantor)
+}
+
+/// Records `dispute_id` as the newest dispute overall and on `agreement_id`.
+pub(crate) fn index_dispute(env: &Env, agreement_id: &String, dispute_id: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DisputeCount, &dispute_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::DisputeCount, 500000, 500000);
+
+    let count_key = DataKey::AgreementDisputeCount(agreement_id.clone());
+    let count = get_agreement_dispute_count(env, agreement_id.clone());
+
+    let at_key = DataKey::AgreementDispute(agreement_id.clone(), count);
+    env.storage().persistent().set(&at_key, &dispute_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&at_key, 500000, 500000);
+
+    env.storage().persistent().set(&count_key, &(count + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, 500000, 500000);
+}
+
+/// The most recently raised dispute on `agreement_id`, if any.
+fn latest_dispute(env: &Env, agreement_id: &String) -> Option<Dispute> {
+    let count = get_agreement_dispute_count(env, agreement_id.clone());
+    if count == 0 {
+        return None;
+    }
+
+    env.storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::AgreementDispute(agreement_id.clone(), count - 1))
+        .and_then(|dispute_id| get_dispute(env, dispute_id))
 }
 
 pub fn vote_on_dispute(
     env: &Env,
     arbiter: Address,
-    agreement_id: String,
+    dispute_id: u32,
     favor_landlord: bool,
 ) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Voting)?;
+
     if !env.storage().persistent().has(&DataKey::Initialized) {
         return Err(DisputeError::NotInitialized);
     }
@@ -166,11 +327,11 @@ pub fn vote_on_dispute(
         .get(&arbiter_key)
         .ok_or(DisputeError::ArbiterNotFound)?;
 
-    if !arbiter_info.active {
+    if !arbiter_info.active || arbiter_info.suspended {
         return Err(DisputeError::ArbiterNotFound);
     }
 
-    let dispute_key = DataKey::Dispute(agreement_id.clone());
+    let dispute_key = DataKey::Dispute(dispute_id);
     let mut dispute: Dispute = env
         .storage()
         .persistent()
@@ -181,14 +342,41 @@ pub fn vote_on_dispute(
         return Err(DisputeError::DisputeAlreadyResolved);
     }
 
-    let vote_key = DataKey::Vote(agreement_id.clone(), arbiter.clone());
+    if dispute.escalated {
+        return Err(DisputeError::DisputeEscalated);
+    }
+
+    // Arbiters decide on the full record once the parties are done
+    let now = env.ledger().timestamp();
+    if now <= dispute.evidence_deadline {
+        return Err(DisputeError::EvidenceWindowOpen);
+    }
+
+    if now > dispute.voting_deadline {
+        return Err(DisputeError::VotingClosed);
+    }
+
+    if !dispute.panel.contains(&arbiter) {
+        return Err(DisputeError::NotPanelMember);
+    }
+
+    let side = if favor_landlord {
+        DisputeOutcome::FavorLandlord
+    } else {
+        DisputeOutcome::FavorTenant
+    };
+    if !dispute.allowed_outcomes.contains(&side) {
+        return Err(DisputeError::InvalidOutcome);
+    }
+
+    let vote_key = DataKey::Vote(dispute_id, arbiter.clone());
     if env.storage().persistent().has(&vote_key) {
         return Err(DisputeError::AlreadyVoted);
     }
 
     let vote = Vote {
         arbiter: arbiter.clone(),
-        agreement_id: agreement_id.clone(),
+        dispute_id,
         favor_landlord,
         voted_at: env.ledger().timestamp(),
     };
@@ -209,19 +397,126 @@ pub fn vote_on_dispute(
         .persistent()
         .extend_ttl(&dispute_key, 500000, 500000);
 
-    events::vote_cast(env, agreement_id, arbiter, favor_landlord);
+    events::vote_cast(env, dispute_id, arbiter, favor_landlord);
 
     Ok(())
 }
 
-pub fn resolve_dispute(env: &Env, agreement_id: String) -> Result<DisputeOutcome, DisputeError> {
+/// Returns the side holding a decisive majority under `rules`, if any.
+fn decisive_outcome(dispute: &Dispute, rules: &VotingRules) -> Option<DisputeOutcome> {
+    let total_votes = dispute.votes_favor_landlord + dispute.votes_favor_tenant;
+    if total_votes == 0 || total_votes < dispute.required_votes {
+        return None;
+    }
+
+    let threshold = total_votes as u64 * rules.supermajority_bps as u64;
+    if dispute.votes_favor_landlord as u64 * 10_000 > threshold {
+        Some(DisputeOutcome::FavorLandlord)
+    } else if dispute.votes_favor_tenant as u64 * 10_000 > threshold {
+        Some(DisputeOutcome::FavorTenant)
+    } else {
+        None
+    }
+}
+
+/// Records the outcome and settles the panel.
+///
+/// If the outcome can still be appealed, enforcement waits for the appeal
+/// window to close (see `enforce_resolution`); otherwise it is applied now.
+fn finalize_dispute(
+    env: &Env,
+    state: &ContractState,
+    mut dispute: Dispute,
+    outcome: DisputeOutcome,
+) -> Result<DisputeOutcome, DisputeError> {
+    let dispute_key = DataKey::Dispute(dispute.id);
+
+    dispute.resolved = true;
+    dispute.resolved_at = Some(env.ledger().timestamp());
+    dispute.outcome = outcome.clone();
+
+    let pool = appeal::settle_bond(env, &dispute);
+    staking::settle_panel(env, state, &dispute, &outcome, pool);
+
+    if !appeal::open_window(env, &mut dispute) {
+        enforce_outcome(env, state, &mut dispute)?;
+    }
+
+    env.storage().persistent().set(&dispute_key, &dispute);
+    env.storage()
+        .persistent()
+        .extend_ttl(&dispute_key, 500000, 500000);
+
+    events::dispute_resolved(
+        env,
+        dispute.id,
+        outcome.clone(),
+        dispute.votes_favor_landlord,
+        dispute.votes_favor_tenant,
+    );
+
+    Ok(outcome)
+}
+
+/// Applies a resolved dispute's outcome to the chioma and escrow contracts.
+///
+/// * `FavorLandlord` - agreement terminated, deposit released to the landlord
+/// * `FavorTenant` - agreement restored to Active, deposit refunded to the tenant
+/// * `Split` - agreement restored to Active, deposit split evenly
+pub(crate) fn enforce_outcome(
+    env: &Env,
+    state: &ContractState,
+    dispute: &mut Dispute,
+) -> Result<(), DisputeError> {
+    let outcome = dispute.outcome.clone();
+    let terminate = outcome == DisputeOutcome::FavorLandlord;
+    ChiomaClient::new(env, &state.chioma_contract)
+        .resolve_dispute(&dispute.agreement_id, &terminate);
+
+    // Release the escrowed deposit per the outcome. This contract must be
+    // the escrow's arbiter for the release to be authorized.
+    if let Some(escrow_id) = dispute.escrow_id.clone() {
+        let escrow_contract = get_escrow_contract(env).ok_or(DisputeError::EscrowNotConfigured)?;
+        let escrow = EscrowClient::new(env, &escrow_contract);
+        let arbiter = env.current_contract_address();
+        match outcome {
+            DisputeOutcome::FavorLandlord => {
+                escrow.resolve_dispute(&escrow_id, &arbiter, &dispute.landlord)
+            }
+            DisputeOutcome::FavorTenant => {
+                escrow.resolve_dispute(&escrow_id, &arbiter, &dispute.tenant)
+            }
+            DisputeOutcome::Split => escrow.resolve_dispute_split(&escrow_id, &arbiter, &5000),
+            DisputeOutcome::Inconclusive | DisputeOutcome::Settled => {
+                return Err(DisputeError::InvalidOutcome)
+            }
+        }
+    }
+
+    dispute.enforced = true;
+
+    // A proposal left open can no longer be accepted
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Settlement(dispute.id));
+
+    events::outcome_enforced(env, dispute.id, outcome);
+
+    Ok(())
+}
+
+pub fn resolve_dispute(env: &Env, dispute_id: u32) -> Result<DisputeOutcome, DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Resolution)?;
+
     let state: ContractState = env
         .storage()
         .instance()
         .get(&DataKey::State)
         .ok_or(DisputeError::NotInitialized)?;
 
-    let dispute_key = DataKey::Dispute(agreement_id.clone());
+    let dispute_key = DataKey::Dispute(dispute_id);
     let mut dispute: Dispute = env
         .storage()
         .persistent()
@@ -232,53 +527,152 @@ pub fn resolve_dispute(env: &Env, agreement_id: String) -> Result<DisputeOutcome
         return Err(DisputeError::DisputeAlreadyResolved);
     }
 
-    let total_votes = dispute.votes_favor_landlord + dispute.votes_favor_tenant;
+    if dispute.escalated {
+        return Err(DisputeError::DisputeEscalated);
+    }
 
-    if total_votes < state.min_votes_required {
-        return Err(DisputeError::InsufficientVotes);
+    let rules = get_voting_rules(env);
+
+    if let Some(outcome) = decisive_outcome(&dispute, &rules) {
+        return finalize_dispute(env, &state, dispute, outcome);
     }
 
-    dispute.resolved = true;
-    dispute.resolved_at = Some(env.ledger().timestamp());
+    let now = env.ledger().timestamp();
+    if now <= dispute.voting_deadline {
+        let total_votes = dispute.votes_favor_landlord + dispute.votes_favor_tenant;
+        if total_votes < dispute.required_votes {
+            return Err(DisputeError::InsufficientVotes);
+        }
+        return Err(DisputeError::VotingInProgress);
+    }
 
-    env.storage().persistent().set(&dispute_key, &dispute);
-    env.storage()
+    // Voting closed without a decisive majority: apply the tie rule
+    match rules.tie_rule {
+        TieRule::Split if dispute.allowed_outcomes.contains(&DisputeOutcome::Split) => {
+            finalize_dispute(env, &state, dispute, DisputeOutcome::Split)
+        }
+        TieRule::ExtendWindow if dispute.extensions < rules.max_extensions => {
+            dispute.extensions += 1;
+            dispute.voting_deadline =
+                now + category::effective_config(env, &state, &dispute.category).voting_period;
+
+            env.storage().persistent().set(&dispute_key, &dispute);
+            env.storage()
+                .persistent()
+                .extend_ttl(&dispute_key, 500000, 500000);
+
+            events::voting_extended(env, dispute_id, dispute.voting_deadline, dispute.extensions);
+
+            Ok(DisputeOutcome::Inconclusive)
+        }
+        TieRule::ExtendWindow | TieRule::EscalateToAdmin | TieRule::Split => {
+            dispute.escalated = true;
+
+            env.storage().persistent().set(&dispute_key, &dispute);
+            env.storage()
+                .persistent()
+                .extend_ttl(&dispute_key, 500000, 500000);
+
+            events::dispute_escalated(
+                env,
+                dispute_id,
+                dispute.votes_favor_landlord,
+                dispute.votes_favor_tenant,
+            );
+
+            Ok(DisputeOutcome::Inconclusive)
+        }
+    }
+}
+
+pub fn resolve_escalated_dispute(
+    env: &Env,
+    admin: Address,
+    dispute_id: u32,
+    outcome: DisputeOutcome,
+) -> Result<DisputeOutcome, DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    admin.require_auth();
+
+    if admin != state.admin {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    let dispute: Dispute = env
+        .storage()
         .persistent()
-        .extend_ttl(&dispute_key, 500000, 500000);
+        .get(&DataKey::Dispute(dispute_id))
+        .ok_or(DisputeError::DisputeNotFound)?;
 
-    let outcome = if dispute.votes_favor_landlord > dispute.votes_favor_tenant {
-        DisputeOutcome::FavorLandlord
-    } else {
-        DisputeOutcome::FavorTenant
-    };
+    if dispute.resolved {
+        return Err(DisputeError::DisputeAlreadyResolved);
+    }
 
-    events::dispute_resolved(
-        env,
-        agreement_id,
-        outcome.clone(),
-        dispute.votes_favor_landlord,
-        dispute.votes_favor_tenant,
-    );
+    if !dispute.escalated {
+        return Err(DisputeError::DisputeNotEscalated);
+    }
 
-    Ok(outcome)
+    if !dispute.allowed_outcomes.contains(&outcome) {
+        return Err(DisputeError::InvalidOutcome);
+    }
+
+    finalize_dispute(env, &state, dispute, outcome)
 }
 
-pub fn get_dispute(env: &Env, agreement_id: String) -> Option<Dispute> {
-    let key = DataKey::Dispute(agreement_id);
+pub fn get_dispute(env: &Env, dispute_id: u32) -> Option<Dispute> {
+    let key = DataKey::Dispute(dispute_id);
     env.storage().persistent().get(&key)
 }
 
-pub fn get_arbiter(env: &Env, arbiter: Address) -> Option<Arbiter> {
-    let key = DataKey::Arbiter(arbiter);
+pub fn get_vote(env: &Env, dispute_id: u32, arbiter: Address) -> Option<Vote> {
+    let key = DataKey::Vote(dispute_id, arbiter);
     env.storage().persistent().get(&key)
 }
 
-pub fn get_arbiter_count(env: &Env) -> u32 {
-    let key = DataKey::ArbiterCount;
-    env.storage().persistent().get(&key).unwrap_or(0)
+/// Number of disputes ever raised. Dispute ids run from 1 to this value.
+pub fn get_dispute_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisputeCount)
+        .unwrap_or(0)
 }
 
-pub fn get_vote(env: &Env, agreement_id: String, arbiter: Address) -> Option<Vote> {
-    let key = DataKey::Vote(agreement_id, arbiter);
-    env.storage().persistent().get(&key)
+pub fn get_agreement_dispute_count(env: &Env, agreement_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgreementDisputeCount(agreement_id))
+        .unwrap_or(0)
+}
+
+/// Returns up to `limit` disputes (capped at `MAX_PAGE_SIZE`) raised on
+/// `agreement_id`, oldest first, starting at position `start`.
+pub fn list_agreement_disputes(
+    env: &Env,
+    agreement_id: String,
+    start: u32,
+    limit: u32,
+) -> Vec<Dispute> {
+    let count = get_agreement_dispute_count(env, agreement_id.clone());
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    let mut disputes = Vec::new(env);
+    for index in start..end {
+        let dispute = env
+            .storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::AgreementDispute(agreement_id.clone(), index))
+            .and_then(|dispute_id| get_dispute(env, dispute_id));
+        if let Some(dispute) = dispute {
+            disputes.push_back(dispute);
+        }
+    }
+
+    disputes
 }
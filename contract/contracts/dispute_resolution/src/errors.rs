@@ -1,3 +1,4 @@
+use chioma_types::AccessError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -17,4 +18,53 @@ pub enum DisputeError {
     InsufficientVotes = 11,
     AgreementNotFound = 12,
     InvalidAgreementState = 13,
+    EscrowNotConfigured = 14,
+    VotingClosed = 15,
+    VotingInProgress = 16,
+    InvalidVotingRules = 17,
+    DisputeEscalated = 18,
+    DisputeNotEscalated = 19,
+    InvalidOutcome = 20,
+    NotPanelMember = 21,
+    InvalidPanelSize = 22,
+    InvalidStakingConfig = 23,
+    StakingNotConfigured = 24,
+    InvalidAmount = 25,
+    StakeLocked = 26,
+    InsufficientStake = 27,
+    EvidenceWindowClosed = 28,
+    CounterStatementExists = 29,
+    EvidenceLimitReached = 30,
+    InvalidAppealConfig = 31,
+    AppealWindowClosed = 32,
+    AppealWindowOpen = 33,
+    NotAppealable = 34,
+    DisputeNotResolved = 35,
+    SettlementNotFound = 36,
+    InvalidSettlement = 37,
+    InvalidCategoryConfig = 38,
+    MaintenanceRequestNotFound = 39,
+    MaintenanceRequestMismatch = 40,
+    MaintenanceAlreadyCited = 41,
+    NoPendingAdmin = 42,
+    InvalidRole = 43,
+    OperationPaused = 44,
+    InvalidEscrow = 45,
+    EvidenceWindowOpen = 46,
+    /// Stored data predates the schema; the admin must call `migrate` first
+    NotMigrated = 47,
+    /// The settlement on record no longer has the terms being accepted
+    SettlementChanged = 48,
+}
+
+impl From<AccessError> for DisputeError {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => DisputeError::NotInitialized,
+            AccessError::Unauthorized => DisputeError::Unauthorized,
+            AccessError::InvalidRole => DisputeError::InvalidRole,
+            AccessError::NoPendingAdmin => DisputeError::NoPendingAdmin,
+            AccessError::OperationPaused => DisputeError::OperationPaused,
+        }
+    }
 }
@@ -1,6 +1,9 @@
-use soroban_sdk::{contractevent, Address, Env, String};
+use soroban_sdk::{contractevent, Address, BytesN, Env, String, Vec};
 
-use crate::types::DisputeOutcome;
+use crate::types::{
+    AppealConfig, CategoryConfig, DisputeCategory, DisputeOutcome, EvidenceKind, Operation,
+    StakingConfig, TieRule, VotingRules,
+};
 
 #[contractevent(topics = ["initialized"])]
 pub struct ContractInitialized {
@@ -17,31 +20,261 @@ pub struct ArbiterAdded {
     pub arbiter: Address,
 }
 
+#[contractevent(topics = ["arbiter_deactivated"])]
+pub struct ArbiterDeactivated {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub arbiter: Address,
+}
+
+#[contractevent(topics = ["arbiter_reactivated"])]
+pub struct ArbiterReactivated {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub arbiter: Address,
+}
+
+#[contractevent(topics = ["arbiter_removed"])]
+pub struct ArbiterRemoved {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub arbiter: Address,
+}
+
+#[contractevent(topics = ["escrow_set"])]
+pub struct EscrowContractSet {
+    #[topic]
+    pub admin: Address,
+    pub escrow_contract: Address,
+}
+
+#[contractevent(topics = ["rules_updated"])]
+pub struct VotingRulesUpdated {
+    #[topic]
+    pub admin: Address,
+    pub voting_period: u64,
+    pub evidence_period: u64,
+    pub supermajority_bps: u32,
+    pub tie_rule: TieRule,
+    pub max_extensions: u32,
+}
+
+#[contractevent(topics = ["panel_size_set"])]
+pub struct PanelSizeSet {
+    #[topic]
+    pub admin: Address,
+    pub panel_size: u32,
+}
+
+#[contractevent(topics = ["staking_set"])]
+pub struct StakingConfigSet {
+    #[topic]
+    pub admin: Address,
+    pub token: Address,
+    pub min_stake: i128,
+    pub filing_fee: i128,
+    pub no_show_slash_bps: u32,
+}
+
+#[contractevent(topics = ["arbiter_staked"])]
+pub struct ArbiterStaked {
+    #[topic]
+    pub arbiter: Address,
+    pub amount: i128,
+    pub total_stake: i128,
+}
+
+#[contractevent(topics = ["arbiter_unstaked"])]
+pub struct ArbiterUnstaked {
+    #[topic]
+    pub arbiter: Address,
+    pub amount: i128,
+    pub total_stake: i128,
+}
+
+#[contractevent(topics = ["appeal_cfg"])]
+pub struct AppealConfigSet {
+    #[topic]
+    pub admin: Address,
+    pub appeal_period: u64,
+    pub bond: i128,
+    pub panel_size: u32,
+}
+
+#[contractevent(topics = ["category_set"])]
+pub struct CategoryConfigSet {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub category: DisputeCategory,
+    pub required_votes: u32,
+    pub voting_period: u64,
+    pub filing_fee: i128,
+    pub allowed_outcomes: Vec<DisputeOutcome>,
+}
+
 #[contractevent(topics = ["dispute_raised"])]
 pub struct DisputeRaised {
+    #[topic]
+    pub dispute_id: u32,
     #[topic]
     pub agreement_id: String,
+    pub category: DisputeCategory,
     pub details_hash: String,
 }
 
+#[contractevent(topics = ["panel_assigned"])]
+pub struct PanelAssigned {
+    #[topic]
+    pub dispute_id: u32,
+    pub panel: Vec<Address>,
+}
+
+#[contractevent(topics = ["arbiter_recused"])]
+pub struct ArbiterRecused {
+    #[topic]
+    pub dispute_id: u32,
+    #[topic]
+    pub arbiter: Address,
+    pub replacement: Option<Address>,
+}
+
+#[contractevent(topics = ["evidence_submitted"])]
+pub struct EvidenceSubmitted {
+    #[topic]
+    pub dispute_id: u32,
+    #[topic]
+    pub submitter: Address,
+    pub index: u32,
+    pub kind: EvidenceKind,
+    pub hash: String,
+}
+
 #[contractevent(topics = ["vote_cast"])]
 pub struct VoteCast {
     #[topic]
-    pub agreement_id: String,
+    pub dispute_id: u32,
     #[topic]
     pub arbiter: Address,
     pub favor_landlord: bool,
 }
 
+#[contractevent(topics = ["voting_extended"])]
+pub struct VotingExtended {
+    #[topic]
+    pub dispute_id: u32,
+    pub voting_deadline: u64,
+    pub extensions: u32,
+}
+
+#[contractevent(topics = ["dispute_escalated"])]
+pub struct DisputeEscalated {
+    #[topic]
+    pub dispute_id: u32,
+    pub votes_favor_landlord: u32,
+    pub votes_favor_tenant: u32,
+}
+
+#[contractevent(topics = ["arbiter_rewarded"])]
+pub struct ArbiterRewarded {
+    #[topic]
+    pub dispute_id: u32,
+    #[topic]
+    pub arbiter: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["arbiter_slashed"])]
+pub struct ArbiterSlashed {
+    #[topic]
+    pub dispute_id: u32,
+    #[topic]
+    pub arbiter: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["dispute_appealed"])]
+pub struct DisputeAppealed {
+    #[topic]
+    pub dispute_id: u32,
+    #[topic]
+    pub appellant: Address,
+    pub bond: i128,
+    pub panel: Vec<Address>,
+}
+
+#[contractevent(topics = ["settlement_proposed"])]
+pub struct SettlementProposed {
+    #[topic]
+    pub dispute_id: u32,
+    #[topic]
+    pub proposer: Address,
+    pub landlord_share_bps: u32,
+    pub terminate: bool,
+}
+
+#[contractevent(topics = ["dispute_settled"])]
+pub struct DisputeSettled {
+    #[topic]
+    pub dispute_id: u32,
+    pub landlord_share_bps: u32,
+    pub terminate: bool,
+}
+
+#[contractevent(topics = ["outcome_enforced"])]
+pub struct OutcomeEnforced {
+    #[topic]
+    pub dispute_id: u32,
+    pub outcome: DisputeOutcome,
+}
+
 #[contractevent(topics = ["dispute_resolved"])]
 pub struct DisputeResolved {
     #[topic]
-    pub agreement_id: String,
+    pub dispute_id: u32,
     pub outcome: DisputeOutcome,
     pub votes_favor_landlord: u32,
     pub votes_favor_tenant: u32,
 }
 
+#[contractevent(topics = ["upgraded"])]
+pub struct ContractUpgraded {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+#[contractevent(topics = ["migrated"])]
+pub struct ContractMigrated {
+    #[topic]
+    pub admin: Address,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+#[contractevent(topics = ["arbiter_migrated"])]
+pub struct ArbiterMigrated {
+    #[topic]
+    pub arbiter: Address,
+}
+
+#[contractevent(topics = ["op_paused"])]
+pub struct OperationPaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+#[contractevent(topics = ["op_unpaused"])]
+pub struct OperationUnpaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
 pub(crate) fn contract_initialized(env: &Env, admin: Address, min_votes_required: u32) {
     ContractInitialized {
         admin,
@@ -54,35 +287,291 @@ pub(crate) fn arbiter_added(env: &Env, admin: Address, arbiter: Address) {
     ArbiterAdded { admin, arbiter }.publish(env);
 }
 
-pub(crate) fn dispute_raised(env: &Env, agreement_id: String, details_hash: String) {
+pub(crate) fn arbiter_deactivated(env: &Env, admin: Address, arbiter: Address) {
+    ArbiterDeactivated { admin, arbiter }.publish(env);
+}
+
+pub(crate) fn arbiter_reactivated(env: &Env, admin: Address, arbiter: Address) {
+    ArbiterReactivated { admin, arbiter }.publish(env);
+}
+
+pub(crate) fn arbiter_removed(env: &Env, admin: Address, arbiter: Address) {
+    ArbiterRemoved { admin, arbiter }.publish(env);
+}
+
+pub(crate) fn escrow_contract_set(env: &Env, admin: Address, escrow_contract: Address) {
+    EscrowContractSet {
+        admin,
+        escrow_contract,
+    }
+    .publish(env);
+}
+
+pub(crate) fn voting_rules_updated(env: &Env, admin: Address, rules: VotingRules) {
+    VotingRulesUpdated {
+        admin,
+        voting_period: rules.voting_period,
+        evidence_period: rules.evidence_period,
+        supermajority_bps: rules.supermajority_bps,
+        tie_rule: rules.tie_rule,
+        max_extensions: rules.max_extensions,
+    }
+    .publish(env);
+}
+
+pub(crate) fn panel_size_set(env: &Env, admin: Address, panel_size: u32) {
+    PanelSizeSet { admin, panel_size }.publish(env);
+}
+
+pub(crate) fn staking_config_set(env: &Env, admin: Address, config: StakingConfig) {
+    StakingConfigSet {
+        admin,
+        token: config.token,
+        min_stake: config.min_stake,
+        filing_fee: config.filing_fee,
+        no_show_slash_bps: config.no_show_slash_bps,
+    }
+    .publish(env);
+}
+
+pub(crate) fn arbiter_staked(env: &Env, arbiter: Address, amount: i128, total_stake: i128) {
+    ArbiterStaked {
+        arbiter,
+        amount,
+        total_stake,
+    }
+    .publish(env);
+}
+
+pub(crate) fn arbiter_unstaked(env: &Env, arbiter: Address, amount: i128, total_stake: i128) {
+    ArbiterUnstaked {
+        arbiter,
+        amount,
+        total_stake,
+    }
+    .publish(env);
+}
+
+pub(crate) fn appeal_config_set(env: &Env, admin: Address, config: AppealConfig) {
+    AppealConfigSet {
+        admin,
+        appeal_period: config.appeal_period,
+        bond: config.bond,
+        panel_size: config.panel_size,
+    }
+    .publish(env);
+}
+
+pub(crate) fn category_config_set(
+    env: &Env,
+    admin: Address,
+    category: DisputeCategory,
+    config: CategoryConfig,
+) {
+    CategoryConfigSet {
+        admin,
+        category,
+        required_votes: config.required_votes,
+        voting_period: config.voting_period,
+        filing_fee: config.filing_fee,
+        allowed_outcomes: config.allowed_outcomes,
+    }
+    .publish(env);
+}
+
+pub(crate) fn dispute_raised(
+    env: &Env,
+    dispute_id: u32,
+    agreement_id: String,
+    category: DisputeCategory,
+    details_hash: String,
+) {
     DisputeRaised {
+        dispute_id,
         agreement_id,
+        category,
         details_hash,
     }
     .publish(env);
 }
 
-pub(crate) fn vote_cast(env: &Env, agreement_id: String, arbiter: Address, favor_landlord: bool) {
+pub(crate) fn panel_assigned(env: &Env, dispute_id: u32, panel: Vec<Address>) {
+    PanelAssigned { dispute_id, panel }.publish(env);
+}
+
+pub(crate) fn arbiter_recused(
+    env: &Env,
+    dispute_id: u32,
+    arbiter: Address,
+    replacement: Option<Address>,
+) {
+    ArbiterRecused {
+        dispute_id,
+        arbiter,
+        replacement,
+    }
+    .publish(env);
+}
+
+pub(crate) fn evidence_submitted(
+    env: &Env,
+    dispute_id: u32,
+    submitter: Address,
+    index: u32,
+    kind: EvidenceKind,
+    hash: String,
+) {
+    EvidenceSubmitted {
+        dispute_id,
+        submitter,
+        index,
+        kind,
+        hash,
+    }
+    .publish(env);
+}
+
+pub(crate) fn vote_cast(env: &Env, dispute_id: u32, arbiter: Address, favor_landlord: bool) {
     VoteCast {
-        agreement_id,
+        dispute_id,
         arbiter,
         favor_landlord,
     }
     .publish(env);
 }
 
+pub(crate) fn voting_extended(env: &Env, dispute_id: u32, voting_deadline: u64, extensions: u32) {
+    VotingExtended {
+        dispute_id,
+        voting_deadline,
+        extensions,
+    }
+    .publish(env);
+}
+
+pub(crate) fn dispute_escalated(
+    env: &Env,
+    dispute_id: u32,
+    votes_favor_landlord: u32,
+    votes_favor_tenant: u32,
+) {
+    DisputeEscalated {
+        dispute_id,
+        votes_favor_landlord,
+        votes_favor_tenant,
+    }
+    .publish(env);
+}
+
+pub(crate) fn arbiter_rewarded(env: &Env, dispute_id: u32, arbiter: Address, amount: i128) {
+    ArbiterRewarded {
+        dispute_id,
+        arbiter,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn arbiter_slashed(env: &Env, dispute_id: u32, arbiter: Address, amount: i128) {
+    ArbiterSlashed {
+        dispute_id,
+        arbiter,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn dispute_appealed(
+    env: &Env,
+    dispute_id: u32,
+    appellant: Address,
+    bond: i128,
+    panel: Vec<Address>,
+) {
+    DisputeAppealed {
+        dispute_id,
+        appellant,
+        bond,
+        panel,
+    }
+    .publish(env);
+}
+
+pub(crate) fn settlement_proposed(
+    env: &Env,
+    dispute_id: u32,
+    proposer: Address,
+    landlord_share_bps: u32,
+    terminate: bool,
+) {
+    SettlementProposed {
+        dispute_id,
+        proposer,
+        landlord_share_bps,
+        terminate,
+    }
+    .publish(env);
+}
+
+pub(crate) fn dispute_settled(
+    env: &Env,
+    dispute_id: u32,
+    landlord_share_bps: u32,
+    terminate: bool,
+) {
+    DisputeSettled {
+        dispute_id,
+        landlord_share_bps,
+        terminate,
+    }
+    .publish(env);
+}
+
+pub(crate) fn outcome_enforced(env: &Env, dispute_id: u32, outcome: DisputeOutcome) {
+    OutcomeEnforced {
+        dispute_id,
+        outcome,
+    }
+    .publish(env);
+}
+
 pub(crate) fn dispute_resolved(
     env: &Env,
-    agreement_id: String,
+    dispute_id: u32,
     outcome: DisputeOutcome,
     votes_favor_landlord: u32,
     votes_favor_tenant: u32,
 ) {
     DisputeResolved {
-        agreement_id,
+        dispute_id,
         outcome,
         votes_favor_landlord,
         votes_favor_tenant,
     }
     .publish(env);
 }
+
+pub(crate) fn contract_upgraded(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    ContractUpgraded { admin, wasm_hash }.publish(env);
+}
+
+pub(crate) fn contract_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    ContractMigrated {
+        admin,
+        from_version,
+        to_version,
+    }
+    .publish(env);
+}
+
+pub(crate) fn arbiter_migrated(env: &Env, arbiter: Address) {
+    ArbiterMigrated { arbiter }.publish(env);
+}
+
+pub(crate) fn operation_paused(env: &Env, operation: Operation, by: Address) {
+    OperationPaused { operation, by }.publish(env);
+}
+
+pub(crate) fn operation_unpaused(env: &Env, operation: Operation, by: Address) {
+    OperationUnpaused { operation, by }.publish(env);
+}
@@ -0,0 +1,229 @@
+use chioma_types::ChiomaClient;
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::access;
+use crate::errors::DisputeError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{ContractState, Dispute, Evidence, EvidenceKind, Operation};
+use crate::upgrade;
+
+/// Maximum number of timeline entries per dispute, including statements.
+pub const MAX_EVIDENCE: u32 = 20;
+
+/// Stores a new timeline entry for `dispute` and bumps its `evidence_count`.
+///
+/// The caller is responsible for persisting the updated dispute.
+pub(crate) fn append(
+    env: &Env,
+    dispute: &mut Dispute,
+    submitter: Address,
+    kind: EvidenceKind,
+    hash: String,
+    content_type: String,
+) {
+    let index = dispute.evidence_count;
+    let entry = Evidence {
+        submitter: submitter.clone(),
+        kind: kind.clone(),
+        hash: hash.clone(),
+        content_type,
+        submitted_at: env.ledger().timestamp(),
+    };
+
+    let key = DataKey::Evidence(dispute.id, index);
+    env.storage().persistent().set(&key, &entry);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+    dispute.evidence_count += 1;
+
+    events::evidence_submitted(env, dispute.id, submitter, index, kind, hash);
+}
+
+/// Loads an open dispute that `submitter` may add to the timeline of.
+fn load_for_submission(
+    env: &Env,
+    submitter: &Address,
+    dispute_id: u32,
+    hash: &String,
+) -> Result<Dispute, DisputeError> {
+    if !env.storage().persistent().has(&DataKey::Initialized) {
+        return Err(DisputeError::NotInitialized);
+    }
+
+    submitter.require_auth();
+
+    if hash.is_empty() {
+        return Err(DisputeError::InvalidDetailsHash);
+    }
+
+    let dispute: Dispute = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Dispute(dispute_id))
+        .ok_or(DisputeError::DisputeNotFound)?;
+
+    if dispute.resolved {
+        return Err(DisputeError::DisputeAlreadyResolved);
+    }
+
+    if *submitter != dispute.landlord && !dispute.is_tenant_side(submitter) {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    if env.ledger().timestamp() > dispute.evidence_deadline {
+        return Err(DisputeError::EvidenceWindowClosed);
+    }
+
+    if dispute.evidence_count >= MAX_EVIDENCE {
+        return Err(DisputeError::EvidenceLimitReached);
+    }
+
+    Ok(dispute)
+}
+
+fn save(env: &Env, dispute: &Dispute) {
+    let key = DataKey::Dispute(dispute.id);
+    env.storage().persistent().set(&key, dispute);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+}
+
+pub fn submit_evidence(
+    env: &Env,
+    submitter: Address,
+    dispute_id: u32,
+    hash: String,
+    content_type: String,
+) -> Result<u32, DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Evidence)?;
+
+    let mut dispute = load_for_submission(env, &submitter, dispute_id, &hash)?;
+
+    let index = dispute.evidence_count;
+    append(
+        env,
+        &mut dispute,
+        submitter,
+        EvidenceKind::Evidence,
+        hash,
+        content_type,
+    );
+    save(env, &dispute);
+
+    Ok(index)
+}
+
+pub fn submit_counter_statement(
+    env: &Env,
+    respondent: Address,
+    dispute_id: u32,
+    hash: String,
+) -> Result<u32, DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Evidence)?;
+
+    let mut dispute = load_for_submission(env, &respondent, dispute_id, &hash)?;
+
+    // The answer comes from the other side of the agreement
+    if dispute.is_tenant_side(&respondent) == dispute.is_tenant_side(&dispute.raiser) {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    let already_answered = get_timeline(env, dispute_id)
+        .iter()
+        .any(|entry| entry.kind == EvidenceKind::CounterStatement);
+    if already_answered {
+        return Err(DisputeError::CounterStatementExists);
+    }
+
+    let index = dispute.evidence_count;
+    append(
+        env,
+        &mut dispute,
+        respondent,
+        EvidenceKind::CounterStatement,
+        hash,
+        String::from_str(env, ""),
+    );
+    save(env, &dispute);
+
+    Ok(index)
+}
+
+pub fn cite_maintenance_request(
+    env: &Env,
+    submitter: Address,
+    dispute_id: u32,
+    request_id: u32,
+) -> Result<u32, DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Evidence)?;
+
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    let request = ChiomaClient::new(env, &state.chioma_contract)
+        .get_maintenance_request(&request_id)
+        .ok_or(DisputeError::MaintenanceRequestNotFound)?;
+
+    let mut dispute = load_for_submission(env, &submitter, dispute_id, &request.description_hash)?;
+
+    if request.agreement_id != dispute.agreement_id {
+        return Err(DisputeError::MaintenanceRequestMismatch);
+    }
+
+    let kind = EvidenceKind::MaintenanceRecord(request_id);
+    let already_cited = get_timeline(env, dispute_id)
+        .iter()
+        .any(|entry| entry.kind == kind);
+    if already_cited {
+        return Err(DisputeError::MaintenanceAlreadyCited);
+    }
+
+    let index = dispute.evidence_count;
+    append(
+        env,
+        &mut dispute,
+        submitter,
+        kind,
+        request.description_hash,
+        String::from_str(env, ""),
+    );
+    save(env, &dispute);
+
+    Ok(index)
+}
+
+pub fn get_evidence(env: &Env, dispute_id: u32, index: u32) -> Option<Evidence> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Evidence(dispute_id, index))
+}
+
+/// Returns every timeline entry for a dispute in submission order.
+pub fn get_timeline(env: &Env, dispute_id: u32) -> Vec<Evidence> {
+    let mut timeline = Vec::new(env);
+
+    let Some(dispute) = env
+        .storage()
+        .persistent()
+        .get::<_, Dispute>(&DataKey::Dispute(dispute_id))
+    else {
+        return timeline;
+    };
+
+    for index in 0..dispute.evidence_count {
+        if let Some(entry) = get_evidence(env, dispute_id, index) {
+            timeline.push_back(entry);
+        }
+    }
+
+    timeline
+}
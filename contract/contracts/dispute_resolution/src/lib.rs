@@ -1,23 +1,53 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use access::Access;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
+mod access;
+mod appeal;
+mod arbiter;
+mod category;
 mod dispute;
 mod errors;
 mod events;
+mod evidence;
+mod panel;
+mod settlement;
+mod staking;
 mod storage;
 mod types;
+mod upgrade;
 
 #[cfg(test)]
 mod tests;
 
+pub use access::{has_role, is_paused, pause, unpause};
+pub use appeal::{appeal, enforce_resolution, get_appeal_config, set_appeal_config};
+pub use arbiter::{
+    add_arbiter, deactivate_arbiter, get_arbiter, get_arbiter_count, get_arbiter_stats,
+    list_arbiters, reactivate_arbiter, remove_arbiter, MAX_PAGE_SIZE,
+};
+pub use category::{get_category_config, set_category_config};
 pub use dispute::{
-    add_arbiter, get_arbiter, get_arbiter_count, get_dispute, get_vote, raise_dispute,
-    resolve_dispute, vote_on_dispute,
+    get_agreement_dispute_count, get_dispute, get_dispute_count, get_escrow_contract, get_vote,
+    get_voting_rules, list_agreement_disputes, raise_dispute, resolve_dispute,
+    resolve_escalated_dispute, set_escrow_contract, set_voting_rules, vote_on_dispute,
 };
 pub use errors::DisputeError;
+pub use evidence::{
+    cite_maintenance_request, get_evidence, get_timeline, submit_counter_statement,
+    submit_evidence, MAX_EVIDENCE,
+};
+pub use panel::{get_panel_size, recuse, set_panel_size, MAX_DRAW_PROBES};
+pub use settlement::{accept_settlement, get_settlement, propose_settlement};
+pub use staking::{get_staking_config, set_staking_config, stake, unstake};
 pub use storage::DataKey;
-pub use types::{Arbiter, ContractState, Dispute, DisputeOutcome, Vote};
+pub use types::{
+    AppealConfig, Arbiter, ArbiterStats, CategoryConfig, ContractState, Dispute, DisputeCategory,
+    DisputeOutcome, Evidence, EvidenceKind, LegacyArbiter, Operation, Role, Settlement,
+    StakingConfig, TieRule, Vote, VotingRules,
+};
+pub use upgrade::{get_schema_version, migrate, migrate_arbiter, upgrade, SCHEMA_VERSION};
 
 #[contract]
 pub struct DisputeResolutionContract;
@@ -59,6 +89,7 @@ impl DisputeResolutionContract {
 
         env.storage().instance().set(&DataKey::State, &state);
         env.storage().instance().extend_ttl(500000, 500000);
+        upgrade::set_schema_version(&env, upgrade::SCHEMA_VERSION);
 
         events::contract_initialized(&env, admin, min_votes_required);
 
@@ -73,46 +104,593 @@ impl DisputeResolutionContract {
         env.storage().instance().get(&DataKey::State)
     }
 
-    /// Add a verified arbiter to handle disputes (admin only).
+    /// Replace the contract's code with a previously uploaded WASM (admin only).
+    ///
+    /// Storage is kept across the upgrade. The admin may be a multisig
+    /// account, in which case its signers authorize the upgrade together.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the upgrade
+    /// * `new_wasm_hash` - The hash of the uploaded WASM to run from now on
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), DisputeError> {
+        upgrade::upgrade(&env, admin, new_wasm_hash)
+    }
+
+    /// Bring stored data up to the schema this code expects (admin only).
+    ///
+    /// Call after `upgrade` when the new code changes the storage schema.
+    /// Does nothing if the data is already current.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the migration
+    ///
+    /// # Returns
+    /// * `u32` - The schema version after migrating
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, DisputeError> {
+        upgrade::migrate(&env, admin)
+    }
+
+    /// Get the schema version of the stored data.
+    ///
+    /// # Returns
+    /// * `u32` - The stored schema version, 0 if written before versioning
+    pub fn get_schema_version(env: Env) -> u32 {
+        upgrade::get_schema_version(&env)
+    }
+
+    /// Convert a first-version arbiter and add it to the arbiter index.
+    ///
+    /// Anyone may call this once `migrate` has run. Does nothing for an
+    /// arbiter that is already current.
+    ///
+    /// # Arguments
+    /// * `arbiter` - The address of the arbiter to convert
+    ///
+    /// # Errors
+    /// * `NotMigrated` - If `migrate` hasn't run yet
+    /// * `ArbiterNotFound` - If no arbiter is stored for the address
+    pub fn migrate_arbiter(env: Env, arbiter: Address) -> Result<(), DisputeError> {
+        upgrade::migrate_arbiter(&env, arbiter)
+    }
+
+    /// Nominate a new admin (admin only).
+    ///
+    /// The current admin stays in charge until the nominee calls
+    /// `accept_admin`, so a mistyped address cannot lock the contract.
+    ///
+    /// # Arguments
+    /// * `admin` - The current admin address
+    /// * `new_admin` - The address nominated to take over
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), DisputeError> {
+        chioma_types::access::propose_admin::<Access>(&env, admin, new_admin)
+    }
+
+    /// Accept a pending admin nomination.
+    ///
+    /// # Arguments
+    /// * `new_admin` - The nominated address
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `NoPendingAdmin` - If no admin has been nominated
+    /// * `Unauthorized` - If the caller is not the nominee
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), DisputeError> {
+        chioma_types::access::accept_admin::<Access>(&env, new_admin)
+    }
+
+    /// Get the nominated admin awaiting acceptance, if any.
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The pending admin
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        chioma_types::access::get_pending_admin(&env)
+    }
+
+    /// Grant an operational role to an account (admin only).
+    ///
+    /// Only `ArbiterManager`, `Pauser` and `Guardian` can be granted on this
+    /// contract.
     ///
     /// # Arguments
     /// * `admin` - The admin address performing the action
-    /// * `arbiter` - The address of the arbiter to add
+    /// * `role` - The role to grant
+    /// * `account` - The account receiving the role
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidRole` - If the role can't be granted on this contract
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), DisputeError> {
+        chioma_types::access::grant_role::<Access>(&env, admin, role, account)
+    }
+
+    /// Revoke an operational role from an account (admin only).
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `role` - The role to revoke
+    /// * `account` - The account losing the role
     ///
     /// # Errors
     /// * `NotInitialized` - If the contract hasn't been initialized
     /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidRole` - If the role can't be granted on this contract
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), DisputeError> {
+        chioma_types::access::revoke_role::<Access>(&env, admin, role, account)
+    }
+
+    /// Check whether an account holds a role. The admin holds every role.
+    ///
+    /// # Returns
+    /// * `bool` - True if the account holds the role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access::has_role(&env, role, account)
+    }
+
+    /// Pause one group of operations, leaving reads and the rest of the
+    /// contract running (admin, pauser or guardian).
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `Pauser` or `Guardian`
+    /// * `operation` - The operation to pause
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller holds none of the roles
+    pub fn pause(env: Env, caller: Address, operation: Operation) -> Result<(), DisputeError> {
+        access::pause(&env, caller, operation)
+    }
+
+    /// Resume a paused operation (admin or pauser). Guardians can't unpause.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `Pauser`
+    /// * `operation` - The operation to resume
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller holds neither role
+    pub fn unpause(env: Env, caller: Address, operation: Operation) -> Result<(), DisputeError> {
+        access::unpause(&env, caller, operation)
+    }
+
+    /// Check whether an operation is paused.
+    ///
+    /// # Returns
+    /// * `bool` - True if calls in the operation are currently rejected
+    pub fn is_paused(env: Env, operation: Operation) -> bool {
+        access::is_paused(&env, operation)
+    }
+
+    /// Add a verified arbiter to handle disputes (admin or arbiter manager).
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `ArbiterManager`
+    /// * `arbiter` - The address of the arbiter to add
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller holds neither role
     /// * `ArbiterAlreadyExists` - If the arbiter is already registered
-    pub fn add_arbiter(env: Env, admin: Address, arbiter: Address) -> Result<(), DisputeError> {
-        dispute::add_arbiter(&env, admin, arbiter)
+    pub fn add_arbiter(env: Env, caller: Address, arbiter: Address) -> Result<(), DisputeError> {
+        arbiter::add_arbiter(&env, caller, arbiter)
+    }
+
+    /// Deactivate an arbiter (admin or arbiter manager).
+    ///
+    /// Inactive arbiters cannot vote and are not drawn for new panels. Their
+    /// existing panel seats remain until those disputes resolve.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `ArbiterManager`
+    /// * `arbiter` - The address of the arbiter to deactivate
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller holds neither role
+    /// * `ArbiterNotFound` - If the arbiter isn't registered
+    pub fn deactivate_arbiter(
+        env: Env,
+        caller: Address,
+        arbiter: Address,
+    ) -> Result<(), DisputeError> {
+        arbiter::deactivate_arbiter(&env, caller, arbiter)
+    }
+
+    /// Reactivate a deactivated arbiter (admin or arbiter manager).
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `ArbiterManager`
+    /// * `arbiter` - The address of the arbiter to reactivate
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller holds neither role
+    /// * `ArbiterNotFound` - If the arbiter isn't registered
+    /// * `InsufficientStake` - If the arbiter holds less than the minimum stake
+    pub fn reactivate_arbiter(
+        env: Env,
+        caller: Address,
+        arbiter: Address,
+    ) -> Result<(), DisputeError> {
+        arbiter::reactivate_arbiter(&env, caller, arbiter)
+    }
+
+    /// Remove an arbiter from the registry (admin or arbiter manager).
+    ///
+    /// Any remaining stake is returned to the arbiter. To cut off a compromised
+    /// key immediately, deactivate it first and remove it once its open
+    /// disputes have resolved.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `ArbiterManager`
+    /// * `arbiter` - The address of the arbiter to remove
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller holds neither role
+    /// * `ArbiterNotFound` - If the arbiter isn't registered
+    /// * `StakeLocked` - If the arbiter sits on an unresolved dispute
+    pub fn remove_arbiter(env: Env, caller: Address, arbiter: Address) -> Result<(), DisputeError> {
+        arbiter::remove_arbiter(&env, caller, arbiter)
+    }
+
+    /// Register the escrow contract holding security deposits (admin only).
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `escrow_contract` - Address of the escrow contract
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn set_escrow_contract(
+        env: Env,
+        admin: Address,
+        escrow_contract: Address,
+    ) -> Result<(), DisputeError> {
+        dispute::set_escrow_contract(&env, admin, escrow_contract)
+    }
+
+    /// Get the registered escrow contract, if any.
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The escrow contract address if registered
+    pub fn get_escrow_contract(env: Env) -> Option<Address> {
+        dispute::get_escrow_contract(&env)
+    }
+
+    /// Set the rules used to vote on and decide disputes (admin only).
+    ///
+    /// Applies to disputes raised afterwards; the voting deadline of an open
+    /// dispute is fixed when it is raised.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `rules` - Voting period, supermajority threshold and tie rule
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidVotingRules` - If the period is zero, the evidence period is not shorter
+    ///   than it or than a category's voting period, or the threshold is outside
+    ///   5000..10000 bps
+    pub fn set_voting_rules(
+        env: Env,
+        admin: Address,
+        rules: VotingRules,
+    ) -> Result<(), DisputeError> {
+        dispute::set_voting_rules(&env, admin, rules)
+    }
+
+    /// Get the current voting rules.
+    ///
+    /// # Returns
+    /// * `VotingRules` - The configured rules, or the defaults if never set
+    pub fn get_voting_rules(env: Env) -> VotingRules {
+        dispute::get_voting_rules(&env)
+    }
+
+    /// Set how many arbiters are drawn for each new dispute (admin only).
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `panel_size` - Number of arbiters per panel
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidPanelSize` - If the size is zero or below the minimum votes required
+    pub fn set_panel_size(env: Env, admin: Address, panel_size: u32) -> Result<(), DisputeError> {
+        panel::set_panel_size(&env, admin, panel_size)
+    }
+
+    /// Get the number of arbiters drawn for each new dispute.
+    ///
+    /// # Returns
+    /// * `u32` - The configured panel size, or `min_votes_required` if never set
+    pub fn get_panel_size(env: Env) -> Result<u32, DisputeError> {
+        let state: ContractState = env
+            .storage()
+            .instance()
+            .get(&DataKey::State)
+            .ok_or(DisputeError::NotInitialized)?;
+        Ok(panel::get_panel_size(&env, &state))
+    }
+
+    /// Configure arbiter staking, the dispute filing fee and no-show slashing (admin only).
+    ///
+    /// The token cannot be changed once set.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `config` - Token, minimum stake, filing fee and slash rate
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidStakingConfig` - If an amount is negative, the slash rate exceeds
+    ///   10000 bps, or the token differs from the configured one
+    pub fn set_staking_config(
+        env: Env,
+        admin: Address,
+        config: StakingConfig,
+    ) -> Result<(), DisputeError> {
+        staking::set_staking_config(&env, admin, config)
+    }
+
+    /// Get the staking configuration, if set.
+    pub fn get_staking_config(env: Env) -> Option<StakingConfig> {
+        staking::get_staking_config(&env)
+    }
+
+    /// Stake tokens as an arbiter. Reaching the minimum stake activates the arbiter
+    /// unless the admin has deactivated it.
+    ///
+    /// # Arguments
+    /// * `arbiter` - The registered arbiter staking
+    /// * `amount` - Amount of the staking token to transfer in
+    ///
+    /// # Errors
+    /// * `StakingNotConfigured` - If staking hasn't been configured
+    /// * `InvalidAmount` - If the amount is not positive
+    /// * `ArbiterNotFound` - If the arbiter isn't registered
+    pub fn stake(env: Env, arbiter: Address, amount: i128) -> Result<(), DisputeError> {
+        staking::stake(&env, arbiter, amount)
+    }
+
+    /// Withdraw staked tokens. Dropping below the minimum stake deactivates the arbiter.
+    ///
+    /// # Arguments
+    /// * `arbiter` - The registered arbiter unstaking
+    /// * `amount` - Amount of the staking token to withdraw
+    ///
+    /// # Errors
+    /// * `StakingNotConfigured` - If staking hasn't been configured
+    /// * `InvalidAmount` - If the amount is not positive
+    /// * `ArbiterNotFound` - If the arbiter isn't registered
+    /// * `StakeLocked` - If the arbiter sits on an unresolved dispute
+    /// * `InsufficientStake` - If the amount exceeds the arbiter's stake
+    pub fn unstake(env: Env, arbiter: Address, amount: i128) -> Result<(), DisputeError> {
+        staking::unstake(&env, arbiter, amount)
+    }
+
+    /// Set the rules for one dispute category (admin only).
+    ///
+    /// Applies to disputes raised afterwards. Categories without a config use
+    /// `min_votes_required`, the voting rules' period and the staking filing
+    /// fee, and allow every outcome.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `category` - The category to configure
+    /// * `config` - Quorum, voting window, filing fee and allowed outcomes
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidCategoryConfig` - If the quorum or window is zero, the window is
+    ///   not longer than the evidence period, the fee is negative, or no arbiter
+    ///   outcome is allowed
+    /// * `StakingNotConfigured` - If a fee is set but no staking token is set
+    pub fn set_category_config(
+        env: Env,
+        admin: Address,
+        category: DisputeCategory,
+        config: CategoryConfig,
+    ) -> Result<(), DisputeError> {
+        category::set_category_config(&env, admin, category, config)
+    }
+
+    /// Get the config for a dispute category, if set.
+    pub fn get_category_config(env: Env, category: DisputeCategory) -> Option<CategoryConfig> {
+        category::get_category_config(&env, category)
     }
 
     /// Raise a dispute for a specific agreement.
     ///
+    /// Marks the agreement as `Disputed` on the chioma contract and, when an
+    /// escrow is given, freezes the deposit held in the escrow contract.
+    ///
+    /// A panel of arbiters is drawn at random from the active pool, excluding
+    /// the landlord, tenant and agent on the agreement. Only panel members may
+    /// vote. If fewer eligible arbiters exist than the panel size, the panel
+    /// is short and the tie rule applies should quorum not be reached.
+    ///
+    /// The dispute's category sets its quorum, voting window, filing fee and
+    /// allowed outcomes (see `set_category_config`). When staking is
+    /// configured, the raiser pays the filing fee.
+    ///
     /// # Arguments
     /// * `raiser` - The address raising the dispute (must be tenant or landlord)
     /// * `agreement_id` - Unique identifier for the agreement in dispute
+    /// * `category` - What the dispute is about
     /// * `details_hash` - Hash reference to off-chain evidence/details (IPFS, etc.)
+    /// * `escrow_id` - Optional escrow holding the agreement's security deposit
+    ///
+    /// # Returns
+    /// * `u32` - The ID of the new dispute
     ///
     /// # Errors
     /// * `NotInitialized` - If the contract hasn't been initialized
-    /// * `DisputeAlreadyExists` - If a dispute already exists for this agreement
+    /// * `DisputeAlreadyExists` - If a dispute on this agreement is still open
     /// * `InvalidDetailsHash` - If the details hash is empty
+    /// * `EscrowNotConfigured` - If an escrow is given but no escrow contract is registered
+    /// * `InvalidEscrow` - If the escrow is not a funded deposit from this agreement's
+    ///   tenant to its landlord arbitrated by this contract
     /// * `Unauthorized` - If raiser is not a party to the agreement
+    /// * `StakingNotConfigured` - If the category charges a filing fee but staking
+    ///   is not configured
     pub fn raise_dispute(
         env: Env,
         raiser: Address,
         agreement_id: String,
+        category: DisputeCategory,
         details_hash: String,
-    ) -> Result<(), DisputeError> {
-        dispute::raise_dispute(&env, raiser, agreement_id, details_hash)
+        escrow_id: Option<BytesN<32>>,
+    ) -> Result<u32, DisputeError> {
+        dispute::raise_dispute(
+            &env,
+            raiser,
+            agreement_id,
+            category,
+            details_hash,
+            escrow_id,
+        )
+    }
+
+    /// Attach evidence to a dispute's timeline (landlord, tenant or co-tenants only).
+    ///
+    /// Accepted until the dispute's evidence deadline.
+    ///
+    /// # Arguments
+    /// * `submitter` - The landlord, tenant or a co-tenant on the agreement
+    /// * `dispute_id` - The ID of the dispute
+    /// * `hash` - Hash reference to the off-chain content (IPFS, etc.)
+    /// * `content_type` - MIME type of the content
+    ///
+    /// # Returns
+    /// * `u32` - Position of the entry in the timeline
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `InvalidDetailsHash` - If the hash is empty
+    /// * `DisputeNotFound` - If the dispute doesn't exist
+    /// * `DisputeAlreadyResolved` - If the dispute has already been resolved
+    /// * `Unauthorized` - If the submitter is not a party to the agreement
+    /// * `EvidenceWindowClosed` - If the evidence deadline has passed
+    /// * `EvidenceLimitReached` - If the timeline already holds `MAX_EVIDENCE` entries
+    pub fn submit_evidence(
+        env: Env,
+        submitter: Address,
+        dispute_id: u32,
+        hash: String,
+        content_type: String,
+    ) -> Result<u32, DisputeError> {
+        evidence::submit_evidence(&env, submitter, dispute_id, hash, content_type)
     }
 
-    /// Vote on an existing dispute (arbiters only).
+    /// Answer the opening statement (the party who did not raise the dispute, once).
+    ///
+    /// # Arguments
+    /// * `respondent` - The landlord, or a tenant if the landlord raised the dispute
+    /// * `dispute_id` - The ID of the dispute
+    /// * `hash` - Hash reference to the off-chain statement
+    ///
+    /// # Returns
+    /// * `u32` - Position of the entry in the timeline
+    ///
+    /// # Errors
+    /// * Same as `submit_evidence`, plus:
+    /// * `Unauthorized` - If the caller is on the same side as the raiser
+    /// * `CounterStatementExists` - If a counter-statement was already submitted
+    pub fn submit_counter_statement(
+        env: Env,
+        respondent: Address,
+        dispute_id: u32,
+        hash: String,
+    ) -> Result<u32, DisputeError> {
+        evidence::submit_counter_statement(&env, respondent, dispute_id, hash)
+    }
+
+    /// Cite a chioma maintenance request on the disputed agreement as evidence
+    /// (landlord or tenant only, once per request).
+    ///
+    /// The entry records the request's description hash. Arbiters read its
+    /// deadlines and any SLA breach from chioma's `get_maintenance_request`.
+    ///
+    /// # Arguments
+    /// * `submitter` - The landlord or tenant on the agreement
+    /// * `dispute_id` - The ID of the dispute
+    /// * `request_id` - The ID of the maintenance request in chioma
+    ///
+    /// # Returns
+    /// * `u32` - Position of the entry in the timeline
+    ///
+    /// # Errors
+    /// * Same as `submit_evidence`, plus:
+    /// * `MaintenanceRequestNotFound` - If chioma has no such request
+    /// * `MaintenanceRequestMismatch` - If the request belongs to another agreement
+    /// * `MaintenanceAlreadyCited` - If the request is already on the timeline
+    pub fn cite_maintenance_request(
+        env: Env,
+        submitter: Address,
+        dispute_id: u32,
+        request_id: u32,
+    ) -> Result<u32, DisputeError> {
+        evidence::cite_maintenance_request(&env, submitter, dispute_id, request_id)
+    }
+
+    /// Get a single timeline entry.
+    ///
+    /// # Arguments
+    /// * `dispute_id` - The ID of the dispute
+    /// * `index` - Position of the entry in the timeline
+    pub fn get_evidence(env: Env, dispute_id: u32, index: u32) -> Option<Evidence> {
+        evidence::get_evidence(&env, dispute_id, index)
+    }
+
+    /// Get a dispute's full timeline in submission order.
+    ///
+    /// The first entry is the raiser's opening statement (`details_hash`).
+    ///
+    /// # Arguments
+    /// * `dispute_id` - The ID of the dispute
+    pub fn get_timeline(env: Env, dispute_id: u32) -> Vec<Evidence> {
+        evidence::get_timeline(&env, dispute_id)
+    }
+
+    /// Vote on an existing dispute (panel members only).
     ///
     /// # Arguments
     /// * `arbiter` - The address of the arbiter voting
-    /// * `agreement_id` - The ID of the agreement in dispute
+    /// * `dispute_id` - The ID of the dispute
     /// * `favor_landlord` - True to vote in favor of landlord, false for tenant
     ///
     /// # Errors
@@ -120,42 +698,286 @@ impl DisputeResolutionContract {
     /// * `ArbiterNotFound` - If the arbiter doesn't exist or is inactive
     /// * `DisputeNotFound` - If the dispute doesn't exist
     /// * `DisputeAlreadyResolved` - If the dispute has already been resolved
+    /// * `DisputeEscalated` - If the dispute has been escalated to the admin
+    /// * `EvidenceWindowOpen` - If the evidence deadline has not passed yet
+    /// * `VotingClosed` - If the voting deadline has passed
+    /// * `NotPanelMember` - If the arbiter is not on the dispute's panel
+    /// * `InvalidOutcome` - If the dispute's category does not allow ruling for that side
     /// * `AlreadyVoted` - If this arbiter has already voted on this dispute
     pub fn vote_on_dispute(
         env: Env,
         arbiter: Address,
-        agreement_id: String,
+        dispute_id: u32,
         favor_landlord: bool,
     ) -> Result<(), DisputeError> {
-        dispute::vote_on_dispute(&env, arbiter, agreement_id, favor_landlord)
+        dispute::vote_on_dispute(&env, arbiter, dispute_id, favor_landlord)
+    }
+
+    /// Step down from a dispute's panel (panel members only).
+    ///
+    /// A replacement is drawn from the eligible pool if one is available.
+    /// Recused arbiters are never drawn for the same dispute again.
+    ///
+    /// # Arguments
+    /// * `arbiter` - The panel member recusing themselves
+    /// * `dispute_id` - The ID of the dispute
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `DisputeNotFound` - If the dispute doesn't exist
+    /// * `DisputeAlreadyResolved` - If the dispute has already been resolved
+    /// * `NotPanelMember` - If the arbiter is not on the dispute's panel
+    /// * `AlreadyVoted` - If the arbiter has already voted on this dispute
+    pub fn recuse(env: Env, arbiter: Address, dispute_id: u32) -> Result<(), DisputeError> {
+        panel::recuse(&env, arbiter, dispute_id)
     }
 
     /// Resolve a dispute by evaluating votes and determining the outcome.
     ///
+    /// Rules, applied in order:
+    /// 1. If quorum is met and one side holds more than the supermajority
+    ///    threshold, that side wins. This may happen before the deadline.
+    /// 2. Otherwise, while voting is open, the call fails.
+    /// 3. Once voting has closed, the tie rule applies: `Split` resolves as
+    ///    `DisputeOutcome::Split`; `ExtendWindow` reopens voting for another
+    ///    period until `max_extensions` is used up, then escalates;
+    ///    `EscalateToAdmin` escalates immediately. Extensions and escalations
+    ///    return `DisputeOutcome::Inconclusive` and leave the dispute open.
+    ///
+    /// A `FavorLandlord` outcome terminates the agreement on the chioma contract
+    /// and releases any escrowed deposit to the landlord. A `FavorTenant` outcome
+    /// restores the agreement to `Active` and refunds the deposit to the tenant.
+    /// A `Split` outcome restores the agreement and splits the deposit evenly.
+    ///
+    /// With staking configured, the filing fee and any stake slashed from panel
+    /// members who missed the deadline are shared by those who voted for the outcome.
+    ///
+    /// With appeals configured, a first-round outcome is recorded but only applied
+    /// by `enforce_resolution` after the appeal window closes.
+    ///
     /// # Arguments
-    /// * `agreement_id` - The ID of the agreement in dispute
+    /// * `dispute_id` - The ID of the dispute
     ///
     /// # Returns
-    /// * `DisputeOutcome` - The outcome of the dispute (FavorLandlord or FavorTenant)
+    /// * `DisputeOutcome` - The outcome of the dispute, or `Inconclusive` if voting was
+    ///   extended or escalated
     ///
     /// # Errors
     /// * `NotInitialized` - If the contract hasn't been initialized
     /// * `DisputeNotFound` - If the dispute doesn't exist
     /// * `DisputeAlreadyResolved` - If the dispute has already been resolved
-    /// * `InsufficientVotes` - If minimum required votes haven't been cast
-    pub fn resolve_dispute(env: Env, agreement_id: String) -> Result<DisputeOutcome, DisputeError> {
-        dispute::resolve_dispute(&env, agreement_id)
+    /// * `DisputeEscalated` - If the dispute awaits an admin decision
+    /// * `InsufficientVotes` - If quorum hasn't been reached and voting is still open
+    /// * `VotingInProgress` - If no side has a decisive majority and voting is still open
+    pub fn resolve_dispute(env: Env, dispute_id: u32) -> Result<DisputeOutcome, DisputeError> {
+        dispute::resolve_dispute(&env, dispute_id)
+    }
+
+    /// Configure appeals (admin only).
+    ///
+    /// Once set, first-round outcomes are enforced only after the appeal window.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `config` - Appeal window, bond and appeal panel size
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidAppealConfig` - If the window is zero, the bond is negative, or the
+    ///   appeal panel is not larger than the first-round panel
+    /// * `StakingNotConfigured` - If a bond is required but no staking token is set
+    pub fn set_appeal_config(
+        env: Env,
+        admin: Address,
+        config: AppealConfig,
+    ) -> Result<(), DisputeError> {
+        appeal::set_appeal_config(&env, admin, config)
+    }
+
+    /// Get the appeal configuration, if set.
+    pub fn get_appeal_config(env: Env) -> Option<AppealConfig> {
+        appeal::get_appeal_config(&env)
+    }
+
+    /// Appeal a first-round outcome (losing party only).
+    ///
+    /// Posts the bond and reopens voting before a larger panel of arbiters who
+    /// did not sit on the first panel. The appeal outcome is final: the bond is
+    /// returned if the outcome changes and forfeited to the appeal panel otherwise.
+    ///
+    /// # Arguments
+    /// * `appellant` - The losing party (either party for a split)
+    /// * `dispute_id` - The ID of the dispute
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `NotAppealable` - If appeals aren't configured, or the dispute was already
+    ///   appealed or enforced
+    /// * `DisputeNotFound` - If the dispute doesn't exist
+    /// * `DisputeNotResolved` - If the dispute hasn't been resolved
+    /// * `AppealWindowClosed` - If the appeal deadline has passed
+    /// * `Unauthorized` - If the appellant did not lose
+    pub fn appeal(env: Env, appellant: Address, dispute_id: u32) -> Result<(), DisputeError> {
+        appeal::appeal(&env, appellant, dispute_id)
+    }
+
+    /// Apply a resolved outcome to the chioma and escrow contracts once the
+    /// appeal window has closed without an appeal. Callable by anyone.
+    ///
+    /// # Arguments
+    /// * `dispute_id` - The ID of the dispute
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `DisputeNotFound` - If the dispute doesn't exist
+    /// * `DisputeNotResolved` - If the dispute hasn't been resolved
+    /// * `DisputeAlreadyResolved` - If the outcome was already enforced
+    /// * `AppealWindowOpen` - If the outcome can still be appealed
+    pub fn enforce_resolution(env: Env, dispute_id: u32) -> Result<(), DisputeError> {
+        appeal::enforce_resolution(&env, dispute_id)
+    }
+
+    /// Offer terms to close a dispute by mutual agreement, without arbiters.
+    ///
+    /// Either party may propose at any point until an outcome is enforced. A new
+    /// proposal replaces any earlier one, so the other party can counter-offer.
+    ///
+    /// # Arguments
+    /// * `proposer` - The landlord or tenant making the offer
+    /// * `dispute_id` - The ID of the dispute
+    /// * `landlord_share_bps` - Share of the escrowed deposit released to the
+    ///   landlord, in basis points; the rest goes to the tenant
+    /// * `terminate` - Whether the agreement ends (`true`) or resumes (`false`)
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `DisputeNotFound` - If the dispute doesn't exist
+    /// * `DisputeAlreadyResolved` - If an outcome has already been enforced
+    /// * `Unauthorized` - If the proposer is not a party to the dispute
+    /// * `InvalidSettlement` - If `landlord_share_bps` exceeds 10000
+    pub fn propose_settlement(
+        env: Env,
+        proposer: Address,
+        dispute_id: u32,
+        landlord_share_bps: u32,
+        terminate: bool,
+    ) -> Result<(), DisputeError> {
+        settlement::propose_settlement(&env, proposer, dispute_id, landlord_share_bps, terminate)
+    }
+
+    /// Accept the other party's settlement proposal.
+    ///
+    /// The acceptor restates the terms, so a proposal replaced after they read
+    /// it is not accepted. Applies the settlement to the chioma and escrow
+    /// contracts and closes the dispute as `Settled`. Panel seats are released,
+    /// and the filing fee and any pending appeal bond are refunded.
+    ///
+    /// # Arguments
+    /// * `acceptor` - The party who did not make the proposal
+    /// * `dispute_id` - The ID of the dispute
+    /// * `landlord_share_bps` - The landlord's share in the proposal being accepted
+    /// * `terminate` - Whether the proposal being accepted ends the agreement
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `DisputeNotFound` - If the dispute doesn't exist
+    /// * `DisputeAlreadyResolved` - If an outcome has already been enforced
+    /// * `Unauthorized` - If the acceptor is not a party, or made the proposal
+    /// * `SettlementNotFound` - If no settlement has been proposed
+    /// * `SettlementChanged` - If the proposal's terms differ from the ones given
+    /// * `EscrowNotConfigured` - If the dispute has an escrow but none is configured
+    pub fn accept_settlement(
+        env: Env,
+        acceptor: Address,
+        dispute_id: u32,
+        landlord_share_bps: u32,
+        terminate: bool,
+    ) -> Result<(), DisputeError> {
+        settlement::accept_settlement(&env, acceptor, dispute_id, landlord_share_bps, terminate)
+    }
+
+    /// Get the pending settlement proposal for a dispute. A proposal is
+    /// removed once it is accepted or the dispute's outcome is enforced.
+    ///
+    /// # Arguments
+    /// * `dispute_id` - The ID of the dispute
+    ///
+    /// # Returns
+    /// * `Option<Settlement>` - The latest proposal, if any
+    pub fn get_settlement(env: Env, dispute_id: u32) -> Option<Settlement> {
+        settlement::get_settlement(&env, dispute_id)
+    }
+
+    /// Decide a dispute that was escalated by the tie rule (admin only).
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `dispute_id` - The ID of the dispute
+    /// * `outcome` - `FavorLandlord`, `FavorTenant` or `Split`
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `DisputeNotFound` - If the dispute doesn't exist
+    /// * `DisputeAlreadyResolved` - If the dispute has already been resolved
+    /// * `DisputeNotEscalated` - If the dispute has not been escalated
+    /// * `InvalidOutcome` - If the dispute's category does not allow the outcome
+    pub fn resolve_escalated_dispute(
+        env: Env,
+        admin: Address,
+        dispute_id: u32,
+        outcome: DisputeOutcome,
+    ) -> Result<DisputeOutcome, DisputeError> {
+        dispute::resolve_escalated_dispute(&env, admin, dispute_id, outcome)
     }
 
     /// Get information about a specific dispute.
     ///
     /// # Arguments
-    /// * `agreement_id` - The ID of the agreement in dispute
+    /// * `dispute_id` - The ID of the dispute
     ///
     /// # Returns
     /// * `Option<Dispute>` - The dispute information if it exists
-    pub fn get_dispute(env: Env, agreement_id: String) -> Option<Dispute> {
-        dispute::get_dispute(&env, agreement_id)
+    pub fn get_dispute(env: Env, dispute_id: u32) -> Option<Dispute> {
+        dispute::get_dispute(&env, dispute_id)
+    }
+
+    /// Get the total number of disputes raised.
+    ///
+    /// # Returns
+    /// * `u32` - The number of disputes; IDs run from 1 to this value
+    pub fn get_dispute_count(env: Env) -> u32 {
+        dispute::get_dispute_count(&env)
+    }
+
+    /// Get the number of disputes raised on an agreement.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - The ID of the agreement
+    ///
+    /// # Returns
+    /// * `u32` - The number of disputes raised on the agreement
+    pub fn get_agreement_dispute_count(env: Env, agreement_id: String) -> u32 {
+        dispute::get_agreement_dispute_count(&env, agreement_id)
+    }
+
+    /// List the disputes raised on an agreement, oldest first.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - The ID of the agreement
+    /// * `start` - Position of the first dispute to return
+    /// * `limit` - Maximum number to return, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * `Vec<Dispute>` - The disputes in the requested page
+    pub fn list_agreement_disputes(
+        env: Env,
+        agreement_id: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Dispute> {
+        dispute::list_agreement_disputes(&env, agreement_id, start, limit)
     }
 
     /// Get information about a specific arbiter.
@@ -166,7 +988,7 @@ impl DisputeResolutionContract {
     /// # Returns
     /// * `Option<Arbiter>` - The arbiter information if they exist
     pub fn get_arbiter(env: Env, arbiter: Address) -> Option<Arbiter> {
-        dispute::get_arbiter(&env, arbiter)
+        arbiter::get_arbiter(&env, arbiter)
     }
 
     /// Get the total count of registered arbiters.
@@ -174,18 +996,42 @@ impl DisputeResolutionContract {
     /// # Returns
     /// * `u32` - The total number of arbiters
     pub fn get_arbiter_count(env: Env) -> u32 {
-        dispute::get_arbiter_count(&env)
+        arbiter::get_arbiter_count(&env)
+    }
+
+    /// Get voting statistics for an arbiter.
+    ///
+    /// # Arguments
+    /// * `arbiter` - The address of the arbiter
+    ///
+    /// # Returns
+    /// * `Option<ArbiterStats>` - Resolved disputes voted on and the share that
+    ///   matched the outcome, if the arbiter exists
+    pub fn get_arbiter_stats(env: Env, arbiter: Address) -> Option<ArbiterStats> {
+        arbiter::get_arbiter_stats(&env, arbiter)
+    }
+
+    /// List registered arbiters.
+    ///
+    /// # Arguments
+    /// * `start` - Position of the first arbiter to return
+    /// * `limit` - Maximum number to return, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * `Vec<Arbiter>` - The arbiters in the requested page
+    pub fn list_arbiters(env: Env, start: u32, limit: u32) -> Vec<Arbiter> {
+        arbiter::list_arbiters(&env, start, limit)
     }
 
     /// Get a specific vote for a dispute.
     ///
     /// # Arguments
-    /// * `agreement_id` - The ID of the agreement in dispute
+    /// * `dispute_id` - The ID of the dispute
     /// * `arbiter` - The address of the arbiter who voted
     ///
     /// # Returns
     /// * `Option<Vote>` - The vote information if it exists
-    pub fn get_vote(env: Env, agreement_id: String, arbiter: Address) -> Option<Vote> {
-        dispute::get_vote(&env, agreement_id, arbiter)
+    pub fn get_vote(env: Env, dispute_id: u32, arbiter: Address) -> Option<Vote> {
+        dispute::get_vote(&env, dispute_id, arbiter)
     }
 }
@@ -0,0 +1,191 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::access;
+use crate::errors::DisputeError;
+use crate::events;
+use crate::staking;
+use crate::storage::DataKey;
+use crate::types::{Arbiter, ContractState, Dispute, Operation};
+use crate::upgrade;
+
+pub fn set_panel_size(env: &Env, admin: Address, panel_size: u32) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    admin.require_auth();
+
+    if admin != state.admin {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    // A panel smaller than the quorum could never resolve a dispute
+    if panel_size == 0 || panel_size < state.min_votes_required {
+        return Err(DisputeError::InvalidPanelSize);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::PanelSize, &panel_size);
+    env.storage().instance().extend_ttl(500000, 500000);
+
+    events::panel_size_set(env, admin, panel_size);
+
+    Ok(())
+}
+
+/// Returns the configured panel size, defaulting to the quorum.
+pub fn get_panel_size(env: &Env, state: &ContractState) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PanelSize)
+        .unwrap_or(state.min_votes_required)
+}
+
+/// Most arbiter slots read when drawing a panel. Each probe reads two
+/// entries, which keeps a draw within the transaction's read limit however
+/// large the pool grows.
+pub const MAX_DRAW_PROBES: u32 = 16;
+
+/// Whether the arbiter at `index` may sit on `dispute`: active and staked,
+/// not a party or agent on the agreement, not already on the panel and not
+/// recused.
+fn eligible_at(env: &Env, dispute: &Dispute, index: u32, min_stake: i128) -> Option<Address> {
+    let address = env
+        .storage()
+        .persistent()
+        .get::<_, Address>(&DataKey::ArbiterAt(index))?;
+
+    if dispute.is_conflicted(&address)
+        || dispute.panel.contains(&address)
+        || dispute.recused.contains(&address)
+        || dispute.prior_panel.contains(&address)
+    {
+        return None;
+    }
+
+    env.storage()
+        .persistent()
+        .get::<_, Arbiter>(&DataKey::Arbiter(address.clone()))
+        .filter(|arbiter| arbiter.active && !arbiter.suspended && arbiter.stake >= min_stake)
+        .map(|_| address)
+}
+
+/// Draws up to `seats` arbiters for `dispute` using the ledger PRNG.
+///
+/// A pool of at most `MAX_DRAW_PROBES` arbiters is read in full. A larger one
+/// is sampled at `MAX_DRAW_PROBES` distinct random slots, so the draw may
+/// come back short even though eligible arbiters remain. Returns fewer than
+/// `seats` when too few are found; the tie rule then decides the dispute if
+/// quorum cannot be reached.
+pub(crate) fn draw_arbiters(env: &Env, dispute: &Dispute, seats: u32) -> Vec<Address> {
+    let count: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ArbiterCount)
+        .unwrap_or(0);
+    let min_stake = staking::min_stake(env);
+
+    let mut slots: Vec<u32> = Vec::new(env);
+    if count <= MAX_DRAW_PROBES {
+        for index in 0..count {
+            slots.push_back(index);
+        }
+        env.prng().shuffle(&mut slots);
+    } else {
+        while slots.len() < MAX_DRAW_PROBES {
+            let index = env.prng().gen_range::<u64>(0..count as u64) as u32;
+            if !slots.contains(index) {
+                slots.push_back(index);
+            }
+        }
+    }
+
+    let mut drawn = Vec::new(env);
+    for index in slots.iter() {
+        if drawn.len() >= seats {
+            break;
+        }
+        if let Some(address) = eligible_at(env, dispute, index, min_stake) {
+            drawn.push_back(address);
+        }
+    }
+
+    drawn
+}
+
+/// Records that `arbiter` holds (`seated`) or gave up a seat on an open dispute.
+pub(crate) fn update_seat(env: &Env, arbiter: &Address, seated: bool) {
+    let key = DataKey::Arbiter(arbiter.clone());
+    let Some(mut arbiter_info) = env.storage().persistent().get::<_, Arbiter>(&key) else {
+        return;
+    };
+
+    arbiter_info.open_disputes = if seated {
+        arbiter_info.open_disputes + 1
+    } else {
+        arbiter_info.open_disputes.saturating_sub(1)
+    };
+
+    env.storage().persistent().set(&key, &arbiter_info);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+}
+
+pub fn recuse(env: &Env, arbiter: Address, dispute_id: u32) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Voting)?;
+
+    if !env.storage().persistent().has(&DataKey::Initialized) {
+        return Err(DisputeError::NotInitialized);
+    }
+
+    arbiter.require_auth();
+
+    let dispute_key = DataKey::Dispute(dispute_id);
+    let mut dispute: Dispute = env
+        .storage()
+        .persistent()
+        .get(&dispute_key)
+        .ok_or(DisputeError::DisputeNotFound)?;
+
+    if dispute.resolved {
+        return Err(DisputeError::DisputeAlreadyResolved);
+    }
+
+    let seat = dispute
+        .panel
+        .first_index_of(&arbiter)
+        .ok_or(DisputeError::NotPanelMember)?;
+
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Vote(dispute_id, arbiter.clone()))
+    {
+        return Err(DisputeError::AlreadyVoted);
+    }
+
+    dispute.panel.remove(seat);
+    dispute.recused.push_back(arbiter.clone());
+    update_seat(env, &arbiter, false);
+
+    let replacement = draw_arbiters(env, &dispute, 1).first();
+    if let Some(replacement) = replacement.clone() {
+        update_seat(env, &replacement, true);
+        dispute.panel.push_back(replacement);
+    }
+
+    env.storage().persistent().set(&dispute_key, &dispute);
+    env.storage()
+        .persistent()
+        .extend_ttl(&dispute_key, 500000, 500000);
+
+    events::arbiter_recused(env, dispute_id, arbiter, replacement);
+
+    Ok(())
+}
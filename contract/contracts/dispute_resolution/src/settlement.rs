@@ -0,0 +1,177 @@
+use chioma_types::{ChiomaClient, EscrowClient};
+use soroban_sdk::{token, Address, Env};
+
+use crate::access;
+use crate::dispute;
+use crate::errors::DisputeError;
+use crate::events;
+use crate::panel;
+use crate::staking;
+use crate::storage::DataKey;
+use crate::types::{ContractState, Dispute, DisputeOutcome, Operation, Settlement};
+use crate::upgrade;
+
+fn load_open_dispute(env: &Env, party: &Address, dispute_id: u32) -> Result<Dispute, DisputeError> {
+    if !env.storage().persistent().has(&DataKey::Initialized) {
+        return Err(DisputeError::NotInitialized);
+    }
+
+    party.require_auth();
+
+    let dispute: Dispute = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Dispute(dispute_id))
+        .ok_or(DisputeError::DisputeNotFound)?;
+
+    // A recorded outcome can still be settled until it is enforced
+    if dispute.enforced {
+        return Err(DisputeError::DisputeAlreadyResolved);
+    }
+
+    if *party != dispute.landlord && *party != dispute.tenant {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    Ok(dispute)
+}
+
+pub fn propose_settlement(
+    env: &Env,
+    proposer: Address,
+    dispute_id: u32,
+    landlord_share_bps: u32,
+    terminate: bool,
+) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Settlements)?;
+
+    load_open_dispute(env, &proposer, dispute_id)?;
+
+    if landlord_share_bps > 10_000 {
+        return Err(DisputeError::InvalidSettlement);
+    }
+
+    // A new proposal from either party replaces the previous one
+    let settlement = Settlement {
+        proposer: proposer.clone(),
+        landlord_share_bps,
+        terminate,
+        proposed_at: env.ledger().timestamp(),
+    };
+
+    let key = DataKey::Settlement(dispute_id);
+    env.storage().persistent().set(&key, &settlement);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+    events::settlement_proposed(env, dispute_id, proposer, landlord_share_bps, terminate);
+
+    Ok(())
+}
+
+pub fn accept_settlement(
+    env: &Env,
+    acceptor: Address,
+    dispute_id: u32,
+    landlord_share_bps: u32,
+    terminate: bool,
+) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Settlements)?;
+
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    let mut dispute = load_open_dispute(env, &acceptor, dispute_id)?;
+
+    let settlement: Settlement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Settlement(dispute_id))
+        .ok_or(DisputeError::SettlementNotFound)?;
+
+    if acceptor == settlement.proposer {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    // The proposer may have replaced the proposal since the acceptor read it
+    if settlement.landlord_share_bps != landlord_share_bps || settlement.terminate != terminate {
+        return Err(DisputeError::SettlementChanged);
+    }
+
+    ChiomaClient::new(env, &state.chioma_contract)
+        .resolve_dispute(&dispute.agreement_id, &settlement.terminate);
+
+    if let Some(escrow_id) = dispute.escrow_id.clone() {
+        let escrow_contract =
+            dispute::get_escrow_contract(env).ok_or(DisputeError::EscrowNotConfigured)?;
+        EscrowClient::new(env, &escrow_contract).resolve_dispute_split(
+            &escrow_id,
+            &env.current_contract_address(),
+            &settlement.landlord_share_bps,
+        );
+    }
+
+    // No arbiter decided the dispute: release seats and refund what was posted
+    if !dispute.resolved {
+        for member in dispute.panel.iter() {
+            panel::update_seat(env, &member, false);
+        }
+    }
+    refund_deposits(env, &dispute);
+
+    dispute.resolved = true;
+    dispute.resolved_at = Some(env.ledger().timestamp());
+    dispute.outcome = DisputeOutcome::Settled;
+    dispute.enforced = true;
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Settlement(dispute_id));
+
+    let dispute_key = DataKey::Dispute(dispute_id);
+    env.storage().persistent().set(&dispute_key, &dispute);
+    env.storage()
+        .persistent()
+        .extend_ttl(&dispute_key, 500000, 500000);
+
+    events::dispute_settled(
+        env,
+        dispute_id,
+        settlement.landlord_share_bps,
+        settlement.terminate,
+    );
+
+    Ok(())
+}
+
+/// Returns the filing fee to the raiser and any pending appeal bond to the
+/// appellant. Once a round has been resolved its fee has already been paid out.
+fn refund_deposits(env: &Env, dispute: &Dispute) {
+    let Some(config) = staking::get_staking_config(env) else {
+        return;
+    };
+    let token_client = token::Client::new(env, &config.token);
+    let contract = env.current_contract_address();
+
+    if dispute.round == 0 && !dispute.resolved && dispute.fee > 0 {
+        token_client.transfer(&contract, &dispute.raiser, &dispute.fee);
+    }
+
+    if let Some(appellant) = dispute.appellant.clone() {
+        if !dispute.resolved && dispute.appeal_bond > 0 {
+            token_client.transfer(&contract, &appellant, &dispute.appeal_bond);
+        }
+    }
+}
+
+pub fn get_settlement(env: &Env, dispute_id: u32) -> Option<Settlement> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Settlement(dispute_id))
+}
@@ -0,0 +1,251 @@
+use soroban_sdk::{token, Address, Env, Vec};
+
+use crate::access;
+use crate::errors::DisputeError;
+use crate::events;
+use crate::panel;
+use crate::storage::DataKey;
+use crate::types::{
+    Arbiter, ContractState, Dispute, DisputeOutcome, Operation, StakingConfig, Vote,
+};
+use crate::upgrade;
+
+pub fn set_staking_config(
+    env: &Env,
+    admin: Address,
+    config: StakingConfig,
+) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    admin.require_auth();
+
+    if admin != state.admin {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    if config.min_stake < 0 || config.filing_fee < 0 || config.no_show_slash_bps > 10_000 {
+        return Err(DisputeError::InvalidStakingConfig);
+    }
+
+    // Stakes and fees already held are denominated in the original token
+    if let Some(current) = get_staking_config(env) {
+        if current.token != config.token {
+            return Err(DisputeError::InvalidStakingConfig);
+        }
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::StakingConfig, &config);
+    env.storage().instance().extend_ttl(500000, 500000);
+
+    events::staking_config_set(env, admin, config);
+
+    Ok(())
+}
+
+pub fn get_staking_config(env: &Env) -> Option<StakingConfig> {
+    env.storage().instance().get(&DataKey::StakingConfig)
+}
+
+/// Minimum stake an arbiter needs to be drawn for a panel.
+pub(crate) fn min_stake(env: &Env) -> i128 {
+    get_staking_config(env).map_or(0, |config| config.min_stake)
+}
+
+pub fn stake(env: &Env, arbiter: Address, amount: i128) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    access::require_not_paused(env, Operation::Staking)?;
+
+    let config = get_staking_config(env).ok_or(DisputeError::StakingNotConfigured)?;
+
+    arbiter.require_auth();
+
+    if amount <= 0 {
+        return Err(DisputeError::InvalidAmount);
+    }
+
+    let key = DataKey::Arbiter(arbiter.clone());
+    let mut arbiter_info: Arbiter = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(DisputeError::ArbiterNotFound)?;
+
+    token::Client::new(env, &config.token).transfer(
+        &arbiter,
+        env.current_contract_address(),
+        &amount,
+    );
+
+    arbiter_info.stake += amount;
+    if arbiter_info.stake >= config.min_stake && !arbiter_info.suspended {
+        arbiter_info.active = true;
+    }
+
+    env.storage().persistent().set(&key, &arbiter_info);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+    events::arbiter_staked(env, arbiter, amount, arbiter_info.stake);
+
+    Ok(())
+}
+
+pub fn unstake(env: &Env, arbiter: Address, amount: i128) -> Result<(), DisputeError> {
+    upgrade::require_migrated(env)?;
+
+    let config = get_staking_config(env).ok_or(DisputeError::StakingNotConfigured)?;
+
+    arbiter.require_auth();
+
+    if amount <= 0 {
+        return Err(DisputeError::InvalidAmount);
+    }
+
+    let key = DataKey::Arbiter(arbiter.clone());
+    let mut arbiter_info: Arbiter = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(DisputeError::ArbiterNotFound)?;
+
+    // Stake backs open panel seats and cannot leave until they are settled
+    if arbiter_info.open_disputes > 0 {
+        return Err(DisputeError::StakeLocked);
+    }
+
+    if amount > arbiter_info.stake {
+        return Err(DisputeError::InsufficientStake);
+    }
+
+    arbiter_info.stake -= amount;
+    if arbiter_info.stake < config.min_stake {
+        arbiter_info.active = false;
+    }
+
+    env.storage().persistent().set(&key, &arbiter_info);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+    token::Client::new(env, &config.token).transfer(
+        &env.current_contract_address(),
+        &arbiter,
+        &amount,
+    );
+
+    events::arbiter_unstaked(env, arbiter, amount, arbiter_info.stake);
+
+    Ok(())
+}
+
+/// Collects `fee` from the raiser, returning the amount taken. Fees are paid
+/// in the staking token, so a fee can't be charged until staking is set up.
+pub(crate) fn collect_filing_fee(
+    env: &Env,
+    raiser: &Address,
+    fee: i128,
+) -> Result<i128, DisputeError> {
+    if fee <= 0 {
+        return Ok(0);
+    }
+
+    let config = get_staking_config(env).ok_or(DisputeError::StakingNotConfigured)?;
+    token::Client::new(env, &config.token).transfer(raiser, env.current_contract_address(), &fee);
+
+    Ok(fee)
+}
+
+/// Releases the panel's seats and settles incentives for a resolved round.
+///
+/// Panel members who voted for `outcome` (every voter for a split) share
+/// `pool` (the filing fee, or a forfeited appeal bond) and any slashed stake
+/// equally. If the voting deadline has passed, active members who never voted
+/// are slashed and deactivated. One already deactivated, say by a miss on an
+/// earlier panel, could not vote and is not slashed again. Anything that cannot
+/// be shared evenly, or the whole pool if no one voted for the outcome, goes to
+/// the admin.
+pub(crate) fn settle_panel(
+    env: &Env,
+    state: &ContractState,
+    dispute: &Dispute,
+    outcome: &DisputeOutcome,
+    mut pool: i128,
+) {
+    let config = get_staking_config(env);
+    let deadline_passed = env.ledger().timestamp() > dispute.voting_deadline;
+
+    let mut winners = Vec::new(env);
+
+    for member in dispute.panel.iter() {
+        let key = DataKey::Arbiter(member.clone());
+        panel::update_seat(env, &member, false);
+        let Some(mut arbiter_info) = env.storage().persistent().get::<_, Arbiter>(&key) else {
+            continue;
+        };
+
+        let vote: Option<Vote> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Vote(dispute.id, member.clone()));
+
+        match (vote, &config) {
+            (Some(vote), _) => {
+                let voted_for_outcome = match outcome {
+                    DisputeOutcome::FavorLandlord => vote.favor_landlord,
+                    DisputeOutcome::FavorTenant => !vote.favor_landlord,
+                    DisputeOutcome::Split => true,
+                    DisputeOutcome::Inconclusive | DisputeOutcome::Settled => false,
+                };
+                arbiter_info.disputes_voted += 1;
+                if voted_for_outcome {
+                    arbiter_info.majority_votes += 1;
+                    winners.push_back(member.clone());
+                }
+            }
+            (None, Some(config)) if deadline_passed && arbiter_info.active => {
+                let slashed = arbiter_info.stake * config.no_show_slash_bps as i128 / 10_000;
+                arbiter_info.stake -= slashed;
+                arbiter_info.active = false;
+                pool += slashed;
+
+                events::arbiter_slashed(env, dispute.id, member.clone(), slashed);
+            }
+            (None, _) => {}
+        }
+
+        env.storage().persistent().set(&key, &arbiter_info);
+        env.storage().persistent().extend_ttl(&key, 500000, 500000);
+    }
+
+    let Some(config) = config else {
+        return;
+    };
+    if pool <= 0 {
+        return;
+    }
+
+    let token_client = token::Client::new(env, &config.token);
+    let contract = env.current_contract_address();
+
+    let mut remainder = pool;
+    if !winners.is_empty() {
+        let share = pool / winners.len() as i128;
+        if share > 0 {
+            for winner in winners.iter() {
+                token_client.transfer(&contract, &winner, &share);
+                remainder -= share;
+                events::arbiter_rewarded(env, dispute.id, winner, share);
+            }
+        }
+    }
+
+    if remainder > 0 {
+        token_client.transfer(&contract, &state.admin, &remainder);
+    }
+}
@@ -1,5 +1,7 @@
 use soroban_sdk::{contracttype, Address, String};
 
+use crate::types::DisputeCategory;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
@@ -7,6 +9,27 @@ pub enum DataKey {
     State,
     Initialized,
     ArbiterCount,
-    Dispute(String),
-    Vote(String, Address),
+    /// Arbiter address by registration order, used to enumerate the pool
+    ArbiterAt(u32),
+    /// Position of an arbiter in the `ArbiterAt` index
+    ArbiterIndex(Address),
+    Dispute(u32),
+    /// Number of disputes ever raised; the next dispute's id
+    DisputeCount,
+    /// Number of disputes raised against an agreement
+    AgreementDisputeCount(String),
+    /// Dispute id by agreement_id and position, in the order raised
+    AgreementDispute(String, u32),
+    Vote(u32, Address),
+    /// Timeline entry by dispute id and position
+    Evidence(u32, u32),
+    EscrowContract,
+    VotingRules,
+    PanelSize,
+    StakingConfig,
+    AppealConfig,
+    CategoryConfig(DisputeCategory),
+    /// Latest settlement proposal by dispute id
+    Settlement(u32),
+    SchemaVersion,
 }
@@ -1,6 +1,16 @@
 use super::*;
-use crate::dispute::RentAgreement;
-use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+use chioma_types::{
+    AgreementStatus, CoTenancy, MaintenancePriority, MaintenanceRequest, MaintenanceStatus,
+    PropertyDetails, RentAgreement, TenantShare,
+};
+use escrow::escrow_impl::{EscrowContract, EscrowContractClient};
+use escrow::EscrowStatus;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient as TokenAdminClient},
+    Address, Env, Map, String,
+};
 
 /// Mock chioma contract that returns a valid RentAgreement for testing.
 #[contract]
@@ -8,13 +18,111 @@ pub struct MockChiomaContract;
 
 #[contractimpl]
 impl MockChiomaContract {
-    /// Returns a mock active RentAgreement for any agreement_id.
+    /// Returns the mock RentAgreement stored for `agreement_id`.
     /// The raiser must be set as either the tenant or landlord for
     /// raise_dispute authorization to pass.
-    pub fn get_agr(env: Env, _agreement_id: String) -> Option<RentAgreement> {
-        // Retrieve the pre-stored mock agreement
-        env.storage().instance().get::<_, RentAgreement>(&0u32)
+    pub fn get_agreement(env: Env, agreement_id: String) -> Option<RentAgreement> {
+        env.storage().instance().get(&agreement_id)
     }
+
+    /// Stores an agreement to be returned by `get_agreement`.
+    pub fn set_agr(env: Env, agreement: RentAgreement) {
+        env.storage()
+            .instance()
+            .set(&agreement.agreement_id.clone(), &agreement);
+    }
+
+    pub fn get_co_tenancy(env: Env, agreement_id: String) -> Option<CoTenancy> {
+        env.storage()
+            .instance()
+            .get(&(symbol_short!("cotenancy"), agreement_id))
+    }
+
+    /// Stores a co-tenancy to be returned by `get_co_tenancy`.
+    pub fn set_co_tenancy(env: Env, agreement_id: String, co_tenancy: CoTenancy) {
+        env.storage()
+            .instance()
+            .set(&(symbol_short!("cotenancy"), agreement_id), &co_tenancy);
+    }
+
+    pub fn get_maintenance_request(env: Env, request_id: u32) -> Option<MaintenanceRequest> {
+        env.storage().instance().get(&request_id)
+    }
+
+    /// Stores a request to be returned by `get_maintenance_request`.
+    pub fn set_maint(env: Env, request: MaintenanceRequest) {
+        env.storage().instance().set(&request.id, &request);
+    }
+
+    pub fn mark_disputed(env: Env, agreement_id: String) {
+        Self::set_status(&env, agreement_id, AgreementStatus::Disputed);
+    }
+
+    pub fn resolve_dispute(env: Env, agreement_id: String, terminate: bool) {
+        let status = if terminate {
+            AgreementStatus::Terminated
+        } else {
+            AgreementStatus::Active
+        };
+        Self::set_status(&env, agreement_id, status);
+    }
+}
+
+impl MockChiomaContract {
+    fn set_status(env: &Env, agreement_id: String, status: AgreementStatus) {
+        let mut agreement: RentAgreement = env.storage().instance().get(&agreement_id).unwrap();
+        agreement.status = status;
+        env.storage().instance().set(&agreement_id, &agreement);
+    }
+}
+
+/// Registers a mock chioma contract holding two Active agreements,
+/// `agreement_001` and `agreement_002`, between a fresh tenant and landlord.
+fn setup_mock_chioma(env: &Env) -> (Address, Address, Address) {
+    let mock_id = env.register(MockChiomaContract, ());
+    let mock = MockChiomaContractClient::new(env, &mock_id);
+
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+
+    for id in ["agreement_001", "agreement_002"] {
+        mock.set_agr(&RentAgreement {
+            agreement_id: String::from_str(env, id),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 0,
+            end_date: 1000000,
+            agent_commission_rate: 0,
+            status: AgreementStatus::Active,
+            total_rent_paid: 0,
+            payment_count: 0,
+            signed_at: Some(0),
+            payment_token: Address::generate(env),
+            next_payment_due: 0,
+            payment_history: Map::new(env),
+        });
+    }
+
+    (mock_id, tenant, landlord)
+}
+
+fn agreement_status(env: &Env, mock_chioma: &Address, agreement_id: &str) -> AgreementStatus {
+    MockChiomaContractClient::new(env, mock_chioma)
+        .get_agreement(&String::from_str(env, agreement_id))
+        .unwrap()
+        .status
+}
+
+/// Moves the ledger past the dispute's evidence deadline so its panel can vote.
+fn close_evidence_window(client: &DisputeResolutionContractClient<'_>, dispute_id: &u32) {
+    let deadline = client.get_dispute(dispute_id).unwrap().evidence_deadline;
+    client
+        .env
+        .ledger()
+        .with_mut(|l| l.timestamp = l.timestamp.max(deadline + 1));
 }
 
 fn create_contract(env: &Env) -> DisputeResolutionContractClient<'_> {
@@ -70,6 +178,212 @@ fn test_double_initialization_fails() {
     client.initialize(&admin, &3, &chioma_contract);
 }
 
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let wasm_hash = soroban_sdk::BytesN::from_array(&env, &[9; 32]);
+
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.try_migrate(&admin),
+        Err(Ok(DisputeError::NotInitialized))
+    );
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_migrate(&outsider),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_upgrade(&outsider, &wasm_hash),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    // A deployment from before schema versioning has no version key
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_migrate_first_version_arbiters() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, _) = setup_mock_chioma(&env);
+    let arbiters = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 100);
+    client.initialize(&admin, &2, &mock_chioma);
+
+    // The first version kept unindexed arbiters
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+        for arbiter in arbiters.iter() {
+            storage.set(
+                &DataKey::Arbiter(arbiter.clone()),
+                &LegacyArbiter {
+                    address: arbiter.clone(),
+                    added_at: 10,
+                    active: true,
+                },
+            );
+        }
+        storage.set(&DataKey::ArbiterCount, &3u32);
+    });
+
+    // Nothing changes arbiters or disputes until the admin migrates
+    assert_eq!(
+        client.try_migrate_arbiter(&arbiters[0]),
+        Err(Ok(DisputeError::NotMigrated))
+    );
+    assert_eq!(
+        client.try_add_arbiter(&admin, &Address::generate(&env)),
+        Err(Ok(DisputeError::NotMigrated))
+    );
+    assert_eq!(
+        client.try_raise_dispute(
+            &tenant,
+            &String::from_str(&env, "agreement_001"),
+            &DisputeCategory::Other,
+            &String::from_str(&env, "QmDetails"),
+            &None,
+        ),
+        Err(Ok(DisputeError::NotMigrated))
+    );
+    assert_eq!(
+        client.try_set_panel_size(&admin, &3),
+        Err(Ok(DisputeError::NotMigrated))
+    );
+    client.pause(&admin, &Operation::Disputes);
+    client.unpause(&admin, &Operation::Disputes);
+
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(client.get_arbiter_count(), 0);
+
+    for arbiter in arbiters.iter() {
+        client.migrate_arbiter(arbiter);
+    }
+    client.migrate_arbiter(&arbiters[0]);
+    assert_eq!(client.get_arbiter_count(), 3);
+    assert_eq!(client.list_arbiters(&0, &10).len(), 3);
+
+    let arbiter = client.get_arbiter(&arbiters[0]).unwrap();
+    assert_eq!(arbiter.added_at, 10);
+    assert!(arbiter.active);
+    assert!(!arbiter.suspended);
+    assert_eq!(arbiter.stake, 0);
+
+    // Migrated arbiters sit on panels like any other
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "agreement_001"),
+        &DisputeCategory::Other,
+        &String::from_str(&env, "QmDetails"),
+        &None,
+    );
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.panel.len(), 2);
+    for member in dispute.panel.iter() {
+        assert!(arbiters.contains(&member));
+        assert_eq!(client.get_arbiter(&member).unwrap().open_disputes, 1);
+    }
+}
+
+#[test]
+fn test_admin_transfer_requires_acceptance() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(DisputeError::NoPendingAdmin))
+    );
+
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    assert_eq!(
+        client.try_accept_admin(&outsider),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_state().unwrap().admin, new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_add_arbiter(&admin, &outsider),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_arbiter_manager_role_manages_pool() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    assert_eq!(
+        client.try_grant_role(&admin, &Role::Verifier, &manager),
+        Err(Ok(DisputeError::InvalidRole))
+    );
+    assert_eq!(
+        client.try_add_arbiter(&manager, &arbiter),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    client.grant_role(&admin, &Role::ArbiterManager, &manager);
+    assert!(client.has_role(&Role::ArbiterManager, &manager));
+
+    client.add_arbiter(&manager, &arbiter);
+    client.deactivate_arbiter(&manager, &arbiter);
+    assert!(!client.get_arbiter(&arbiter).unwrap().active);
+
+    // Managing arbiters does not extend to admin-only calls
+    assert_eq!(
+        client.try_migrate(&manager),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    client.revoke_role(&admin, &Role::ArbiterManager, &manager);
+    assert_eq!(
+        client.try_reactivate_arbiter(&manager, &arbiter),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+}
+
 #[test]
 fn test_add_arbiter_success() {
     let env = Env::default();
@@ -124,43 +438,93 @@ fn test_add_arbiter_fails_when_already_exists() {
     client.add_arbiter(&admin, &arbiter);
 }
 
-// NOTE: Tests for raise_dispute require a mock chioma contract
-// These tests are temporarily disabled until integration test setup is complete
-// See INTEGRATION.md for cross-contract testing approach
+#[test]
+fn test_guardian_pauses_raising_disputes() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let (mock_chioma, tenant, _landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &mock_chioma);
+    client.grant_role(&admin, &Role::Guardian, &guardian);
+
+    client.pause(&guardian, &Operation::Disputes);
+    assert!(client.is_paused(&Operation::Disputes));
+    assert!(!client.is_paused(&Operation::Resolution));
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let result = client.try_raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(DisputeError::OperationPaused)));
+
+    assert_eq!(
+        client.try_unpause(&guardian, &Operation::Disputes),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+    client.unpause(&admin, &Operation::Disputes);
+
+    let result = client.try_raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    assert_eq!(result, Ok(Ok(1)));
+}
 
-/*
 #[test]
 fn test_raise_dispute_success() {
     let env = Env::default();
     let client = create_contract(&env);
 
     let admin = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
 
     env.mock_all_auths();
 
-    client.initialize(&admin, &3, &Address::generate(&env));
+    client.initialize(&admin, &3, &mock_chioma);
 
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    let result = client.try_raise_dispute(&Address::generate(&env), &agreement_id, &details_hash);
-    assert!(result.is_ok());
+    let result = client.try_raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    assert_eq!(result, Ok(Ok(1)));
 
-    let dispute = client.get_dispute(&agreement_id).unwrap();
+    let dispute = client.get_dispute(&1).unwrap();
+    assert_eq!(dispute.id, 1);
     assert_eq!(dispute.agreement_id, agreement_id);
+    assert_eq!(dispute.landlord, landlord);
+    assert_eq!(dispute.tenant, tenant);
+    assert_eq!(dispute.escrow_id, None);
     assert_eq!(dispute.details_hash, details_hash);
     assert!(!dispute.resolved);
     assert_eq!(dispute.votes_favor_landlord, 0);
     assert_eq!(dispute.votes_favor_tenant, 0);
     assert!(dispute.get_outcome().is_none());
-}
-*/
 
-// NOTE: Tests for raise_dispute require a mock chioma contract
-// These tests are temporarily disabled until integration test setup is complete
-// See INTEGRATION.md for cross-contract testing approach
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Disputed
+    );
+}
 
-/*
 #[test]
 #[should_panic(expected = "Error(Contract, #7)")]
 fn test_raise_dispute_fails_when_already_exists() {
@@ -177,12 +541,22 @@ fn test_raise_dispute_fails_when_already_exists() {
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
+    client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
 }
-*/
 
-/*
 #[test]
 #[should_panic(expected = "Error(Contract, #10)")]
 fn test_raise_dispute_fails_with_empty_details_hash() {
@@ -198,11 +572,15 @@ fn test_raise_dispute_fails_with_empty_details_hash() {
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "");
 
-    client.raise_dispute(&Address::generate(&env), &agreement_id, &details_hash);
+    client.raise_dispute(
+        &Address::generate(&env),
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
 }
-*/
 
-/*
 #[test]
 fn test_vote_on_dispute_success() {
     let env = Env::default();
@@ -220,23 +598,36 @@ fn test_vote_on_dispute_success() {
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+
+    // Votes wait for the parties to finish filing evidence
+    let deadline = client.get_dispute(&dispute_id).unwrap().evidence_deadline;
+    env.ledger().with_mut(|l| l.timestamp = deadline);
+    assert_eq!(
+        client.try_vote_on_dispute(&arbiter, &dispute_id, &true),
+        Err(Ok(DisputeError::EvidenceWindowOpen))
+    );
 
-    let result = client.try_vote_on_dispute(&arbiter, &agreement_id, &true);
+    close_evidence_window(&client, &dispute_id);
+    let result = client.try_vote_on_dispute(&arbiter, &dispute_id, &true);
     assert!(result.is_ok());
 
-    let dispute = client.get_dispute(&agreement_id).unwrap();
+    let dispute = client.get_dispute(&dispute_id).unwrap();
     assert_eq!(dispute.votes_favor_landlord, 1);
     assert_eq!(dispute.votes_favor_tenant, 0);
 
-    let vote = client.get_vote(&agreement_id, &arbiter).unwrap();
+    let vote = client.get_vote(&dispute_id, &arbiter).unwrap();
     assert_eq!(vote.arbiter, arbiter);
-    assert_eq!(vote.agreement_id, agreement_id);
+    assert_eq!(vote.dispute_id, dispute_id);
     assert!(vote.favor_landlord);
 }
-*/
 
-/*
 #[test]
 #[should_panic(expected = "Error(Contract, #5)")]
 fn test_vote_fails_when_not_arbiter() {
@@ -254,10 +645,16 @@ fn test_vote_fails_when_not_arbiter() {
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
-    client.vote_on_dispute(&non_arbiter, &agreement_id, &true);
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&non_arbiter, &dispute_id, &true);
 }
-*/
 
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
@@ -273,12 +670,9 @@ fn test_vote_fails_when_dispute_not_found() {
     client.initialize(&admin, &3, &Address::generate(&env));
     client.add_arbiter(&admin, &arbiter);
 
-    let agreement_id = String::from_str(&env, "agreement_001");
-
-    client.vote_on_dispute(&arbiter, &agreement_id, &true);
+    client.vote_on_dispute(&arbiter, &1, &true);
 }
 
-/*
 #[test]
 #[should_panic(expected = "Error(Contract, #9)")]
 fn test_vote_fails_when_already_voted() {
@@ -297,13 +691,18 @@ fn test_vote_fails_when_already_voted() {
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
-    client.vote_on_dispute(&arbiter, &agreement_id, &true);
-    client.vote_on_dispute(&arbiter, &agreement_id, &false);
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter, &dispute_id, &false);
 }
-*/
 
-/*
 #[test]
 fn test_resolve_dispute_favor_landlord() {
     let env = Env::default();
@@ -325,16 +724,23 @@ fn test_resolve_dispute_favor_landlord() {
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
 
-    client.vote_on_dispute(&arbiter1, &agreement_id, &true);
-    client.vote_on_dispute(&arbiter2, &agreement_id, &true);
-    client.vote_on_dispute(&arbiter3, &agreement_id, &false);
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter1, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter2, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter3, &dispute_id, &false);
 
-    let outcome = client.resolve_dispute(&agreement_id);
+    let outcome = client.resolve_dispute(&dispute_id);
     assert_eq!(outcome, DisputeOutcome::FavorLandlord);
 
-    let dispute = client.get_dispute(&agreement_id).unwrap();
+    let dispute = client.get_dispute(&dispute_id).unwrap();
     assert!(dispute.resolved);
     assert!(dispute.resolved_at.is_some());
     assert_eq!(
@@ -343,10 +749,13 @@ fn test_resolve_dispute_favor_landlord() {
     );
     assert_eq!(dispute.votes_favor_landlord, 2);
     assert_eq!(dispute.votes_favor_tenant, 1);
+
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Terminated
+    );
 }
-*/
 
-/*
 #[test]
 fn test_resolve_dispute_favor_tenant() {
     let env = Env::default();
@@ -368,24 +777,34 @@ fn test_resolve_dispute_favor_tenant() {
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
 
-    client.vote_on_dispute(&arbiter1, &agreement_id, &false);
-    client.vote_on_dispute(&arbiter2, &agreement_id, &false);
-    client.vote_on_dispute(&arbiter3, &agreement_id, &true);
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter1, &dispute_id, &false);
+    client.vote_on_dispute(&arbiter2, &dispute_id, &false);
+    client.vote_on_dispute(&arbiter3, &dispute_id, &true);
 
-    let outcome = client.resolve_dispute(&agreement_id);
+    let outcome = client.resolve_dispute(&dispute_id);
     assert_eq!(outcome, DisputeOutcome::FavorTenant);
 
-    let dispute = client.get_dispute(&agreement_id).unwrap();
+    let dispute = client.get_dispute(&dispute_id).unwrap();
     assert!(dispute.resolved);
     assert_eq!(dispute.get_outcome().unwrap(), DisputeOutcome::FavorTenant);
     assert_eq!(dispute.votes_favor_landlord, 1);
     assert_eq!(dispute.votes_favor_tenant, 2);
+
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
 }
-*/
 
-/*
 #[test]
 #[should_panic(expected = "Error(Contract, #11)")]
 fn test_resolve_dispute_fails_with_insufficient_votes() {
@@ -404,14 +823,19 @@ fn test_resolve_dispute_fails_with_insufficient_votes() {
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
-    client.vote_on_dispute(&arbiter1, &agreement_id, &true);
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter1, &dispute_id, &true);
 
-    client.resolve_dispute(&agreement_id);
+    client.resolve_dispute(&dispute_id);
 }
-*/
 
-/*
 #[test]
 #[should_panic(expected = "Error(Contract, #8)")]
 fn test_resolve_dispute_fails_when_already_resolved() {
@@ -434,18 +858,23 @@ fn test_resolve_dispute_fails_when_already_resolved() {
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
 
-    client.vote_on_dispute(&arbiter1, &agreement_id, &true);
-    client.vote_on_dispute(&arbiter2, &agreement_id, &true);
-    client.vote_on_dispute(&arbiter3, &agreement_id, &false);
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter1, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter2, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter3, &dispute_id, &false);
 
-    client.resolve_dispute(&agreement_id);
-    client.resolve_dispute(&agreement_id);
+    client.resolve_dispute(&dispute_id);
+    client.resolve_dispute(&dispute_id);
 }
-*/
 
-/*
 #[test]
 #[should_panic(expected = "Error(Contract, #8)")]
 fn test_vote_fails_after_dispute_resolved() {
@@ -466,23 +895,29 @@ fn test_vote_fails_after_dispute_resolved() {
     client.add_arbiter(&admin, &arbiter2);
     client.add_arbiter(&admin, &arbiter3);
     client.add_arbiter(&admin, &arbiter4);
+    client.set_panel_size(&admin, &4);
 
     let agreement_id = String::from_str(&env, "agreement_001");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id, &details_hash);
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
 
-    client.vote_on_dispute(&arbiter1, &agreement_id, &true);
-    client.vote_on_dispute(&arbiter2, &agreement_id, &true);
-    client.vote_on_dispute(&arbiter3, &agreement_id, &false);
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter1, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter2, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter3, &dispute_id, &false);
 
-    client.resolve_dispute(&agreement_id);
+    client.resolve_dispute(&dispute_id);
 
-    client.vote_on_dispute(&arbiter4, &agreement_id, &false);
+    client.vote_on_dispute(&arbiter4, &dispute_id, &false);
 }
-*/
 
-/*
 #[test]
 fn test_multiple_disputes() {
     let env = Env::default();
@@ -505,47 +940,2745 @@ fn test_multiple_disputes() {
     let agreement_id2 = String::from_str(&env, "agreement_002");
     let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
 
-    client.raise_dispute(&tenant, &agreement_id1, &details_hash);
-    client.raise_dispute(&tenant, &agreement_id2, &details_hash);
+    let dispute_id1 = client.raise_dispute(
+        &tenant,
+        &agreement_id1,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    let dispute_id2 = client.raise_dispute(
+        &tenant,
+        &agreement_id2,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    assert_eq!((dispute_id1, dispute_id2), (1, 2));
 
-    client.vote_on_dispute(&arbiter1, &agreement_id1, &true);
-    client.vote_on_dispute(&arbiter2, &agreement_id1, &true);
-    client.vote_on_dispute(&arbiter3, &agreement_id1, &false);
+    close_evidence_window(&client, &dispute_id1);
+    client.vote_on_dispute(&arbiter1, &dispute_id1, &true);
+    client.vote_on_dispute(&arbiter2, &dispute_id1, &true);
+    client.vote_on_dispute(&arbiter3, &dispute_id1, &false);
 
-    client.vote_on_dispute(&arbiter1, &agreement_id2, &false);
-    client.vote_on_dispute(&arbiter2, &agreement_id2, &false);
-    client.vote_on_dispute(&arbiter3, &agreement_id2, &true);
+    close_evidence_window(&client, &dispute_id2);
+    client.vote_on_dispute(&arbiter1, &dispute_id2, &false);
+    client.vote_on_dispute(&arbiter2, &dispute_id2, &false);
+    client.vote_on_dispute(&arbiter3, &dispute_id2, &true);
 
-    let outcome1 = client.resolve_dispute(&agreement_id1);
+    let outcome1 = client.resolve_dispute(&dispute_id1);
     assert_eq!(outcome1, DisputeOutcome::FavorLandlord);
 
-    let outcome2 = client.resolve_dispute(&agreement_id2);
+    let outcome2 = client.resolve_dispute(&dispute_id2);
     assert_eq!(outcome2, DisputeOutcome::FavorTenant);
 }
-*/
 
 #[test]
-fn test_get_arbiter_count() {
+fn test_set_escrow_contract() {
     let env = Env::default();
     let client = create_contract(&env);
 
     let admin = Address::generate(&env);
+    let escrow_contract = Address::generate(&env);
 
     env.mock_all_auths();
 
     client.initialize(&admin, &3, &Address::generate(&env));
+    assert_eq!(client.get_escrow_contract(), None);
 
-    assert_eq!(client.get_arbiter_count(), 0);
+    client.set_escrow_contract(&admin, &escrow_contract);
+    assert_eq!(client.get_escrow_contract(), Some(escrow_contract));
+}
 
-    let arbiter1 = Address::generate(&env);
-    client.add_arbiter(&admin, &arbiter1);
-    assert_eq!(client.get_arbiter_count(), 1);
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_set_escrow_contract_fails_when_not_admin() {
+    let env = Env::default();
+    let client = create_contract(&env);
 
-    let arbiter2 = Address::generate(&env);
-    client.add_arbiter(&admin, &arbiter2);
-    assert_eq!(client.get_arbiter_count(), 2);
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+    client.set_escrow_contract(&Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_raise_dispute_with_escrow_fails_when_escrow_not_configured() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, _landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &mock_chioma);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let escrow_id = soroban_sdk::BytesN::from_array(&env, &[0u8; 32]);
+
+    client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &Some(escrow_id),
+    );
+}
+
+/// Registers an escrow contract arbitrated by `arbiter` and funds a deposit
+/// from `tenant` to `landlord`.
+fn setup_funded_escrow(
+    env: &Env,
+    arbiter: &Address,
+    tenant: &Address,
+    landlord: &Address,
+    amount: i128,
+) -> (
+    EscrowContractClient<'static>,
+    soroban_sdk::BytesN<32>,
+    Address,
+) {
+    let escrow_id = env.register(EscrowContract, ());
+    let escrow = EscrowContractClient::new(env, &escrow_id);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    TokenAdminClient::new(env, &token).mint(tenant, &amount);
+
+    let id = escrow.create(tenant, landlord, arbiter, &amount, &token);
+    escrow.fund_escrow(&id, tenant);
+
+    (escrow, id, token)
+}
+
+#[test]
+fn test_raise_dispute_rejects_escrow_not_for_agreement() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &mock_chioma);
+    client.add_arbiter(&admin, &Address::generate(&env));
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+
+    // Arbitrated by someone else: the outcome could never be enforced
+    let (escrow, other_arbiter_id, _) = setup_funded_escrow(&env, &admin, &tenant, &landlord, 2000);
+    client.set_escrow_contract(&admin, &escrow.address);
+    let res = client.try_raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &Some(other_arbiter_id),
+    );
+    assert_eq!(res, Err(Ok(DisputeError::InvalidEscrow)));
+
+    // Parties reversed
+    let (escrow, reversed_id, _) =
+        setup_funded_escrow(&env, &client.address, &landlord, &tenant, 2000);
+    client.set_escrow_contract(&admin, &escrow.address);
+    let res = client.try_raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &Some(reversed_id),
+    );
+    assert_eq!(res, Err(Ok(DisputeError::InvalidEscrow)));
+
+    // Unknown escrow id
+    let res = client.try_raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &Some(soroban_sdk::BytesN::from_array(&env, &[9u8; 32])),
+    );
+    assert_eq!(res, Err(Ok(DisputeError::InvalidEscrow)));
+
+    // Nothing was frozen, so a dispute can still be raised
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
+}
+
+#[test]
+fn test_resolution_releases_escrow_to_landlord() {
+    let env = Env::default();
+    let client = create_contract(&env);
 
+    let admin = Address::generate(&env);
+    let arbiter1 = Address::generate(&env);
+    let arbiter2 = Address::generate(&env);
     let arbiter3 = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    let (escrow, escrow_id, token) =
+        setup_funded_escrow(&env, &client.address, &tenant, &landlord, 2000);
+
+    client.initialize(&admin, &3, &mock_chioma);
+    client.set_escrow_contract(&admin, &escrow.address);
+    client.add_arbiter(&admin, &arbiter1);
+    client.add_arbiter(&admin, &arbiter2);
     client.add_arbiter(&admin, &arbiter3);
-    assert_eq!(client.get_arbiter_count(), 3);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+
+    let dispute_id = client.raise_dispute(
+        &landlord,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &Some(escrow_id.clone()),
+    );
+
+    assert_eq!(escrow.get_escrow(&escrow_id).status, EscrowStatus::Disputed);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Disputed
+    );
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter1, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter2, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter3, &dispute_id, &false);
+
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::FavorLandlord
+    );
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&landlord), 2000);
+    assert_eq!(token_client.balance(&escrow.address), 0);
+    assert_eq!(escrow.get_escrow(&escrow_id).status, EscrowStatus::Released);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Terminated
+    );
+}
+
+#[test]
+fn test_resolution_refunds_escrow_to_tenant() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let arbiter1 = Address::generate(&env);
+    let arbiter2 = Address::generate(&env);
+    let arbiter3 = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    let (escrow, escrow_id, token) =
+        setup_funded_escrow(&env, &client.address, &tenant, &landlord, 2000);
+
+    client.initialize(&admin, &3, &mock_chioma);
+    client.set_escrow_contract(&admin, &escrow.address);
+    client.add_arbiter(&admin, &arbiter1);
+    client.add_arbiter(&admin, &arbiter2);
+    client.add_arbiter(&admin, &arbiter3);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &Some(escrow_id.clone()),
+    );
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter1, &dispute_id, &false);
+    client.vote_on_dispute(&arbiter2, &dispute_id, &false);
+    client.vote_on_dispute(&arbiter3, &dispute_id, &true);
+
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::FavorTenant
+    );
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&tenant), 2000);
+    assert_eq!(token_client.balance(&escrow.address), 0);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
+}
+
+/// Registers the real chioma contract with `dispute_contract` as its dispute
+/// contract and creates a signed (Active) agreement `agreement_001`.
+/// Property registry stand-in that reports listed properties as verified.
+#[contract]
+pub struct MockPropertyRegistry;
+
+#[contractimpl]
+impl MockPropertyRegistry {
+    pub fn get_property(env: Env, property_id: String) -> Option<PropertyDetails> {
+        env.storage().instance().get(&property_id)
+    }
+
+    /// Lists `property_id` as a verified property of `landlord`.
+    pub fn list(env: Env, property_id: String, landlord: Address) {
+        let property = PropertyDetails {
+            property_id: property_id.clone(),
+            landlord,
+            metadata_hash: String::from_str(&env, "QmProperty"),
+            verified: true,
+            registered_at: 0,
+            verified_at: Some(0),
+        };
+        env.storage().instance().set(&property_id, &property);
+    }
+}
+
+fn setup_chioma(
+    env: &Env,
+    dispute_contract: &Address,
+) -> (chioma::ContractClient<'static>, Address, Address) {
+    let chioma_id = env.register(chioma::Contract, ());
+    let chioma = chioma::ContractClient::new(env, &chioma_id);
+
+    let admin = Address::generate(env);
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+
+    chioma.initialize(
+        &admin,
+        &chioma::Config {
+            fee_bps: 100,
+            fee_collector: Address::generate(env),
+        },
+    );
+    chioma.set_dispute_contract(dispute_contract);
+
+    let registry_id = env.register(MockPropertyRegistry, ());
+    chioma.set_property_registry(&registry_id);
+    let property_id = String::from_str(env, "PROPERTY_001");
+    MockPropertyRegistryClient::new(env, &registry_id).list(&property_id, &landlord);
+
+    let agreement_id = String::from_str(env, "agreement_001");
+    chioma.create_agreement(
+        &landlord,
+        &chioma::AgreementTerms {
+            agreement_id: agreement_id.clone(),
+            property_id,
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 1000000,
+            agent_commission_rate: 0,
+            payment_token: Address::generate(env),
+            document_hash: BytesN::from_array(env, &[1; 32]),
+            document_cid: None,
+        },
+    );
+    chioma.submit_agreement(&landlord, &agreement_id);
+    let terms_hash = chioma.get_terms_hash(&agreement_id);
+    chioma.sign_agreement(&landlord, &agreement_id, &terms_hash);
+    chioma.sign_agreement(&tenant, &agreement_id, &terms_hash);
+
+    (chioma, tenant, landlord)
+}
+
+#[test]
+fn test_dispute_lifecycle_against_chioma_contract() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let arbiter1 = Address::generate(&env);
+    let arbiter2 = Address::generate(&env);
+    let arbiter3 = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (chioma, tenant, landlord) = setup_chioma(&env, &client.address);
+
+    client.initialize(&admin, &3, &chioma.address);
+    client.add_arbiter(&admin, &arbiter1);
+    client.add_arbiter(&admin, &arbiter2);
+    client.add_arbiter(&admin, &arbiter3);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.landlord, landlord);
+    assert_eq!(dispute.tenant, tenant);
+    assert_eq!(
+        chioma.get_agreement(&agreement_id).unwrap().status,
+        chioma::AgreementStatus::Disputed
+    );
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter1, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter2, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter3, &dispute_id, &false);
+
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::FavorLandlord
+    );
+    assert_eq!(
+        chioma.get_agreement(&agreement_id).unwrap().status,
+        chioma::AgreementStatus::Terminated
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_raise_dispute_fails_for_unknown_chioma_agreement() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (chioma, tenant, _landlord) = setup_chioma(&env, &client.address);
+
+    client.initialize(&admin, &3, &chioma.address);
+
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "missing"),
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+}
+
+#[test]
+fn test_get_arbiter_count() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    assert_eq!(client.get_arbiter_count(), 0);
+
+    let arbiter1 = Address::generate(&env);
+    client.add_arbiter(&admin, &arbiter1);
+    assert_eq!(client.get_arbiter_count(), 1);
+
+    let arbiter2 = Address::generate(&env);
+    client.add_arbiter(&admin, &arbiter2);
+    assert_eq!(client.get_arbiter_count(), 2);
+
+    let arbiter3 = Address::generate(&env);
+    client.add_arbiter(&admin, &arbiter3);
+    assert_eq!(client.get_arbiter_count(), 3);
+}
+
+/// Initializes the contract against the mock chioma with three arbiters and
+/// raises a dispute on `agreement_001`.
+fn setup_open_dispute(
+    env: &Env,
+    client: &DisputeResolutionContractClient<'_>,
+    rules: Option<VotingRules>,
+) -> (Address, [Address; 3], Address, Address, Address, u32) {
+    let admin = Address::generate(env);
+    let arbiters = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(env);
+
+    client.initialize(&admin, &3, &mock_chioma);
+    for arbiter in arbiters.iter() {
+        client.add_arbiter(&admin, arbiter);
+    }
+    if let Some(rules) = rules {
+        client.set_voting_rules(&admin, &rules);
+    }
+
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &String::from_str(env, "agreement_001"),
+        &DisputeCategory::Other,
+        &String::from_str(env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco"),
+        &None,
+    );
+
+    (admin, arbiters, mock_chioma, tenant, landlord, dispute_id)
+}
+
+#[test]
+fn test_set_voting_rules() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+    assert_eq!(client.get_voting_rules(), VotingRules::default());
+
+    let rules = VotingRules {
+        voting_period: 86_400,
+        evidence_period: 43_200,
+        supermajority_bps: 6666,
+        tie_rule: TieRule::EscalateToAdmin,
+        max_extensions: 0,
+    };
+    client.set_voting_rules(&admin, &rules);
+
+    assert_eq!(client.get_voting_rules(), rules);
+}
+
+#[test]
+fn test_set_voting_rules_rejects_invalid_threshold() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    let mut rules = VotingRules {
+        supermajority_bps: 4999,
+        ..VotingRules::default()
+    };
+    assert_eq!(
+        client.try_set_voting_rules(&admin, &rules),
+        Err(Ok(DisputeError::InvalidVotingRules))
+    );
+
+    rules.supermajority_bps = 10_000;
+    assert_eq!(
+        client.try_set_voting_rules(&admin, &rules),
+        Err(Ok(DisputeError::InvalidVotingRules))
+    );
+
+    rules.supermajority_bps = 5000;
+    rules.voting_period = 0;
+    assert_eq!(
+        client.try_set_voting_rules(&admin, &rules),
+        Err(Ok(DisputeError::InvalidVotingRules))
+    );
+
+    rules.voting_period = 86_400;
+    rules.evidence_period = 86_401;
+    assert_eq!(
+        client.try_set_voting_rules(&admin, &rules),
+        Err(Ok(DisputeError::InvalidVotingRules))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_vote_fails_after_voting_deadline() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, arbiters, _, _, _, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(
+        dispute.voting_deadline,
+        dispute.raised_at + VotingRules::default().voting_period
+    );
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = dispute.voting_deadline + 1);
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiters[0], &dispute_id, &true);
+}
+
+#[test]
+fn test_decisive_majority_resolves_before_deadline() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, arbiters, _, _, _, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    close_evidence_window(&client, &dispute_id);
+    for arbiter in arbiters.iter() {
+        client.vote_on_dispute(arbiter, &dispute_id, &false);
+    }
+
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert!(env.ledger().timestamp() < dispute.voting_deadline);
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::FavorTenant
+    );
+}
+
+#[test]
+fn test_supermajority_not_reached_keeps_voting_open() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let rules = VotingRules {
+        supermajority_bps: 7500,
+        ..VotingRules::default()
+    };
+    let (_, arbiters, _, _, _, dispute_id) = setup_open_dispute(&env, &client, Some(rules));
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiters[0], &dispute_id, &true);
+    client.vote_on_dispute(&arbiters[1], &dispute_id, &true);
+    client.vote_on_dispute(&arbiters[2], &dispute_id, &false);
+
+    // 2 of 3 is not more than 75%
+    assert_eq!(
+        client.try_resolve_dispute(&dispute_id),
+        Err(Ok(DisputeError::VotingInProgress))
+    );
+}
+
+#[test]
+fn test_inconclusive_vote_extends_window_then_escalates() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, arbiters, mock_chioma, _, _, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiters[0], &dispute_id, &true);
+    client.vote_on_dispute(&arbiters[1], &dispute_id, &false);
+
+    let first_deadline = client.get_dispute(&dispute_id).unwrap().voting_deadline;
+    env.ledger().with_mut(|l| l.timestamp = first_deadline + 1);
+
+    // No quorum by the deadline: voting is reopened once
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::Inconclusive
+    );
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.extensions, 1);
+    assert_eq!(
+        dispute.voting_deadline,
+        first_deadline + 1 + VotingRules::default().voting_period
+    );
+    assert!(!dispute.resolved);
+    assert!(!dispute.escalated);
+
+    // Still inconclusive after the extension: escalated to the admin
+    env.ledger()
+        .with_mut(|l| l.timestamp = dispute.voting_deadline + 1);
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::Inconclusive
+    );
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert!(dispute.escalated);
+    assert!(!dispute.resolved);
+
+    assert_eq!(
+        client.try_resolve_dispute(&dispute_id),
+        Err(Ok(DisputeError::DisputeEscalated))
+    );
+
+    assert_eq!(
+        client.resolve_escalated_dispute(&admin, &dispute_id, &DisputeOutcome::FavorTenant),
+        DisputeOutcome::FavorTenant
+    );
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert!(dispute.resolved);
+    assert_eq!(dispute.get_outcome(), Some(DisputeOutcome::FavorTenant));
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
+}
+
+#[test]
+fn test_resolve_escalated_dispute_rejects_invalid_requests() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let rules = VotingRules {
+        tie_rule: TieRule::EscalateToAdmin,
+        ..VotingRules::default()
+    };
+    let (admin, _, _, _, _, dispute_id) = setup_open_dispute(&env, &client, Some(rules));
+
+    assert_eq!(
+        client.try_resolve_escalated_dispute(&admin, &dispute_id, &DisputeOutcome::FavorLandlord),
+        Err(Ok(DisputeError::DisputeNotEscalated))
+    );
+
+    let deadline = client.get_dispute(&dispute_id).unwrap().voting_deadline;
+    env.ledger().with_mut(|l| l.timestamp = deadline + 1);
+    client.resolve_dispute(&dispute_id);
+    assert!(client.get_dispute(&dispute_id).unwrap().escalated);
+
+    assert_eq!(
+        client.try_resolve_escalated_dispute(
+            &Address::generate(&env),
+            &dispute_id,
+            &DisputeOutcome::FavorLandlord
+        ),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_resolve_escalated_dispute(&admin, &dispute_id, &DisputeOutcome::Inconclusive),
+        Err(Ok(DisputeError::InvalidOutcome))
+    );
+}
+
+#[test]
+fn test_split_tie_rule_splits_escrow() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let arbiter1 = Address::generate(&env);
+    let arbiter2 = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    let (escrow, escrow_id, token) =
+        setup_funded_escrow(&env, &client.address, &tenant, &landlord, 2000);
+
+    client.initialize(&admin, &2, &mock_chioma);
+    client.set_escrow_contract(&admin, &escrow.address);
+    client.set_voting_rules(
+        &admin,
+        &VotingRules {
+            tie_rule: TieRule::Split,
+            ..VotingRules::default()
+        },
+    );
+    client.add_arbiter(&admin, &arbiter1);
+    client.add_arbiter(&admin, &arbiter2);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &Some(escrow_id.clone()),
+    );
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiter1, &dispute_id, &true);
+    client.vote_on_dispute(&arbiter2, &dispute_id, &false);
+
+    assert_eq!(
+        client.try_resolve_dispute(&dispute_id),
+        Err(Ok(DisputeError::VotingInProgress))
+    );
+
+    let deadline = client.get_dispute(&dispute_id).unwrap().voting_deadline;
+    env.ledger().with_mut(|l| l.timestamp = deadline + 1);
+
+    assert_eq!(client.resolve_dispute(&dispute_id), DisputeOutcome::Split);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&landlord), 1000);
+    assert_eq!(token_client.balance(&tenant), 1000);
+    assert_eq!(escrow.get_escrow(&escrow_id).status, EscrowStatus::Released);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
+}
+
+#[test]
+fn test_panel_excludes_parties_guarantor_and_agent() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+    let agent = Address::generate(&env);
+
+    let mock = MockChiomaContractClient::new(&env, &mock_chioma);
+    let mut agreement = mock
+        .get_agreement(&String::from_str(&env, "agreement_001"))
+        .unwrap();
+    agreement.agent = Some(agent.clone());
+    mock.set_agr(&agreement);
+
+    let co_tenant = Address::generate(&env);
+    let guarantor = Address::generate(&env);
+    mock.set_co_tenancy(
+        &agreement.agreement_id,
+        &CoTenancy {
+            tenants: soroban_sdk::vec![
+                &env,
+                TenantShare {
+                    tenant: tenant.clone(),
+                    share_bps: 5000,
+                },
+                TenantShare {
+                    tenant: co_tenant.clone(),
+                    share_bps: 5000,
+                },
+            ],
+            guarantor: Some(guarantor.clone()),
+        },
+    );
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &2, &mock_chioma);
+    client.set_panel_size(&admin, &3);
+    for conflicted in [&landlord, &tenant, &co_tenant, &guarantor, &agent] {
+        client.add_arbiter(&admin, conflicted);
+    }
+    let arbiter1 = Address::generate(&env);
+    let arbiter2 = Address::generate(&env);
+    client.add_arbiter(&admin, &arbiter1);
+    client.add_arbiter(&admin, &arbiter2);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+
+    // Only the two unconflicted arbiters are eligible
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.panel.len(), 2);
+    assert!(dispute.panel.contains(&arbiter1));
+    assert!(dispute.panel.contains(&arbiter2));
+
+    close_evidence_window(&client, &dispute_id);
+    for conflicted in [&landlord, &tenant, &co_tenant, &guarantor, &agent] {
+        assert_eq!(
+            client.try_vote_on_dispute(conflicted, &dispute_id, &true),
+            Err(Ok(DisputeError::NotPanelMember))
+        );
+    }
+}
+
+#[test]
+fn test_panel_is_drawn_from_pool() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, _landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &mock_chioma);
+    assert_eq!(client.get_panel_size(), 3);
+
+    let mut pool = soroban_sdk::Vec::new(&env);
+    for _ in 0..6 {
+        let arbiter = Address::generate(&env);
+        client.add_arbiter(&admin, &arbiter);
+        pool.push_back(arbiter);
+    }
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+
+    let panel = client.get_dispute(&dispute_id).unwrap().panel;
+    assert_eq!(panel.len(), 3);
+    for (i, member) in panel.iter().enumerate() {
+        assert!(pool.contains(&member));
+        assert_eq!(panel.first_index_of(&member), Some(i as u32));
+    }
+
+    let outsider = pool.iter().find(|a| !panel.contains(a)).unwrap();
+    close_evidence_window(&client, &dispute_id);
+    assert_eq!(
+        client.try_vote_on_dispute(&outsider, &dispute_id, &true),
+        Err(Ok(DisputeError::NotPanelMember))
+    );
+
+    for member in panel.iter() {
+        client.vote_on_dispute(&member, &dispute_id, &true);
+    }
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::FavorLandlord
+    );
+}
+
+#[test]
+fn test_panel_draw_reads_a_bounded_sample_of_a_large_pool() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, _landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &mock_chioma);
+    let mut pool = soroban_sdk::Vec::new(&env);
+    for _ in 0..MAX_DRAW_PROBES * 4 {
+        let arbiter = Address::generate(&env);
+        client.add_arbiter(&admin, &arbiter);
+        pool.push_back(arbiter);
+    }
+
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "agreement_001"),
+        &DisputeCategory::Other,
+        &String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco"),
+        &None,
+    );
+    let reads = env.cost_estimate().resources().memory_read_entries;
+
+    // Two reads per probed slot plus the dispute's own bookkeeping
+    assert!(reads < 2 * MAX_DRAW_PROBES + 20);
+
+    let panel = client.get_dispute(&dispute_id).unwrap().panel;
+    assert_eq!(panel.len(), 3);
+    for (i, member) in panel.iter().enumerate() {
+        assert!(pool.contains(&member));
+        assert_eq!(panel.first_index_of(&member), Some(i as u32));
+    }
+}
+
+#[test]
+fn test_set_panel_size_rejects_size_below_quorum() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    assert_eq!(
+        client.try_set_panel_size(&admin, &0),
+        Err(Ok(DisputeError::InvalidPanelSize))
+    );
+    assert_eq!(
+        client.try_set_panel_size(&admin, &2),
+        Err(Ok(DisputeError::InvalidPanelSize))
+    );
+    assert_eq!(
+        client.try_set_panel_size(&Address::generate(&env), &5),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_recuse_draws_replacement() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, _, _, _, _, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    // Raised with exactly three arbiters, so the new one is off the panel
+    let reserve = Address::generate(&env);
+    client.add_arbiter(&admin, &reserve);
+
+    let panel = client.get_dispute(&dispute_id).unwrap().panel;
+    let recusing = panel.get(0).unwrap();
+    client.recuse(&recusing, &dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.panel.len(), 3);
+    assert!(!dispute.panel.contains(&recusing));
+    assert!(dispute.panel.contains(&reserve));
+    assert_eq!(dispute.recused.len(), 1);
+    assert_eq!(dispute.recused.get(0), Some(recusing.clone()));
+
+    close_evidence_window(&client, &dispute_id);
+    assert_eq!(
+        client.try_vote_on_dispute(&recusing, &dispute_id, &true),
+        Err(Ok(DisputeError::NotPanelMember))
+    );
+    assert_eq!(
+        client.try_recuse(&recusing, &dispute_id),
+        Err(Ok(DisputeError::NotPanelMember))
+    );
+
+    // No one left to draw: the panel shrinks
+    let member = dispute.panel.get(0).unwrap();
+    client.recuse(&member, &dispute_id);
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.panel.len(), 2);
+}
+
+#[test]
+fn test_recuse_fails_after_voting() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, arbiters, _, _, _, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiters[0], &dispute_id, &true);
+
+    assert_eq!(
+        client.try_recuse(&arbiters[0], &dispute_id),
+        Err(Ok(DisputeError::AlreadyVoted))
+    );
+}
+
+/// Configures staking with a fresh token and registers three arbiters who
+/// each stake `min_stake`. The tenant is funded to pay the filing fee.
+fn setup_staking(
+    env: &Env,
+    client: &DisputeResolutionContractClient<'_>,
+    min_votes_required: u32,
+) -> (Address, Address, [Address; 3], Address, Address) {
+    let admin = Address::generate(env);
+    let (mock_chioma, tenant, _landlord) = setup_mock_chioma(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_admin = TokenAdminClient::new(env, &token);
+
+    client.initialize(&admin, &min_votes_required, &mock_chioma);
+    client.set_panel_size(&admin, &3);
+    client.set_staking_config(
+        &admin,
+        &StakingConfig {
+            token: token.clone(),
+            min_stake: 1000,
+            filing_fee: 300,
+            no_show_slash_bps: 5000,
+        },
+    );
+
+    let arbiters = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for arbiter in arbiters.iter() {
+        client.add_arbiter(&admin, arbiter);
+        token_admin.mint(arbiter, &1000);
+        client.stake(arbiter, &1000);
+    }
+    token_admin.mint(&tenant, &300);
+
+    (admin, token, arbiters, mock_chioma, tenant)
+}
+
+#[test]
+fn test_stake_activates_arbiter() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+    assert_eq!(
+        client.try_stake(&arbiter, &100),
+        Err(Ok(DisputeError::StakingNotConfigured))
+    );
+
+    client.set_staking_config(
+        &admin,
+        &StakingConfig {
+            token: token.clone(),
+            min_stake: 1000,
+            filing_fee: 0,
+            no_show_slash_bps: 0,
+        },
+    );
+    client.add_arbiter(&admin, &arbiter);
+    assert!(!client.get_arbiter(&arbiter).unwrap().active);
+
+    TokenAdminClient::new(&env, &token).mint(&arbiter, &1000);
+    assert_eq!(
+        client.try_stake(&arbiter, &0),
+        Err(Ok(DisputeError::InvalidAmount))
+    );
+
+    client.stake(&arbiter, &500);
+    assert!(!client.get_arbiter(&arbiter).unwrap().active);
+
+    client.stake(&arbiter, &500);
+    let arbiter_info = client.get_arbiter(&arbiter).unwrap();
+    assert!(arbiter_info.active);
+    assert_eq!(arbiter_info.stake, 1000);
+    assert_eq!(
+        TokenClient::new(&env, &token).balance(&client.address),
+        1000
+    );
+
+    client.unstake(&arbiter, &1);
+    assert!(!client.get_arbiter(&arbiter).unwrap().active);
+    assert_eq!(
+        client.try_unstake(&arbiter, &1000),
+        Err(Ok(DisputeError::InsufficientStake))
+    );
+}
+
+#[test]
+fn test_set_staking_config_rejects_invalid_config() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    let config = StakingConfig {
+        token: Address::generate(&env),
+        min_stake: 1000,
+        filing_fee: 100,
+        no_show_slash_bps: 10_001,
+    };
+    assert_eq!(
+        client.try_set_staking_config(&admin, &config),
+        Err(Ok(DisputeError::InvalidStakingConfig))
+    );
+
+    let config = StakingConfig {
+        no_show_slash_bps: 1000,
+        filing_fee: -1,
+        ..config
+    };
+    assert_eq!(
+        client.try_set_staking_config(&admin, &config),
+        Err(Ok(DisputeError::InvalidStakingConfig))
+    );
+
+    let config = StakingConfig {
+        filing_fee: 100,
+        ..config
+    };
+    client.set_staking_config(&admin, &config);
+    assert_eq!(client.get_staking_config(), Some(config.clone()));
+
+    let config = StakingConfig {
+        token: Address::generate(&env),
+        ..config
+    };
+    assert_eq!(
+        client.try_set_staking_config(&admin, &config),
+        Err(Ok(DisputeError::InvalidStakingConfig))
+    );
+}
+
+#[test]
+fn test_filing_fee_rewards_majority_voters() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, token, arbiters, _, tenant) = setup_staking(&env, &client, 3);
+    let token_client = TokenClient::new(&env, &token);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+
+    assert_eq!(token_client.balance(&tenant), 0);
+    assert_eq!(client.get_dispute(&dispute_id).unwrap().fee, 300);
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiters[0], &dispute_id, &true);
+    client.vote_on_dispute(&arbiters[1], &dispute_id, &true);
+    client.vote_on_dispute(&arbiters[2], &dispute_id, &false);
+
+    // Stake is locked while the dispute is open
+    assert_eq!(
+        client.try_unstake(&arbiters[0], &1000),
+        Err(Ok(DisputeError::StakeLocked))
+    );
+
+    client.resolve_dispute(&dispute_id);
+
+    assert_eq!(token_client.balance(&arbiters[0]), 150);
+    assert_eq!(token_client.balance(&arbiters[1]), 150);
+    assert_eq!(token_client.balance(&arbiters[2]), 0);
+
+    client.unstake(&arbiters[0], &1000);
+    assert_eq!(token_client.balance(&arbiters[0]), 1150);
+    assert_eq!(client.get_arbiter(&arbiters[0]).unwrap().open_disputes, 0);
+}
+
+#[test]
+fn test_no_show_is_slashed_after_deadline() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, token, arbiters, _, tenant) = setup_staking(&env, &client, 2);
+    client.set_voting_rules(
+        &admin,
+        &VotingRules {
+            tie_rule: TieRule::Split,
+            ..VotingRules::default()
+        },
+    );
+    let token_client = TokenClient::new(&env, &token);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiters[0], &dispute_id, &true);
+    client.vote_on_dispute(&arbiters[1], &dispute_id, &false);
+
+    let deadline = client.get_dispute(&dispute_id).unwrap().voting_deadline;
+    env.ledger().with_mut(|l| l.timestamp = deadline + 1);
+
+    assert_eq!(client.resolve_dispute(&dispute_id), DisputeOutcome::Split);
+
+    // Half of the no-show's stake joins the fee: (300 + 500) / 2 per voter
+    let no_show = client.get_arbiter(&arbiters[2]).unwrap();
+    assert_eq!(no_show.stake, 500);
+    assert!(!no_show.active);
+    assert_eq!(token_client.balance(&arbiters[0]), 400);
+    assert_eq!(token_client.balance(&arbiters[1]), 400);
+    assert_eq!(token_client.balance(&client.address), 2500);
+}
+
+#[test]
+fn test_no_show_is_slashed_once_across_panels() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, token, arbiters, _, tenant) = setup_staking(&env, &client, 2);
+    client.set_voting_rules(
+        &admin,
+        &VotingRules {
+            tie_rule: TieRule::Split,
+            ..VotingRules::default()
+        },
+    );
+    TokenAdminClient::new(&env, &token).mint(&tenant, &300);
+
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let mut dispute_ids = [0u32; 2];
+    for (i, agreement) in ["agreement_001", "agreement_002"].iter().enumerate() {
+        dispute_ids[i] = client.raise_dispute(
+            &tenant,
+            &String::from_str(&env, agreement),
+            &DisputeCategory::Other,
+            &details_hash,
+            &None,
+        );
+    }
+    assert_eq!(client.get_arbiter(&arbiters[2]).unwrap().open_disputes, 2);
+
+    for dispute_id in dispute_ids.iter() {
+        close_evidence_window(&client, dispute_id);
+        client.vote_on_dispute(&arbiters[0], dispute_id, &true);
+        client.vote_on_dispute(&arbiters[1], dispute_id, &false);
+    }
+
+    let deadline = client.get_dispute(&dispute_ids[1]).unwrap().voting_deadline;
+    env.ledger().with_mut(|l| l.timestamp = deadline + 1);
+
+    client.resolve_dispute(&dispute_ids[0]);
+    assert_eq!(client.get_arbiter(&arbiters[2]).unwrap().stake, 500);
+
+    // Deactivated by the first miss, the arbiter could not vote on the second
+    client.resolve_dispute(&dispute_ids[1]);
+    let no_show = client.get_arbiter(&arbiters[2]).unwrap();
+    assert_eq!(no_show.stake, 500);
+    assert!(!no_show.active);
+    assert_eq!(no_show.open_disputes, 0);
+}
+
+#[test]
+fn test_filing_fee_requires_staking() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, _landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &1, &mock_chioma);
+    client.add_arbiter(&admin, &Address::generate(&env));
+
+    // No fee is charged for a category that sets none
+    let config = CategoryConfig {
+        required_votes: 1,
+        voting_period: 259_200,
+        filing_fee: 0,
+        allowed_outcomes: soroban_sdk::vec![&env, DisputeOutcome::FavorTenant],
+    };
+    client.set_category_config(&admin, &DisputeCategory::Maintenance, &config);
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "agreement_001"),
+        &DisputeCategory::Maintenance,
+        &details_hash,
+        &None,
+    );
+    assert_eq!(client.get_dispute(&dispute_id).unwrap().fee, 0);
+
+    // A stored fee is never waived for want of a token to charge it in
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(
+            &DataKey::CategoryConfig(DisputeCategory::UnpaidRent),
+            &CategoryConfig {
+                filing_fee: 100,
+                ..config.clone()
+            },
+        );
+    });
+    assert_eq!(
+        client.try_raise_dispute(
+            &tenant,
+            &String::from_str(&env, "agreement_002"),
+            &DisputeCategory::UnpaidRent,
+            &details_hash,
+            &None,
+        ),
+        Err(Ok(DisputeError::StakingNotConfigured))
+    );
+}
+
+#[test]
+fn test_deactivate_and_reactivate_arbiter() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, arbiters, _, _, _, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    client.deactivate_arbiter(&admin, &arbiters[0]);
+    assert!(!client.get_arbiter(&arbiters[0]).unwrap().active);
+    close_evidence_window(&client, &dispute_id);
+    assert_eq!(
+        client.try_vote_on_dispute(&arbiters[0], &dispute_id, &true),
+        Err(Ok(DisputeError::ArbiterNotFound))
+    );
+
+    client.reactivate_arbiter(&admin, &arbiters[0]);
+    assert!(client.get_arbiter(&arbiters[0]).unwrap().active);
+    client.vote_on_dispute(&arbiters[0], &dispute_id, &true);
+
+    assert_eq!(
+        client.try_deactivate_arbiter(&Address::generate(&env), &arbiters[1]),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_reactivate_arbiter(&admin, &Address::generate(&env)),
+        Err(Ok(DisputeError::ArbiterNotFound))
+    );
+}
+
+#[test]
+fn test_reactivate_requires_minimum_stake() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, _, arbiters, _, _) = setup_staking(&env, &client, 3);
+
+    client.deactivate_arbiter(&admin, &arbiters[0]);
+    client.unstake(&arbiters[0], &1);
+
+    assert_eq!(
+        client.try_reactivate_arbiter(&admin, &arbiters[0]),
+        Err(Ok(DisputeError::InsufficientStake))
+    );
+}
+
+#[test]
+fn test_stake_does_not_lift_suspension() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, token, arbiters, _, _) = setup_staking(&env, &client, 3);
+
+    client.deactivate_arbiter(&admin, &arbiters[0]);
+    assert!(client.get_arbiter(&arbiters[0]).unwrap().suspended);
+
+    TokenAdminClient::new(&env, &token).mint(&arbiters[0], &500);
+    client.stake(&arbiters[0], &500);
+
+    let arbiter_info = client.get_arbiter(&arbiters[0]).unwrap();
+    assert_eq!(arbiter_info.stake, 1500);
+    assert!(!arbiter_info.active);
+    assert!(arbiter_info.suspended);
+
+    client.reactivate_arbiter(&admin, &arbiters[0]);
+    let arbiter_info = client.get_arbiter(&arbiters[0]).unwrap();
+    assert!(arbiter_info.active);
+    assert!(!arbiter_info.suspended);
+}
+
+#[test]
+fn test_remove_arbiter() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, token, arbiters, _, tenant) = setup_staking(&env, &client, 3);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+
+    // Seated on an open dispute: deactivate now, remove once it resolves
+    assert_eq!(
+        client.try_remove_arbiter(&admin, &arbiters[0]),
+        Err(Ok(DisputeError::StakeLocked))
+    );
+
+    close_evidence_window(&client, &dispute_id);
+    for arbiter in arbiters.iter() {
+        client.vote_on_dispute(arbiter, &dispute_id, &false);
+    }
+    client.resolve_dispute(&dispute_id);
+
+    client.remove_arbiter(&admin, &arbiters[0]);
+
+    assert_eq!(client.get_arbiter(&arbiters[0]), None);
+    assert_eq!(client.get_arbiter_count(), 2);
+    assert_eq!(TokenClient::new(&env, &token).balance(&arbiters[0]), 1100);
+
+    let listed = client.list_arbiters(&0, &10);
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed.get(0).unwrap().address, arbiters[2]);
+    assert_eq!(listed.get(1).unwrap().address, arbiters[1]);
+
+    // The address can be registered again
+    client.add_arbiter(&admin, &arbiters[0]);
+    assert_eq!(client.get_arbiter_count(), 3);
+    assert_eq!(
+        client.list_arbiters(&2, &10).get(0).unwrap().address,
+        arbiters[0]
+    );
+}
+
+#[test]
+fn test_list_arbiters_paginates() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    let mut added = soroban_sdk::Vec::new(&env);
+    for _ in 0..5 {
+        let arbiter = Address::generate(&env);
+        client.add_arbiter(&admin, &arbiter);
+        added.push_back(arbiter);
+    }
+
+    let first = client.list_arbiters(&0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap().address, added.get(0).unwrap());
+    assert_eq!(first.get(1).unwrap().address, added.get(1).unwrap());
+
+    let last = client.list_arbiters(&4, &2);
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0).unwrap().address, added.get(4).unwrap());
+
+    assert_eq!(client.list_arbiters(&5, &2).len(), 0);
+    assert_eq!(client.list_arbiters(&0, &1000).len(), 5);
+}
+
+#[test]
+fn test_arbiter_stats_track_majority_agreement() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, arbiters, _, tenant, _, dispute_1) = setup_open_dispute(&env, &client, None);
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+
+    close_evidence_window(&client, &dispute_1);
+    client.vote_on_dispute(&arbiters[0], &dispute_1, &true);
+    client.vote_on_dispute(&arbiters[1], &dispute_1, &true);
+    client.vote_on_dispute(&arbiters[2], &dispute_1, &false);
+    client.resolve_dispute(&dispute_1);
+
+    let dispute_2 = client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "agreement_002"),
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    close_evidence_window(&client, &dispute_2);
+    client.vote_on_dispute(&arbiters[0], &dispute_2, &false);
+    client.vote_on_dispute(&arbiters[1], &dispute_2, &true);
+    client.vote_on_dispute(&arbiters[2], &dispute_2, &true);
+    client.resolve_dispute(&dispute_2);
+
+    let stats = client.get_arbiter_stats(&arbiters[0]).unwrap();
+    assert_eq!(stats.disputes_voted, 2);
+    assert_eq!(stats.majority_votes, 1);
+    assert_eq!(stats.majority_rate_bps, 5000);
+
+    let stats = client.get_arbiter_stats(&arbiters[1]).unwrap();
+    assert_eq!(stats.majority_rate_bps, 10_000);
+
+    assert_eq!(client.get_arbiter_stats(&Address::generate(&env)), None);
+}
+
+#[test]
+fn test_dispute_timeline_records_both_sides() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, _, _, tenant, landlord, dispute_id) = setup_open_dispute(&env, &client, None);
+    let pdf = String::from_str(&env, "application/pdf");
+
+    let index = client.submit_evidence(
+        &tenant,
+        &dispute_id,
+        &String::from_str(&env, "QmTenantPhotos"),
+        &String::from_str(&env, "image/jpeg"),
+    );
+    assert_eq!(index, 1);
+
+    env.ledger().with_mut(|l| l.timestamp += 3600);
+    client.submit_counter_statement(
+        &landlord,
+        &dispute_id,
+        &String::from_str(&env, "QmLandlordAnswer"),
+    );
+    client.submit_evidence(
+        &landlord,
+        &dispute_id,
+        &String::from_str(&env, "QmInspectionReport"),
+        &pdf,
+    );
+
+    let timeline = client.get_timeline(&dispute_id);
+    assert_eq!(timeline.len(), 4);
+
+    let statement = timeline.get(0).unwrap();
+    assert_eq!(statement.kind, EvidenceKind::Statement);
+    assert_eq!(statement.submitter, tenant);
+    assert_eq!(
+        statement.hash,
+        client.get_dispute(&dispute_id).unwrap().details_hash
+    );
+
+    let counter = timeline.get(2).unwrap();
+    assert_eq!(counter.kind, EvidenceKind::CounterStatement);
+    assert_eq!(counter.submitter, landlord);
+    assert_eq!(
+        counter.submitted_at,
+        timeline.get(1).unwrap().submitted_at + 3600
+    );
+
+    let report = client.get_evidence(&dispute_id, &3).unwrap();
+    assert_eq!(report.kind, EvidenceKind::Evidence);
+    assert_eq!(report.content_type, pdf);
+    assert_eq!(client.get_dispute(&dispute_id).unwrap().evidence_count, 4);
+}
+
+#[test]
+fn test_counter_statement_restrictions() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, _, _, tenant, landlord, dispute_id) = setup_open_dispute(&env, &client, None);
+    let hash = String::from_str(&env, "QmAnswer");
+
+    // The tenant raised the dispute, so only the landlord may answer
+    assert_eq!(
+        client.try_submit_counter_statement(&tenant, &dispute_id, &hash),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    client.submit_counter_statement(&landlord, &dispute_id, &hash);
+    assert_eq!(
+        client.try_submit_counter_statement(&landlord, &dispute_id, &hash),
+        Err(Ok(DisputeError::CounterStatementExists))
+    );
+}
+
+#[test]
+fn test_submit_evidence_restrictions() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, arbiters, _, tenant, _, dispute_id) = setup_open_dispute(&env, &client, None);
+    let hash = String::from_str(&env, "QmEvidence");
+    let content_type = String::from_str(&env, "image/png");
+
+    assert_eq!(
+        client.try_submit_evidence(&arbiters[0], &dispute_id, &hash, &content_type),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_submit_evidence(
+            &tenant,
+            &dispute_id,
+            &String::from_str(&env, ""),
+            &content_type
+        ),
+        Err(Ok(DisputeError::InvalidDetailsHash))
+    );
+
+    for _ in 1..MAX_EVIDENCE {
+        client.submit_evidence(&tenant, &dispute_id, &hash, &content_type);
+    }
+    assert_eq!(
+        client.try_submit_evidence(&tenant, &dispute_id, &hash, &content_type),
+        Err(Ok(DisputeError::EvidenceLimitReached))
+    );
+}
+
+#[test]
+fn test_co_tenants_may_file_evidence() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+    let co_tenant = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    MockChiomaContractClient::new(&env, &mock_chioma).set_co_tenancy(
+        &agreement_id,
+        &CoTenancy {
+            tenants: soroban_sdk::vec![
+                &env,
+                TenantShare {
+                    tenant: tenant.clone(),
+                    share_bps: 5000,
+                },
+                TenantShare {
+                    tenant: co_tenant.clone(),
+                    share_bps: 5000,
+                },
+            ],
+            guarantor: None,
+        },
+    );
+
+    client.initialize(&admin, &3, &mock_chioma);
+    let dispute_id = client.raise_dispute(
+        &landlord,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco"),
+        &None,
+    );
+    assert_eq!(
+        client.get_dispute(&dispute_id).unwrap().co_tenants,
+        soroban_sdk::vec![&env, co_tenant.clone()]
+    );
+
+    let hash = String::from_str(&env, "QmEvidence");
+    let content_type = String::from_str(&env, "image/png");
+    assert_eq!(
+        client.submit_evidence(&co_tenant, &dispute_id, &hash, &content_type),
+        1
+    );
+    assert_eq!(
+        client.try_submit_evidence(&Address::generate(&env), &dispute_id, &hash, &content_type),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    // The landlord raised the dispute, so any tenant may answer it
+    client.submit_counter_statement(&co_tenant, &dispute_id, &hash);
+    assert_eq!(
+        client.try_submit_counter_statement(&tenant, &dispute_id, &hash),
+        Err(Ok(DisputeError::CounterStatementExists))
+    );
+}
+
+#[test]
+fn test_submit_evidence_fails_after_evidence_window() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, _, _, tenant, _, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(
+        dispute.evidence_deadline,
+        dispute.raised_at + VotingRules::default().evidence_period
+    );
+    env.ledger()
+        .with_mut(|l| l.timestamp = dispute.evidence_deadline + 1);
+
+    assert_eq!(
+        client.try_submit_evidence(
+            &tenant,
+            &dispute_id,
+            &String::from_str(&env, "QmLate"),
+            &String::from_str(&env, "image/png"),
+        ),
+        Err(Ok(DisputeError::EvidenceWindowClosed))
+    );
+}
+
+/// Stores an unanswered request on `agreement_id` whose acknowledgement
+/// deadline was missed.
+fn set_breached_repair(
+    env: &Env,
+    mock_chioma: &Address,
+    request_id: u32,
+    agreement_id: &str,
+    tenant: &Address,
+    landlord: &Address,
+) {
+    MockChiomaContractClient::new(env, mock_chioma).set_maint(&MaintenanceRequest {
+        id: request_id,
+        agreement_id: String::from_str(env, agreement_id),
+        tenant: tenant.clone(),
+        landlord: landlord.clone(),
+        description_hash: String::from_str(env, "QmBrokenBoiler"),
+        priority: MaintenancePriority::Emergency,
+        status: MaintenanceStatus::Open,
+        opened_at: 0,
+        acknowledge_by: 86_400,
+        complete_by: 259_200,
+        acknowledged_at: None,
+        scheduled_for: None,
+        completed_at: None,
+        acknowledgement_breached_at: Some(86_401),
+        completion_breached_at: None,
+    });
+}
+
+#[test]
+fn test_cite_maintenance_request() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, arbiters, mock_chioma, tenant, landlord, dispute_id) =
+        setup_open_dispute(&env, &client, None);
+    set_breached_repair(&env, &mock_chioma, 1, "agreement_001", &tenant, &landlord);
+    set_breached_repair(&env, &mock_chioma, 2, "agreement_002", &tenant, &landlord);
+
+    let index = client.cite_maintenance_request(&tenant, &dispute_id, &1);
+    let entry = client.get_evidence(&dispute_id, &index).unwrap();
+    assert_eq!(entry.kind, EvidenceKind::MaintenanceRecord(1));
+    assert_eq!(entry.submitter, tenant);
+    assert_eq!(entry.hash, String::from_str(&env, "QmBrokenBoiler"));
+
+    assert_eq!(
+        client.try_cite_maintenance_request(&tenant, &dispute_id, &1),
+        Err(Ok(DisputeError::MaintenanceAlreadyCited))
+    );
+    assert_eq!(
+        client.try_cite_maintenance_request(&tenant, &dispute_id, &2),
+        Err(Ok(DisputeError::MaintenanceRequestMismatch))
+    );
+    assert_eq!(
+        client.try_cite_maintenance_request(&tenant, &dispute_id, &3),
+        Err(Ok(DisputeError::MaintenanceRequestNotFound))
+    );
+    assert_eq!(
+        client.try_cite_maintenance_request(&arbiters[0], &dispute_id, &1),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+}
+
+/// Registers eight arbiters, a panel of three and appeals heard by five with a
+/// `bond` posted in a fresh token, then raises a dispute on `agreement_001`.
+/// Both parties hold 1000 of the token.
+fn setup_appeals(
+    env: &Env,
+    client: &DisputeResolutionContractClient<'_>,
+    bond: i128,
+) -> (
+    soroban_sdk::Vec<Address>,
+    Address,
+    Address,
+    Address,
+    Address,
+    u32,
+) {
+    let admin = Address::generate(env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_admin = TokenAdminClient::new(env, &token);
+    token_admin.mint(&tenant, &1000);
+    token_admin.mint(&landlord, &1000);
+
+    client.initialize(&admin, &3, &mock_chioma);
+    client.set_staking_config(
+        &admin,
+        &StakingConfig {
+            token: token.clone(),
+            min_stake: 0,
+            filing_fee: 0,
+            no_show_slash_bps: 0,
+        },
+    );
+    client.set_appeal_config(
+        &admin,
+        &AppealConfig {
+            appeal_period: 86_400,
+            bond,
+            panel_size: 5,
+        },
+    );
+
+    let mut arbiters = soroban_sdk::Vec::new(env);
+    for _ in 0..8 {
+        let arbiter = Address::generate(env);
+        client.add_arbiter(&admin, &arbiter);
+        arbiters.push_back(arbiter);
+    }
+
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &String::from_str(env, "agreement_001"),
+        &DisputeCategory::Other,
+        &String::from_str(env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco"),
+        &None,
+    );
+
+    (arbiters, mock_chioma, token, tenant, landlord, dispute_id)
+}
+
+fn vote_panel(client: &DisputeResolutionContractClient<'_>, dispute_id: &u32, favor: bool) {
+    let panel = client.get_dispute(dispute_id).unwrap().panel;
+    close_evidence_window(client, dispute_id);
+    for member in panel.iter() {
+        client.vote_on_dispute(&member, dispute_id, &favor);
+    }
+}
+
+#[test]
+fn test_appeal_overturns_outcome_and_returns_bond() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, mock_chioma, token, tenant, landlord, dispute_id) = setup_appeals(&env, &client, 200);
+    let token_client = TokenClient::new(&env, &token);
+
+    vote_panel(&client, &dispute_id, true);
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::FavorLandlord
+    );
+
+    // Not enforced while the appeal window is open
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert!(!dispute.enforced);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Disputed
+    );
+
+    assert_eq!(
+        client.try_appeal(&landlord, &dispute_id),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    client.appeal(&tenant, &dispute_id);
+    assert_eq!(token_client.balance(&tenant), 800);
+
+    let appealed = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(appealed.round, 1);
+    assert!(!appealed.resolved);
+    assert_eq!(appealed.appealed_outcome, DisputeOutcome::FavorLandlord);
+    assert_eq!(appealed.panel.len(), 5);
+    for member in appealed.panel.iter() {
+        assert!(!dispute.panel.contains(&member));
+    }
+
+    vote_panel(&client, &dispute_id, false);
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::FavorTenant
+    );
+
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert!(dispute.enforced);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
+    assert_eq!(token_client.balance(&tenant), 1000);
+
+    assert_eq!(
+        client.try_appeal(&landlord, &dispute_id),
+        Err(Ok(DisputeError::NotAppealable))
+    );
+}
+
+#[test]
+fn test_upheld_appeal_forfeits_bond_to_appeal_panel() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, mock_chioma, token, tenant, _, dispute_id) = setup_appeals(&env, &client, 200);
+    let token_client = TokenClient::new(&env, &token);
+
+    vote_panel(&client, &dispute_id, true);
+    client.resolve_dispute(&dispute_id);
+    client.appeal(&tenant, &dispute_id);
+
+    vote_panel(&client, &dispute_id, true);
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::FavorLandlord
+    );
+
+    assert_eq!(token_client.balance(&tenant), 800);
+    for member in client.get_dispute(&dispute_id).unwrap().panel.iter() {
+        assert_eq!(token_client.balance(&member), 40);
+    }
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Terminated
+    );
+}
+
+#[test]
+fn test_enforce_resolution_after_appeal_window() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, mock_chioma, _, tenant, _, dispute_id) = setup_appeals(&env, &client, 0);
+
+    assert_eq!(
+        client.try_enforce_resolution(&dispute_id),
+        Err(Ok(DisputeError::DisputeNotResolved))
+    );
+
+    vote_panel(&client, &dispute_id, true);
+    client.resolve_dispute(&dispute_id);
+
+    assert_eq!(
+        client.try_enforce_resolution(&dispute_id),
+        Err(Ok(DisputeError::AppealWindowOpen))
+    );
+
+    let appeal_deadline = client.get_dispute(&dispute_id).unwrap().appeal_deadline;
+    env.ledger().with_mut(|l| l.timestamp = appeal_deadline + 1);
+
+    assert_eq!(
+        client.try_appeal(&tenant, &dispute_id),
+        Err(Ok(DisputeError::AppealWindowClosed))
+    );
+
+    client.enforce_resolution(&dispute_id);
+    assert!(client.get_dispute(&dispute_id).unwrap().enforced);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Terminated
+    );
+
+    assert_eq!(
+        client.try_enforce_resolution(&dispute_id),
+        Err(Ok(DisputeError::DisputeAlreadyResolved))
+    );
+}
+
+#[test]
+fn test_set_appeal_config_validation() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    // The appeal panel must be larger than the first-round panel of three
+    assert_eq!(
+        client.try_set_appeal_config(
+            &admin,
+            &AppealConfig {
+                appeal_period: 86_400,
+                bond: 0,
+                panel_size: 3,
+            }
+        ),
+        Err(Ok(DisputeError::InvalidAppealConfig))
+    );
+    assert_eq!(
+        client.try_set_appeal_config(
+            &admin,
+            &AppealConfig {
+                appeal_period: 86_400,
+                bond: 100,
+                panel_size: 5,
+            }
+        ),
+        Err(Ok(DisputeError::StakingNotConfigured))
+    );
+
+    let config = AppealConfig {
+        appeal_period: 86_400,
+        bond: 0,
+        panel_size: 5,
+    };
+    client.set_appeal_config(&admin, &config);
+    assert_eq!(client.get_appeal_config(), Some(config));
+}
+
+#[test]
+fn test_settlement_splits_escrow_and_restores_agreement() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let arbiter1 = Address::generate(&env);
+    let arbiter2 = Address::generate(&env);
+    let arbiter3 = Address::generate(&env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    let (escrow, escrow_id, token) =
+        setup_funded_escrow(&env, &client.address, &tenant, &landlord, 2000);
+
+    client.initialize(&admin, &3, &mock_chioma);
+    client.set_escrow_contract(&admin, &escrow.address);
+    client.add_arbiter(&admin, &arbiter1);
+    client.add_arbiter(&admin, &arbiter2);
+    client.add_arbiter(&admin, &arbiter3);
+
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco"),
+        &Some(escrow_id.clone()),
+    );
+    assert_eq!(client.get_arbiter(&arbiter1).unwrap().open_disputes, 1);
+
+    client.propose_settlement(&tenant, &dispute_id, &7000, &false);
+    client.accept_settlement(&landlord, &dispute_id, &7000, &false);
+
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert!(dispute.resolved);
+    assert!(dispute.enforced);
+    assert_eq!(dispute.outcome, DisputeOutcome::Settled);
+    assert_eq!(dispute.get_outcome(), Some(DisputeOutcome::Settled));
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&landlord), 1400);
+    assert_eq!(token_client.balance(&tenant), 600);
+    assert_eq!(escrow.get_escrow(&escrow_id).status, EscrowStatus::Released);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
+
+    // Seats are released without counting as votes
+    let arbiter_info = client.get_arbiter(&arbiter1).unwrap();
+    assert_eq!(arbiter_info.open_disputes, 0);
+    assert_eq!(arbiter_info.disputes_voted, 0);
+
+    assert_eq!(
+        client.try_vote_on_dispute(&arbiter1, &dispute_id, &true),
+        Err(Ok(DisputeError::DisputeAlreadyResolved))
+    );
+}
+
+#[test]
+fn test_counter_offer_replaces_proposal() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, _, mock_chioma, tenant, landlord, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    client.propose_settlement(&tenant, &dispute_id, &2000, &false);
+
+    // A party cannot accept its own proposal
+    assert_eq!(
+        client.try_accept_settlement(&tenant, &dispute_id, &2000, &false),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    client.propose_settlement(&landlord, &dispute_id, &5000, &true);
+    let settlement = client.get_settlement(&dispute_id).unwrap();
+    assert_eq!(settlement.proposer, landlord);
+    assert_eq!(settlement.landlord_share_bps, 5000);
+    assert!(settlement.terminate);
+
+    assert_eq!(
+        client.try_accept_settlement(&landlord, &dispute_id, &5000, &true),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+    client.accept_settlement(&tenant, &dispute_id, &5000, &true);
+
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Terminated
+    );
+    assert_eq!(
+        client.try_propose_settlement(&tenant, &dispute_id, &5000, &false),
+        Err(Ok(DisputeError::DisputeAlreadyResolved))
+    );
+}
+
+#[test]
+fn test_settlement_replaced_before_acceptance_is_rejected() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, _, mock_chioma, tenant, landlord, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    client.propose_settlement(&tenant, &dispute_id, &7000, &false);
+
+    // The tenant swaps in worse terms just before the landlord accepts
+    client.propose_settlement(&tenant, &dispute_id, &0, &true);
+    assert_eq!(
+        client.try_accept_settlement(&landlord, &dispute_id, &7000, &false),
+        Err(Ok(DisputeError::SettlementChanged))
+    );
+    assert!(!client.get_dispute(&dispute_id).unwrap().resolved);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Disputed
+    );
+
+    client.accept_settlement(&landlord, &dispute_id, &0, &true);
+    assert_eq!(client.get_settlement(&dispute_id), None);
+    assert_eq!(
+        client.try_accept_settlement(&landlord, &dispute_id, &0, &true),
+        Err(Ok(DisputeError::DisputeAlreadyResolved))
+    );
+}
+
+#[test]
+fn test_enforced_outcome_removes_open_settlement() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, arbiters, _, tenant, _, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    client.propose_settlement(&tenant, &dispute_id, &2000, &false);
+
+    close_evidence_window(&client, &dispute_id);
+    for arbiter in arbiters.iter() {
+        client.vote_on_dispute(arbiter, &dispute_id, &false);
+    }
+    client.resolve_dispute(&dispute_id);
+
+    assert!(client.get_dispute(&dispute_id).unwrap().enforced);
+    assert_eq!(client.get_settlement(&dispute_id), None);
+}
+
+#[test]
+fn test_settlement_rejects_invalid_requests() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, arbiters, _, tenant, landlord, dispute_id) = setup_open_dispute(&env, &client, None);
+
+    assert_eq!(
+        client.try_propose_settlement(&arbiters[0], &dispute_id, &5000, &false),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_propose_settlement(&tenant, &dispute_id, &10_001, &false),
+        Err(Ok(DisputeError::InvalidSettlement))
+    );
+    assert_eq!(
+        client.try_propose_settlement(&tenant, &99, &5000, &false),
+        Err(Ok(DisputeError::DisputeNotFound))
+    );
+    assert_eq!(
+        client.try_accept_settlement(&landlord, &dispute_id, &5000, &false),
+        Err(Ok(DisputeError::SettlementNotFound))
+    );
+    assert_eq!(client.get_settlement(&dispute_id), None);
+}
+
+#[test]
+fn test_settlement_refunds_filing_fee() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, token, arbiters, mock_chioma, tenant) = setup_staking(&env, &client, 3);
+    let token_client = TokenClient::new(&env, &token);
+    let agreement_id = String::from_str(&env, "agreement_001");
+
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco"),
+        &None,
+    );
+    assert_eq!(token_client.balance(&tenant), 0);
+
+    let landlord = client.get_dispute(&dispute_id).unwrap().landlord;
+    client.propose_settlement(&landlord, &dispute_id, &0, &false);
+    client.accept_settlement(&tenant, &dispute_id, &0, &false);
+
+    // No arbiter decided the dispute, so no one is rewarded or slashed
+    assert_eq!(token_client.balance(&tenant), 300);
+    for arbiter in arbiters.iter() {
+        let arbiter_info = client.get_arbiter(arbiter).unwrap();
+        assert_eq!(arbiter_info.stake, 1000);
+        assert_eq!(arbiter_info.open_disputes, 0);
+        assert!(arbiter_info.active);
+    }
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
+}
+
+#[test]
+fn test_settlement_during_appeal_window_and_after_enforcement() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, mock_chioma, token, tenant, landlord, dispute_id) = setup_appeals(&env, &client, 200);
+    let token_client = TokenClient::new(&env, &token);
+
+    vote_panel(&client, &dispute_id, true);
+    client.resolve_dispute(&dispute_id);
+    client.appeal(&tenant, &dispute_id);
+    assert_eq!(token_client.balance(&tenant), 800);
+
+    // Settling a pending appeal returns the bond
+    client.propose_settlement(&landlord, &dispute_id, &8000, &true);
+    client.accept_settlement(&tenant, &dispute_id, &8000, &true);
+    assert_eq!(token_client.balance(&tenant), 1000);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Terminated
+    );
+
+    let dispute = client.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.outcome, DisputeOutcome::Settled);
+    for member in dispute.panel.iter() {
+        assert_eq!(client.get_arbiter(&member).unwrap().open_disputes, 0);
+    }
+
+    assert_eq!(
+        client.try_enforce_resolution(&dispute_id),
+        Err(Ok(DisputeError::DisputeAlreadyResolved))
+    );
+    assert_eq!(
+        client.try_appeal(&landlord, &dispute_id),
+        Err(Ok(DisputeError::NotAppealable))
+    );
+}
+
+#[test]
+fn test_agreement_can_be_disputed_again_after_resolution() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, arbiters, mock_chioma, _, landlord, first) = setup_open_dispute(&env, &client, None);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+
+    // Only one dispute per agreement may be open at a time
+    assert_eq!(
+        client.try_raise_dispute(
+            &landlord,
+            &agreement_id,
+            &DisputeCategory::Other,
+            &details_hash,
+            &None
+        ),
+        Err(Ok(DisputeError::DisputeAlreadyExists))
+    );
+
+    close_evidence_window(&client, &first);
+    for arbiter in arbiters.iter() {
+        client.vote_on_dispute(arbiter, &first, &false);
+    }
+    assert_eq!(client.resolve_dispute(&first), DisputeOutcome::FavorTenant);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
+
+    let second = client.raise_dispute(
+        &landlord,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+    assert_eq!(second, 2);
+    assert_eq!(client.get_dispute_count(), 2);
+    assert_eq!(client.get_agreement_dispute_count(&agreement_id), 2);
+
+    let dispute = client.get_dispute(&second).unwrap();
+    assert_eq!(dispute.raiser, landlord);
+    assert!(!dispute.resolved);
+    assert_eq!(client.get_timeline(&second).len(), 1);
+
+    // Votes and outcomes stay with the dispute they were cast on
+    assert!(client.get_dispute(&first).unwrap().resolved);
+    assert!(client.get_vote(&second, &arbiters[0]).is_none());
+    close_evidence_window(&client, &second);
+    client.vote_on_dispute(&arbiters[0], &second, &true);
+    assert!(client.get_vote(&first, &arbiters[0]).is_some());
+}
+
+#[test]
+fn test_list_agreement_disputes() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (_, _, _, tenant, landlord, first) = setup_open_dispute(&env, &client, None);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+
+    let other = client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "agreement_002"),
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+
+    client.propose_settlement(&tenant, &first, &0, &false);
+    client.accept_settlement(&landlord, &first, &0, &false);
+    let second = client.raise_dispute(
+        &landlord,
+        &agreement_id,
+        &DisputeCategory::Other,
+        &details_hash,
+        &None,
+    );
+
+    let disputes = client.list_agreement_disputes(&agreement_id, &0, &10);
+    assert_eq!(disputes.len(), 2);
+    assert_eq!(disputes.get(0).unwrap().id, first);
+    assert_eq!(disputes.get(1).unwrap().id, second);
+
+    let page = client.list_agreement_disputes(&agreement_id, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, second);
+
+    let other_disputes =
+        client.list_agreement_disputes(&String::from_str(&env, "agreement_002"), &0, &10);
+    assert_eq!(other_disputes.len(), 1);
+    assert_eq!(other_disputes.get(0).unwrap().id, other);
+
+    assert_eq!(
+        client
+            .list_agreement_disputes(&String::from_str(&env, "agreement_404"), &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_set_voting_rules_checks_category_voting_periods() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+    client.set_category_config(
+        &admin,
+        &DisputeCategory::Maintenance,
+        &CategoryConfig {
+            required_votes: 1,
+            voting_period: 259_200,
+            filing_fee: 0,
+            allowed_outcomes: soroban_sdk::vec![&env, DisputeOutcome::FavorTenant],
+        },
+    );
+
+    // Evidence would outlast the maintenance category's voting window
+    let mut rules = VotingRules {
+        voting_period: 604_800,
+        evidence_period: 259_200,
+        ..VotingRules::default()
+    };
+    assert_eq!(
+        client.try_set_voting_rules(&admin, &rules),
+        Err(Ok(DisputeError::InvalidVotingRules))
+    );
+
+    rules.evidence_period = 259_199;
+    client.set_voting_rules(&admin, &rules);
+    assert_eq!(client.get_voting_rules(), rules);
+}
+
+#[test]
+fn test_set_category_config_validation() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    let config = CategoryConfig {
+        required_votes: 1,
+        voting_period: 259_200,
+        filing_fee: 0,
+        allowed_outcomes: soroban_sdk::vec![&env, DisputeOutcome::FavorTenant],
+    };
+
+    assert_eq!(
+        client.try_set_category_config(
+            &Address::generate(&env),
+            &DisputeCategory::Maintenance,
+            &config
+        ),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    let invalid = [
+        CategoryConfig {
+            required_votes: 0,
+            ..config.clone()
+        },
+        // Shorter than the default 48 hour evidence period
+        CategoryConfig {
+            voting_period: 3600,
+            ..config.clone()
+        },
+        CategoryConfig {
+            filing_fee: -1,
+            ..config.clone()
+        },
+        CategoryConfig {
+            allowed_outcomes: soroban_sdk::Vec::new(&env),
+            ..config.clone()
+        },
+        CategoryConfig {
+            allowed_outcomes: soroban_sdk::vec![&env, DisputeOutcome::Settled],
+            ..config.clone()
+        },
+    ];
+    for invalid_config in invalid.iter() {
+        assert_eq!(
+            client.try_set_category_config(&admin, &DisputeCategory::Maintenance, invalid_config),
+            Err(Ok(DisputeError::InvalidCategoryConfig))
+        );
+    }
+
+    assert_eq!(
+        client.try_set_category_config(
+            &admin,
+            &DisputeCategory::Maintenance,
+            &CategoryConfig {
+                filing_fee: 100,
+                ..config.clone()
+            }
+        ),
+        Err(Ok(DisputeError::StakingNotConfigured))
+    );
+
+    client.set_category_config(&admin, &DisputeCategory::Maintenance, &config);
+    assert_eq!(
+        client.get_category_config(&DisputeCategory::Maintenance),
+        Some(config)
+    );
+    assert_eq!(client.get_category_config(&DisputeCategory::Other), None);
+}
+
+#[test]
+fn test_deposit_disputes_use_fast_track_category() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, token, arbiters, mock_chioma, tenant) = setup_staking(&env, &client, 3);
+    let token_client = TokenClient::new(&env, &token);
+    TokenAdminClient::new(&env, &token).mint(&tenant, &100);
+    client.set_category_config(
+        &admin,
+        &DisputeCategory::DepositDeduction,
+        &CategoryConfig {
+            required_votes: 1,
+            voting_period: 259_200,
+            filing_fee: 100,
+            allowed_outcomes: soroban_sdk::vec![
+                &env,
+                DisputeOutcome::FavorLandlord,
+                DisputeOutcome::FavorTenant,
+                DisputeOutcome::Split,
+            ],
+        },
+    );
+
+    let details_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    let fast = client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "agreement_001"),
+        &DisputeCategory::DepositDeduction,
+        &details_hash,
+        &None,
+    );
+    let slow = client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "agreement_002"),
+        &DisputeCategory::UnpaidRent,
+        &details_hash,
+        &None,
+    );
+
+    // 100 for the deposit dispute and the default 300 for the other
+    assert_eq!(token_client.balance(&tenant), 0);
+    let fast_dispute = client.get_dispute(&fast).unwrap();
+    assert_eq!(fast_dispute.category, DisputeCategory::DepositDeduction);
+    assert_eq!(fast_dispute.fee, 100);
+    assert_eq!(fast_dispute.required_votes, 1);
+    assert_eq!(
+        fast_dispute.voting_deadline,
+        fast_dispute.raised_at + 259_200
+    );
+
+    let slow_dispute = client.get_dispute(&slow).unwrap();
+    assert_eq!(slow_dispute.fee, 300);
+    assert_eq!(slow_dispute.required_votes, 3);
+    assert_eq!(
+        slow_dispute.voting_deadline,
+        slow_dispute.raised_at + VotingRules::default().voting_period
+    );
+
+    // A single vote decides the fast-track dispute
+    close_evidence_window(&client, &fast);
+    client.vote_on_dispute(&arbiters[0], &fast, &false);
+    assert_eq!(client.resolve_dispute(&fast), DisputeOutcome::FavorTenant);
+    assert_eq!(
+        agreement_status(&env, &mock_chioma, "agreement_001"),
+        AgreementStatus::Active
+    );
+
+    close_evidence_window(&client, &slow);
+    client.vote_on_dispute(&arbiters[0], &slow, &false);
+    assert_eq!(
+        client.try_resolve_dispute(&slow),
+        Err(Ok(DisputeError::InsufficientVotes))
+    );
+}
+
+#[test]
+fn test_category_restricts_allowed_outcomes() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let (mock_chioma, tenant, _landlord) = setup_mock_chioma(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin, &2, &mock_chioma);
+    client.set_voting_rules(
+        &admin,
+        &VotingRules {
+            tie_rule: TieRule::Split,
+            ..VotingRules::default()
+        },
+    );
+    let arbiters = [Address::generate(&env), Address::generate(&env)];
+    for arbiter in arbiters.iter() {
+        client.add_arbiter(&admin, arbiter);
+    }
+    client.set_category_config(
+        &admin,
+        &DisputeCategory::Maintenance,
+        &CategoryConfig {
+            required_votes: 2,
+            voting_period: 604_800,
+            filing_fee: 0,
+            allowed_outcomes: soroban_sdk::vec![
+                &env,
+                DisputeOutcome::FavorLandlord,
+                DisputeOutcome::FavorTenant,
+            ],
+        },
+    );
+
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "agreement_001"),
+        &DisputeCategory::Maintenance,
+        &String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco"),
+        &None,
+    );
+
+    close_evidence_window(&client, &dispute_id);
+    client.vote_on_dispute(&arbiters[0], &dispute_id, &true);
+    client.vote_on_dispute(&arbiters[1], &dispute_id, &false);
+
+    // A split is not allowed, so the split tie rule escalates instead
+    let deadline = client.get_dispute(&dispute_id).unwrap().voting_deadline;
+    env.ledger().with_mut(|l| l.timestamp = deadline + 1);
+    assert_eq!(
+        client.resolve_dispute(&dispute_id),
+        DisputeOutcome::Inconclusive
+    );
+    assert!(client.get_dispute(&dispute_id).unwrap().escalated);
+
+    assert_eq!(
+        client.try_resolve_escalated_dispute(&admin, &dispute_id, &DisputeOutcome::Split),
+        Err(Ok(DisputeError::InvalidOutcome))
+    );
+    assert_eq!(
+        client.resolve_escalated_dispute(&admin, &dispute_id, &DisputeOutcome::FavorTenant),
+        DisputeOutcome::FavorTenant
+    );
+}
+
+#[test]
+fn test_vote_rejected_for_disallowed_side() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    env.mock_all_auths();
+
+    let (admin, arbiters, _, tenant, _, _) = setup_open_dispute(&env, &client, None);
+    client.set_category_config(
+        &admin,
+        &DisputeCategory::Other,
+        &CategoryConfig {
+            required_votes: 3,
+            voting_period: 604_800,
+            filing_fee: 0,
+            allowed_outcomes: soroban_sdk::vec![&env, DisputeOutcome::FavorTenant],
+        },
+    );
+
+    let dispute_id = client.raise_dispute(
+        &tenant,
+        &String::from_str(&env, "agreement_002"),
+        &DisputeCategory::Other,
+        &String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco"),
+        &None,
+    );
+
+    close_evidence_window(&client, &dispute_id);
+    assert_eq!(
+        client.try_vote_on_dispute(&arbiters[0], &dispute_id, &true),
+        Err(Ok(DisputeError::InvalidOutcome))
+    );
+    client.vote_on_dispute(&arbiters[0], &dispute_id, &false);
 }
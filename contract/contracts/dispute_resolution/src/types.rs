@@ -1,10 +1,142 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+
+pub use chioma_types::Role;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DisputeOutcome {
     FavorLandlord,
     FavorTenant,
+    /// Deposit split evenly; the agreement is restored to Active
+    Split,
+    /// No decision this round: voting was extended or escalated to the admin
+    Inconclusive,
+    /// Closed by mutual agreement of the parties, without an arbiter decision
+    Settled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeCategory {
+    DepositDeduction,
+    UnpaidRent,
+    Maintenance,
+    EarlyTermination,
+    /// Harassment and anything not covered above
+    Other,
+}
+
+/// Rules for disputes in one category, overriding the contract-wide quorum,
+/// voting period and filing fee.
+///
+/// Arbiters may only decide a dispute with one of `allowed_outcomes`: votes
+/// for a side whose outcome is not allowed are rejected, and a split tie rule
+/// escalates to the admin when `Split` is not allowed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategoryConfig {
+    pub required_votes: u32,
+    pub voting_period: u64,
+    pub filing_fee: i128,
+    pub allowed_outcomes: Vec<DisputeOutcome>,
+}
+
+/// What happens when voting closes without a decisive majority.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TieRule {
+    /// Reopen voting for another period, escalating once extensions run out
+    ExtendWindow,
+    /// Hand the decision to the admin
+    EscalateToAdmin,
+    /// Resolve as `DisputeOutcome::Split`
+    Split,
+}
+
+/// Rules governing how disputes are voted on and decided.
+///
+/// A side wins once at least `min_votes_required` votes are cast and its share
+/// of the votes is strictly greater than `supermajority_bps` (5000 is a simple
+/// majority, 6666 two thirds). Votes are accepted until the voting deadline;
+/// a dispute still undecided after the deadline is handled by `tie_rule`.
+/// Parties may add evidence for the first `evidence_period` seconds; votes are
+/// accepted once that window has closed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingRules {
+    pub voting_period: u64,
+    pub evidence_period: u64,
+    pub supermajority_bps: u32,
+    pub tie_rule: TieRule,
+    pub max_extensions: u32,
+}
+
+impl Default for VotingRules {
+    fn default() -> Self {
+        Self {
+            voting_period: 604_800,   // 7 days
+            evidence_period: 172_800, // 48 hours
+            supermajority_bps: 5000,
+            tie_rule: TieRule::ExtendWindow,
+            max_extensions: 1,
+        }
+    }
+}
+
+/// Appeal settings.
+///
+/// After a first-round resolution the losing party (either party for a split)
+/// has `appeal_period` seconds to appeal by posting `bond` in the staking token.
+/// The appeal is heard by `panel_size` arbiters who did not sit on the first
+/// panel, and its outcome is final. The bond is returned if the outcome
+/// changes and forfeited to the appeal panel otherwise.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AppealConfig {
+    pub appeal_period: u64,
+    pub bond: i128,
+    pub panel_size: u32,
+}
+
+/// A settlement offered by one party to close a dispute without arbiters.
+///
+/// Once the other party accepts, `landlord_share_bps` of any escrowed deposit
+/// is released to the landlord and the rest to the tenant, and the agreement
+/// is either terminated or restored to Active.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Settlement {
+    pub proposer: Address,
+    pub landlord_share_bps: u32,
+    pub terminate: bool,
+    pub proposed_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EvidenceKind {
+    /// The raiser's opening statement (`details_hash`)
+    Statement,
+    /// Supporting material from either party
+    Evidence,
+    /// The respondent's answer to the opening statement
+    CounterStatement,
+    /// A chioma maintenance request on the disputed agreement, by id. Its
+    /// deadlines and any SLA breach are read from chioma.
+    MaintenanceRecord(u32),
+}
+
+/// An entry in a dispute's timeline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Evidence {
+    pub submitter: Address,
+    pub kind: EvidenceKind,
+    /// Hash reference to the off-chain content (IPFS, etc.)
+    pub hash: String,
+    /// MIME type of the content; empty for statements
+    pub content_type: String,
+    pub submitted_at: u64,
 }
 
 #[contracttype]
@@ -22,31 +154,134 @@ pub struct Arbiter {
     pub address: Address,
     pub added_at: u64,
     pub active: bool,
+    /// Set when the admin deactivates the arbiter; staking cannot lift it
+    pub suspended: bool,
+    /// Tokens staked by the arbiter, held by this contract
+    pub stake: i128,
+    /// Unresolved disputes the arbiter sits on; stake is locked while non-zero
+    pub open_disputes: u32,
+    /// Resolved disputes the arbiter voted on
+    pub disputes_voted: u32,
+    /// Resolved disputes where the arbiter voted for the final outcome
+    pub majority_votes: u32,
+}
+
+impl Arbiter {
+    pub fn stats(&self) -> ArbiterStats {
+        let majority_rate_bps = if self.disputes_voted == 0 {
+            0
+        } else {
+            (self.majority_votes as u64 * 10_000 / self.disputes_voted as u64) as u32
+        };
+
+        ArbiterStats {
+            disputes_voted: self.disputes_voted,
+            majority_votes: self.majority_votes,
+            majority_rate_bps,
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbiterStats {
+    pub disputes_voted: u32,
+    pub majority_votes: u32,
+    /// Share of votes that matched the final outcome, in basis points
+    pub majority_rate_bps: u32,
+}
+
+/// Arbiter incentive settings.
+///
+/// Arbiters must stake at least `min_stake` to be drawn for panels. Raisers pay
+/// `filing_fee` when opening a dispute. On resolution, the fee plus any slashed
+/// stake is shared equally by panel members who voted for the outcome (every
+/// voter for a split). Panel members who missed the voting deadline lose
+/// `no_show_slash_bps` of their stake and are deactivated until they restake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakingConfig {
+    pub token: Address,
+    pub min_stake: i128,
+    pub filing_fee: i128,
+    pub no_show_slash_bps: u32,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Dispute {
+    pub id: u32,
     pub agreement_id: String,
+    pub category: DisputeCategory,
+    /// Votes needed to decide the dispute, from its category
+    pub required_votes: u32,
+    /// Outcomes arbiters may reach, from its category
+    pub allowed_outcomes: Vec<DisputeOutcome>,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub agent: Option<Address>,
+    /// Other tenants sharing the agreement, recorded when the dispute is raised
+    pub co_tenants: Vec<Address>,
+    /// Guarantor of the tenants' rent, if any
+    pub guarantor: Option<Address>,
+    pub raiser: Address,
+    pub escrow_id: Option<BytesN<32>>,
+    /// Filing fee paid by the raiser, distributed to arbiters on resolution
+    pub fee: i128,
     pub details_hash: String,
     pub raised_at: u64,
+    /// Evidence is accepted until this timestamp
+    pub evidence_deadline: u64,
+    /// Number of entries in the timeline, including the opening statement
+    pub evidence_count: u32,
     pub resolved: bool,
     pub resolved_at: Option<u64>,
     pub votes_favor_landlord: u32,
     pub votes_favor_tenant: u32,
+    pub voting_deadline: u64,
+    pub extensions: u32,
+    pub escalated: bool,
+    /// `Inconclusive` until the dispute is resolved
+    pub outcome: DisputeOutcome,
+    /// Arbiters assigned to vote on this dispute
+    pub panel: Vec<Address>,
+    /// Arbiters who recused themselves; never reassigned to this dispute
+    pub recused: Vec<Address>,
+    /// 0 for the first round, 1 once appealed
+    pub round: u32,
+    /// Whether the outcome has been applied to the chioma and escrow contracts
+    pub enforced: bool,
+    /// A resolved first-round outcome may be appealed until this timestamp
+    pub appeal_deadline: u64,
+    pub appellant: Option<Address>,
+    /// Bond posted by the appellant, held until the appeal is decided
+    pub appeal_bond: i128,
+    /// First-round outcome under appeal; `Inconclusive` if not appealed
+    pub appealed_outcome: DisputeOutcome,
+    /// First-round panel, excluded from the appeal panel
+    pub prior_panel: Vec<Address>,
 }
 
 impl Dispute {
+    /// Whether `address` is the tenant or one of the co-tenants.
+    pub fn is_tenant_side(&self, address: &Address) -> bool {
+        *address == self.tenant || self.co_tenants.contains(address)
+    }
+
+    /// Whether `address` is a party to the agreement, its agent or guarantor.
+    pub fn is_conflicted(&self, address: &Address) -> bool {
+        *address == self.landlord
+            || self.is_tenant_side(address)
+            || self.agent.as_ref() == Some(address)
+            || self.guarantor.as_ref() == Some(address)
+    }
+
     pub fn get_outcome(&self) -> Option<DisputeOutcome> {
         if !self.resolved {
             return None;
         }
 
-        if self.votes_favor_landlord > self.votes_favor_tenant {
-            Some(DisputeOutcome::FavorLandlord)
-        } else {
-            Some(DisputeOutcome::FavorTenant)
-        }
+        Some(self.outcome.clone())
     }
 }
 
@@ -54,7 +289,35 @@ impl Dispute {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Vote {
     pub arbiter: Address,
-    pub agreement_id: String,
+    pub dispute_id: u32,
     pub favor_landlord: bool,
     pub voted_at: u64,
 }
+
+/// `Arbiter` as the first version stored it, before staking and panels.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyArbiter {
+    pub address: Address,
+    pub added_at: u64,
+    pub active: bool,
+}
+
+/// A group of calls that can be paused on its own. Reads, admin calls and
+/// unstaking are never paused.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    /// Raising and appealing disputes
+    Disputes,
+    /// Submitting evidence, counter statements and maintenance citations
+    Evidence,
+    /// Voting and recusing
+    Voting,
+    /// Resolving disputes and enforcing outcomes
+    Resolution,
+    /// Proposing and accepting settlements
+    Settlements,
+    /// Adding stake
+    Staking,
+}
@@ -0,0 +1,135 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::access;
+use crate::arbiter;
+use crate::errors::DisputeError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{Arbiter, LegacyArbiter};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Returns the schema version of the stored data. Zero means the data was
+/// written before schema versioning was introduced.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+pub(crate) fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+    env.storage().instance().extend_ttl(500000, 500000);
+}
+
+/// Replace the contract's code with an uploaded WASM (admin only). Storage
+/// is kept; call `migrate` afterwards if the new code changed its schema.
+pub fn upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), DisputeError> {
+    access::require_admin(env, &admin)?;
+
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+
+    events::contract_upgraded(env, admin, new_wasm_hash);
+
+    Ok(())
+}
+
+/// Bring stored data up to `SCHEMA_VERSION` (admin only). A no-op when the
+/// data is already current. Returns the schema version after migrating.
+///
+/// First-version arbiters are too many to convert in one call. After this,
+/// `migrate_arbiter` converts them one at a time. The first version never
+/// stored a dispute: its `raise_dispute` called a chioma function that
+/// didn't exist.
+pub fn migrate(env: &Env, admin: Address) -> Result<u32, DisputeError> {
+    access::require_admin(env, &admin)?;
+
+    let from_version = get_schema_version(env);
+    if from_version >= SCHEMA_VERSION {
+        return Ok(from_version);
+    }
+
+    // The first version counted arbiters without indexing them. The index
+    // starts empty and each arbiter joins it when migrated.
+    if from_version < 1 {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ArbiterCount, &0u32);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::ArbiterCount, 500000, 500000);
+    }
+
+    set_schema_version(env, SCHEMA_VERSION);
+
+    events::contract_migrated(env, admin, from_version, SCHEMA_VERSION);
+
+    Ok(SCHEMA_VERSION)
+}
+
+/// Fail with `NotMigrated` while data written by older code awaits `migrate`.
+/// Every call that changes arbiters, disputes or their config checks this
+/// first; access control, pausing and the upgrade calls don't. An
+/// uninitialized contract passes so the call reports `NotInitialized`.
+pub(crate) fn require_migrated(env: &Env) -> Result<(), DisputeError> {
+    let initialized = env.storage().instance().has(&DataKey::State);
+    if initialized && get_schema_version(env) < SCHEMA_VERSION {
+        return Err(DisputeError::NotMigrated);
+    }
+
+    Ok(())
+}
+
+/// Convert a first-version arbiter and add it to the arbiter index. Anyone
+/// may call this. Does nothing for an arbiter that is already indexed.
+///
+/// The first version had no stake, so a migrated arbiter starts with none.
+/// One the admin had deactivated stays suspended.
+pub fn migrate_arbiter(env: &Env, arbiter: Address) -> Result<(), DisputeError> {
+    require_migrated(env)?;
+
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::ArbiterIndex(arbiter.clone()))
+    {
+        return Ok(());
+    }
+
+    let legacy: LegacyArbiter = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Arbiter(arbiter.clone()))
+        .ok_or(DisputeError::ArbiterNotFound)?;
+
+    arbiter::save_arbiter(
+        env,
+        &Arbiter {
+            address: legacy.address,
+            added_at: legacy.added_at,
+            active: legacy.active,
+            suspended: !legacy.active,
+            stake: 0,
+            open_disputes: 0,
+            disputes_voted: 0,
+            majority_votes: 0,
+        },
+    );
+
+    let count = arbiter::get_arbiter_count(env);
+    arbiter::set_index(env, count, &arbiter);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ArbiterCount, &(count + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::ArbiterCount, 500000, 500000);
+
+    events::arbiter_migrated(env, arbiter);
+
+    Ok(())
+}
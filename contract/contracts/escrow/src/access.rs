@@ -4,12 +4,28 @@
 use soroban_sdk::{Address, Env};
 
 use crate::errors::EscrowError;
-use crate::types::Escrow;
+use crate::escrow_impl::EscrowContract;
+use crate::storage::EscrowStorage;
+use crate::types::{Escrow, Operation};
 
 /// Access control validation functions.
 pub struct AccessControl;
 
 impl AccessControl {
+    /// Verify caller is the contract admin.
+    pub fn is_admin(env: &Env, caller: &Address) -> Result<(), EscrowError> {
+        match EscrowStorage::get_admin(env) {
+            Some(admin) if admin == *caller => Ok(()),
+            Some(_) => Err(EscrowError::NotAuthorized),
+            None => Err(EscrowError::NotInitialized),
+        }
+    }
+
+    /// Verify an operation is not paused.
+    pub fn not_paused(env: &Env, operation: Operation) -> Result<(), EscrowError> {
+        chioma_types::access::require_not_paused::<EscrowContract, _>(env, operation)
+    }
+
     /// Verify caller is the depositor (tenant).
     pub fn is_depositor(escrow: &Escrow, caller: &Address) -> Result<(), EscrowError> {
         if escrow.depositor == *caller {
@@ -116,6 +116,76 @@ impl DisputeHandler {
         Ok(())
     }
 
+    /// Resolve a dispute by splitting funds between both parties (admin only).
+    ///
+    /// CHECKS:
+    /// - Escrow must exist
+    /// - Escrow must be in Disputed state
+    /// - Caller must be arbiter
+    /// - Beneficiary share must be between 0 and 10000 basis points
+    ///
+    /// EFFECTS:
+    /// - Update escrow status to Released
+    /// - Clear dispute reason
+    /// - Clear approvals
+    ///
+    /// INTERACTIONS:
+    /// - Token transfers to beneficiary and depositor after state update
+    pub fn resolve_dispute_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_bps: u32,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is arbiter
+        AccessControl::is_arbiter(&escrow, &caller)?;
+
+        // Verify escrow is in Disputed state
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidState);
+        }
+
+        // Authorize the dispute resolution
+        caller.require_auth();
+
+        if beneficiary_bps > 10_000 {
+            return Err(EscrowError::InvalidSplit);
+        }
+
+        let beneficiary_amount = escrow.amount * beneficiary_bps as i128 / 10_000;
+        let depositor_amount = escrow.amount - beneficiary_amount;
+
+        // EFFECTS: Update status and clear dispute
+        escrow.status = EscrowStatus::Released;
+        escrow.dispute_reason = None;
+        EscrowStorage::save(&env, &escrow);
+
+        // Clear approvals
+        EscrowStorage::clear_approvals(&env, &escrow_id);
+
+        // INTERACTIONS: Token transfers from escrow contract to both parties
+        let token_client = token::Client::new(&env, &escrow.token);
+        if beneficiary_amount > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &escrow.beneficiary,
+                &beneficiary_amount,
+            );
+        }
+        if depositor_amount > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &escrow.depositor,
+                &depositor_amount,
+            );
+        }
+
+        Ok(())
+    }
+
     /// Get dispute information for an escrow.
     /// Returns the dispute reason if escrow is disputed, None otherwise.
     pub fn get_dispute_info(
@@ -1,5 +1,6 @@
 //! Custom error types for the Escrow contract.
 //! Each error maps to a unique contract error code.
+use chioma_types::AccessError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -28,4 +29,28 @@ pub enum EscrowError {
     EmptyDisputeReason = 10,
     /// Invalid approval target (neither beneficiary nor depositor)
     InvalidApprovalTarget = 11,
+    /// Split share is outside 0..=10000 basis points
+    InvalidSplit = 12,
+    /// Contract has already been initialized
+    AlreadyInitialized = 13,
+    /// Contract has not been initialized
+    NotInitialized = 14,
+    /// No admin transfer has been proposed
+    NoPendingAdmin = 15,
+    /// Operation is paused
+    OperationPaused = 16,
+    /// Role can't be granted on this contract
+    InvalidRole = 17,
+}
+
+impl From<AccessError> for EscrowError {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => EscrowError::NotInitialized,
+            AccessError::Unauthorized => EscrowError::NotAuthorized,
+            AccessError::InvalidRole => EscrowError::InvalidRole,
+            AccessError::NoPendingAdmin => EscrowError::NoPendingAdmin,
+            AccessError::OperationPaused => EscrowError::OperationPaused,
+        }
+    }
 }
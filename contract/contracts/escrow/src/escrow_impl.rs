@@ -1,20 +1,62 @@
 //! Core escrow lifecycle logic: creation, funding, approvals, and release.
 //! Implements checks-effects-interactions pattern for reentrancy safety.
+use chioma_types::access;
+use chioma_types::Role;
 use soroban_sdk::{contract, contractimpl, token, xdr::ToXdr, Address, BytesN, Env};
 
 use crate::dispute::DisputeHandler;
 
 use crate::access::AccessControl;
 use crate::errors::EscrowError;
+use crate::events;
 use crate::storage::EscrowStorage;
-use crate::types::{Escrow, EscrowStatus, ReleaseApproval};
+use crate::types::{Escrow, EscrowStatus, Operation, ReleaseApproval};
+
+pub const SCHEMA_VERSION: u32 = 1;
 
 /// Core escrow contract implementation.
 #[contract]
 pub struct EscrowContract;
 
+impl chioma_types::AccessControl for EscrowContract {
+    type Error = EscrowError;
+
+    fn admin(env: &Env) -> Option<Address> {
+        EscrowStorage::get_admin(env)
+    }
+
+    fn set_admin(env: &Env, admin: &Address) {
+        EscrowStorage::set_admin(env, admin);
+    }
+
+    fn is_grantable(role: &Role) -> bool {
+        matches!(role, Role::Pauser | Role::Guardian)
+    }
+}
+
 #[contractimpl]
 impl EscrowContract {
+    /// Initialize the contract with an admin.
+    ///
+    /// CHECKS:
+    /// - Contract must not already be initialized
+    /// - Admin must authorize
+    ///
+    /// EFFECTS:
+    /// - Stores the admin and the current schema version
+    pub fn initialize(env: Env, admin: Address) -> Result<(), EscrowError> {
+        if EscrowStorage::get_admin(&env).is_some() {
+            return Err(EscrowError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        EscrowStorage::set_admin(&env, &admin);
+        EscrowStorage::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(())
+    }
+
     /// Create a new escrow.
     ///
     /// CHECKS:
@@ -37,6 +79,8 @@ impl EscrowContract {
         amount: i128,
         token: Address,
     ) -> Result<BytesN<32>, EscrowError> {
+        AccessControl::not_paused(&env, Operation::Deposits)?;
+
         // CHECKS: Validate inputs
         if amount <= 0 {
             return Err(EscrowError::InsufficientFunds);
@@ -95,6 +139,8 @@ impl EscrowContract {
         escrow_id: BytesN<32>,
         caller: Address,
     ) -> Result<(), EscrowError> {
+        AccessControl::not_paused(&env, Operation::Deposits)?;
+
         // CHECKS: Get and validate escrow
         let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
 
@@ -142,6 +188,8 @@ impl EscrowContract {
         caller: Address,
         release_to: Address,
     ) -> Result<(), EscrowError> {
+        AccessControl::not_paused(&env, Operation::Releases)?;
+
         // CHECKS: Get and validate escrow
         let escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
 
@@ -210,25 +258,41 @@ impl EscrowContract {
     }
 
     /// Set up a dispute on an escrow.
+    /// Rejected while `Disputes` is paused.
     pub fn initiate_dispute(
         env: Env,
         escrow_id: BytesN<32>,
         caller: Address,
         reason: soroban_sdk::String,
     ) -> Result<(), EscrowError> {
+        AccessControl::not_paused(&env, Operation::Disputes)?;
         DisputeHandler::initiate_dispute(env, escrow_id, caller, reason)
     }
 
     /// Resolve a dispute by releasing funds to a target.
+    /// Rejected while `Releases` is paused.
     pub fn resolve_dispute(
         env: Env,
         escrow_id: BytesN<32>,
         caller: Address,
         release_to: Address,
     ) -> Result<(), EscrowError> {
+        AccessControl::not_paused(&env, Operation::Releases)?;
         DisputeHandler::resolve_dispute(env, escrow_id, caller, release_to)
     }
 
+    /// Resolve a dispute by splitting funds between beneficiary and depositor.
+    /// Rejected while `Releases` is paused.
+    pub fn resolve_dispute_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_bps: u32,
+    ) -> Result<(), EscrowError> {
+        AccessControl::not_paused(&env, Operation::Releases)?;
+        DisputeHandler::resolve_dispute_split(env, escrow_id, caller, beneficiary_bps)
+    }
+
     /// Get details of an escrow.
     /// Read-only view function.
     pub fn get_escrow(env: Env, escrow_id: BytesN<32>) -> Result<Escrow, EscrowError> {
@@ -251,4 +315,158 @@ impl EscrowContract {
             &release_to,
         ))
     }
+
+    /// Replace the contract's code with a previously uploaded WASM.
+    /// Storage is kept across the upgrade. The admin may be a multisig
+    /// account, in which case its signers authorize the upgrade together.
+    ///
+    /// CHECKS:
+    /// - Contract must be initialized
+    /// - Caller must be the admin and authorize
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        events::contract_upgraded(&env, admin, new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Bring stored data up to `SCHEMA_VERSION` after an upgrade.
+    /// A no-op when the data is already current.
+    /// Returns the schema version after migrating.
+    ///
+    /// CHECKS:
+    /// - Contract must be initialized
+    /// - Caller must be the admin and authorize
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, EscrowError> {
+        AccessControl::is_admin(&env, &admin)?;
+        admin.require_auth();
+
+        let from_version = EscrowStorage::get_schema_version(&env);
+        if from_version >= SCHEMA_VERSION {
+            return Ok(from_version);
+        }
+
+        EscrowStorage::set_schema_version(&env, SCHEMA_VERSION);
+        events::contract_migrated(&env, admin, from_version, SCHEMA_VERSION);
+
+        Ok(SCHEMA_VERSION)
+    }
+
+    /// Get the schema version of the stored data.
+    /// Returns 0 for data written before schema versioning.
+    pub fn get_schema_version(env: Env) -> u32 {
+        EscrowStorage::get_schema_version(&env)
+    }
+
+    /// Propose a new admin. The current admin stays in charge until the
+    /// nominee calls `accept_admin`; a later proposal replaces this one.
+    ///
+    /// CHECKS:
+    /// - Contract must be initialized
+    /// - Caller must be the admin and authorize
+    ///
+    /// EFFECTS:
+    /// - Stores `new_admin` as the pending admin and emits `admin_proposed`
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), EscrowError> {
+        access::propose_admin::<Self>(&env, admin, new_admin)
+    }
+
+    /// Accept a pending admin proposal.
+    ///
+    /// CHECKS:
+    /// - An admin transfer must have been proposed
+    /// - Caller must be the proposed admin and authorize
+    ///
+    /// EFFECTS:
+    /// - Makes the caller the admin, clears the pending admin and emits
+    ///   `admin_changed`
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), EscrowError> {
+        access::accept_admin::<Self>(&env, new_admin)
+    }
+
+    /// Get the current admin, if initialized.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        EscrowStorage::get_admin(&env)
+    }
+
+    /// Get the proposed admin awaiting acceptance, if any.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        access::get_pending_admin(&env)
+    }
+
+    /// Grant a role to an account. Only `Pauser` and `Guardian` can be
+    /// granted on this contract; pausers may pause and unpause operations,
+    /// guardians may only pause them.
+    ///
+    /// CHECKS:
+    /// - Contract must be initialized
+    /// - Caller must be the admin and authorize
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), EscrowError> {
+        access::grant_role::<Self>(&env, admin, role, account)
+    }
+
+    /// Revoke a role from an account.
+    ///
+    /// CHECKS:
+    /// - Contract must be initialized
+    /// - Caller must be the admin and authorize
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), EscrowError> {
+        access::revoke_role::<Self>(&env, admin, role, account)
+    }
+
+    /// Check whether an account holds a role. The admin holds every role.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access::has_role::<Self>(&env, role, account)
+    }
+
+    /// Pause one group of operations. Pausing an already paused
+    /// operation is a no-op.
+    ///
+    /// CHECKS:
+    /// - Caller must be the admin, a pauser or a guardian and authorize
+    ///
+    /// EFFECTS:
+    /// - Sets the operation's pause flag and emits `op_paused`
+    pub fn pause(env: Env, caller: Address, operation: Operation) -> Result<(), EscrowError> {
+        if access::pause::<Self, _>(&env, &caller, operation.clone())? {
+            events::operation_paused(&env, operation, caller);
+        }
+
+        Ok(())
+    }
+
+    /// Resume a paused operation. Guardians cannot unpause.
+    ///
+    /// CHECKS:
+    /// - Contract must be initialized
+    /// - Caller must be the admin or a pauser and authorize
+    ///
+    /// EFFECTS:
+    /// - Clears the operation's pause flag and emits `op_unpaused`
+    pub fn unpause(env: Env, caller: Address, operation: Operation) -> Result<(), EscrowError> {
+        if access::unpause::<Self, _>(&env, &caller, operation.clone())? {
+            events::operation_unpaused(&env, operation, caller);
+        }
+
+        Ok(())
+    }
+
+    /// Check whether an operation is paused.
+    pub fn is_paused(env: Env, operation: Operation) -> bool {
+        access::is_paused(&env, operation)
+    }
 }
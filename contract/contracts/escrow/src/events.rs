@@ -0,0 +1,62 @@
+//! Events emitted by the Escrow contract.
+use soroban_sdk::{contractevent, Address, BytesN, Env};
+
+use crate::types::Operation;
+
+/// Emitted when an operation is paused.
+/// Topics: ["op_paused", operation: Operation]
+#[contractevent(topics = ["op_paused"])]
+pub struct OperationPaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+/// Emitted when a paused operation is resumed.
+/// Topics: ["op_unpaused", operation: Operation]
+#[contractevent(topics = ["op_unpaused"])]
+pub struct OperationUnpaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+/// Emitted when the contract code is upgraded.
+/// Topics: ["upgraded", admin: Address]
+#[contractevent(topics = ["upgraded"])]
+pub struct ContractUpgraded {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Emitted when stored data is migrated to a newer schema version.
+/// Topics: ["migrated", admin: Address]
+#[contractevent(topics = ["migrated"])]
+pub struct ContractMigrated {
+    #[topic]
+    pub admin: Address,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+pub(crate) fn operation_paused(env: &Env, operation: Operation, by: Address) {
+    OperationPaused { operation, by }.publish(env);
+}
+
+pub(crate) fn operation_unpaused(env: &Env, operation: Operation, by: Address) {
+    OperationUnpaused { operation, by }.publish(env);
+}
+
+pub(crate) fn contract_upgraded(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    ContractUpgraded { admin, wasm_hash }.publish(env);
+}
+
+pub(crate) fn contract_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    ContractMigrated {
+        admin,
+        from_version,
+        to_version,
+    }
+    .publish(env);
+}
@@ -10,6 +10,7 @@ pub mod access;
 pub mod dispute;
 pub mod errors;
 pub mod escrow_impl;
+pub mod events;
 pub mod storage;
 pub mod types;
 
@@ -20,6 +21,6 @@ mod tests;
 pub use access::AccessControl;
 pub use dispute::DisputeHandler;
 pub use errors::EscrowError;
-pub use escrow_impl::EscrowContract;
+pub use escrow_impl::{EscrowContract, SCHEMA_VERSION};
 pub use storage::EscrowStorage;
-pub use types::{DataKey, Escrow, EscrowStatus, ReleaseApproval};
+pub use types::{DataKey, Escrow, EscrowStatus, Operation, ReleaseApproval};
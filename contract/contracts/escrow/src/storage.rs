@@ -127,4 +127,31 @@ impl EscrowStorage {
             .instance()
             .set(&DataKey::EscrowCount, &(count + 1));
     }
+
+    /// Retrieve the contract admin.
+    /// Returns None if the contract hasn't been initialized.
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get::<_, Address>(&DataKey::Admin)
+    }
+
+    /// Set the contract admin.
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the schema version of the stored data.
+    /// Returns 0 for data written before schema versioning.
+    pub fn get_schema_version(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get::<_, u32>(&DataKey::SchemaVersion)
+            .unwrap_or_default()
+    }
+
+    /// Record the schema version of the stored data.
+    pub fn set_schema_version(env: &Env, version: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &version);
+    }
 }
@@ -1,12 +1,14 @@
 //! Tests for the Escrow contract.
 
-use soroban_sdk::testutils::{Address as _, Ledger};
+use chioma_types::Role;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
 use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Val, Vec};
 
-use crate::escrow_impl::{EscrowContract, EscrowContractClient};
-use crate::types::EscrowStatus;
+use crate::errors::EscrowError;
+use crate::escrow_impl::{EscrowContract, EscrowContractClient, SCHEMA_VERSION};
+use crate::types::{EscrowStatus, Operation};
 
 fn setup_test(env: &Env) -> (EscrowContractClient<'_>, Address, Address, Address, Address) {
     let contract_id = env.register(EscrowContract, ());
@@ -244,3 +246,195 @@ fn test_approval_count_tracks_per_target() {
     let token_client = TokenClient::new(&env, &token_address);
     assert_eq!(token_client.balance(&beneficiary), amount);
 }
+
+#[test]
+fn test_dispute_resolution_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+
+    let token_admin = TokenAdminClient::new(&env, &token_address);
+    token_admin.mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let reason = soroban_sdk::String::from_str(&env, "Partial damage");
+    client.initiate_dispute(&escrow_id, &depositor, &reason);
+
+    // 30% to beneficiary, remainder back to depositor
+    client.resolve_dispute_split(&escrow_id, &arbiter, &3000);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.dispute_reason, None);
+
+    let token_client = TokenClient::new(&env, &token_address);
+    assert_eq!(token_client.balance(&beneficiary), 300);
+    assert_eq!(token_client.balance(&depositor), 700);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_dispute_resolution_split_rejects_invalid_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+    let amount = 1000i128;
+
+    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+
+    let token_admin = TokenAdminClient::new(&env, &token_address);
+    token_admin.mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    let reason = soroban_sdk::String::from_str(&env, "Partial damage");
+    client.initiate_dispute(&escrow_id, &depositor, &reason);
+
+    let result = client.try_resolve_dispute_split(&escrow_id, &arbiter, &10_001);
+    assert_eq!(result, Err(Ok(crate::errors::EscrowError::InvalidSplit)));
+
+    // Only the arbiter may split
+    let result = client.try_resolve_dispute_split(&escrow_id, &depositor, &5000);
+    assert_eq!(result, Err(Ok(crate::errors::EscrowError::NotAuthorized)));
+}
+
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, depositor, _, _, _) = setup_test(&env);
+
+    let admin = Address::generate(&env);
+    let wasm_hash = soroban_sdk::BytesN::from_array(&env, &[9; 32]);
+
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(
+        client.try_migrate(&admin),
+        Err(Ok(EscrowError::NotInitialized))
+    );
+
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(EscrowError::AlreadyInitialized))
+    );
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+
+    // Data from before schema versioning is migrated and announced
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .remove(&crate::types::DataKey::SchemaVersion);
+    });
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    let event = env.events().all().last().unwrap();
+    let topics: Vec<Val> = (Symbol::new(&env, "migrated"), admin.clone()).into_val(&env);
+    assert_eq!(event.0, client.address);
+    assert_eq!(event.1, topics);
+
+    assert_eq!(
+        client.try_migrate(&depositor),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_upgrade(&depositor, &wasm_hash),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+}
+
+#[test]
+fn test_admin_transfer_requires_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, depositor, _, _, _) = setup_test(&env);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(EscrowError::NoPendingAdmin))
+    );
+    assert_eq!(
+        client.try_propose_admin(&depositor, &new_admin),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_admin(), Some(admin.clone()));
+
+    assert_eq!(
+        client.try_accept_admin(&depositor),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_migrate(&admin),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+}
+
+#[test]
+fn test_guardian_can_pause_releases_but_not_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(&env);
+
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let amount = 1000i128;
+
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.try_pause(&guardian, &Operation::Releases),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_grant_role(&admin, &Role::Verifier, &guardian),
+        Err(Ok(EscrowError::InvalidRole))
+    );
+    client.grant_role(&admin, &Role::Guardian, &guardian);
+    assert!(client.has_role(&Role::Guardian, &guardian));
+
+    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    TokenAdminClient::new(&env, &token_address).mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+    client.approve_release(&escrow_id, &depositor, &beneficiary);
+
+    client.pause(&guardian, &Operation::Releases);
+    assert!(client.is_paused(&Operation::Releases));
+    assert!(!client.is_paused(&Operation::Deposits));
+    assert_eq!(
+        client.try_approve_release(&escrow_id, &arbiter, &beneficiary),
+        Err(Ok(EscrowError::OperationPaused))
+    );
+    assert_eq!(
+        client.try_unpause(&guardian, &Operation::Releases),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+
+    // A pauser can recover without the admin key
+    let pauser = Address::generate(&env);
+    client.grant_role(&admin, &Role::Pauser, &pauser);
+    client.unpause(&pauser, &Operation::Releases);
+    assert!(!client.is_paused(&Operation::Releases));
+    client.revoke_role(&admin, &Role::Guardian, &guardian);
+    assert_eq!(
+        client.try_pause(&guardian, &Operation::Releases),
+        Err(Ok(EscrowError::NotAuthorized))
+    );
+    client.approve_release(&escrow_id, &arbiter, &beneficiary);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Released);
+}
@@ -1,45 +1,7 @@
 //! Data structures and enums for the Escrow contract.
-use soroban_sdk::{contracttype, Address, BytesN, String};
+use soroban_sdk::{contracttype, Address, BytesN};
 
-/// Status of an escrow throughout its lifecycle.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-#[contracttype]
-pub enum EscrowStatus {
-    /// Initial state after creation, not yet funded
-    Pending = 0,
-    /// Funds have been deposited into escrow
-    Funded = 1,
-    /// Funds have been released to the beneficiary
-    Released = 2,
-    /// Funds have been refunded to the depositor
-    Refunded = 3,
-    /// Under dispute, awaiting admin resolution
-    Disputed = 4,
-}
-
-/// Represents a security deposit escrow managed by 2-of-3 multi-sig.
-#[derive(Clone, Debug, PartialEq, Eq)]
-#[contracttype]
-pub struct Escrow {
-    /// Unique identifier for the escrow (hash of agreement_id)
-    pub id: BytesN<32>,
-    /// The party depositing funds (tenant)
-    pub depositor: Address,
-    /// The party who benefits from the deposit (landlord)
-    pub beneficiary: Address,
-    /// The admin/arbiter who can resolve disputes
-    pub arbiter: Address,
-    /// Amount of funds in the escrow
-    pub amount: i128,
-    /// Token contract address (USDC, XLM, etc.)
-    pub token: Address,
-    /// Current status of the escrow
-    pub status: EscrowStatus,
-    /// Timestamp when escrow was created
-    pub created_at: u64,
-    /// Reason for dispute, if any
-    pub dispute_reason: Option<String>,
-}
+pub use chioma_types::{Escrow, EscrowStatus};
 
 /// Records approval of fund release by a single party.
 #[derive(Clone, Debug)]
@@ -53,6 +15,19 @@ pub struct ReleaseApproval {
     pub timestamp: u64,
 }
 
+/// A group of escrow calls that can be paused on its own.
+/// Reads and admin calls are never paused.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum Operation {
+    /// Creating and funding escrows
+    Deposits = 0,
+    /// Releasing funds by approval or dispute resolution
+    Releases = 1,
+    /// Raising disputes
+    Disputes = 2,
+}
+
 /// Storage key variants for persistent storage.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -69,4 +44,8 @@ pub enum DataKey {
     ApprovalCount(BytesN<32>, Address),
     /// Per-signer-per-target flag: DataKey::SignerApproved(escrow_id, signer, release_to) => bool
     SignerApproved(BytesN<32>, Address, Address),
+    /// Address allowed to upgrade and migrate the contract
+    Admin,
+    /// Schema version of the stored data
+    SchemaVersion,
 }
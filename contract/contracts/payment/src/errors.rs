@@ -1,4 +1,5 @@
 //! Custom error types for the Payment contract.
+use chioma_types::AccessError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -21,4 +22,34 @@ pub enum PaymentError {
     PaymentNotDue = 18,
     /// Invalid amount provided
     InvalidAmount = 5,
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not the admin
+    Unauthorized = 19,
+    /// No admin transfer has been proposed
+    NoPendingAdmin = 20,
+    /// Role cannot be granted on this contract
+    InvalidRole = 21,
+    /// Operation is paused
+    OperationPaused = 22,
+    /// No fee collector change is scheduled
+    NoScheduledChange = 23,
+    /// The scheduled change's timelock has not passed yet
+    TimelockNotElapsed = 24,
+    /// The scheduled change was not executed in time and must be rescheduled
+    ChangeExpired = 25,
+}
+
+impl From<AccessError> for PaymentError {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => PaymentError::NotInitialized,
+            AccessError::Unauthorized => PaymentError::Unauthorized,
+            AccessError::InvalidRole => PaymentError::InvalidRole,
+            AccessError::NoPendingAdmin => PaymentError::NoPendingAdmin,
+            AccessError::OperationPaused => PaymentError::OperationPaused,
+        }
+    }
 }
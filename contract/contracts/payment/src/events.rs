@@ -0,0 +1,118 @@
+//! Events emitted by the Payment contract.
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
+
+use crate::types::{FeeCollectorChange, Operation};
+
+/// Emitted when an operation is paused
+#[contractevent(topics = ["op_paused"])]
+pub struct OperationPaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+/// Emitted when a paused operation is resumed
+#[contractevent(topics = ["op_unpaused"])]
+pub struct OperationUnpaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+/// Emitted when the contract code is upgraded
+#[contractevent(topics = ["upgraded"])]
+pub struct ContractUpgraded {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Emitted when stored data is migrated to a newer schema version
+#[contractevent(topics = ["migrated"])]
+pub struct ContractMigrated {
+    #[topic]
+    pub admin: Address,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+/// Emitted when an agreement's inline payment history is moved into
+/// per-month entries
+#[contractevent(topics = ["history_migrated"])]
+pub struct PaymentHistoryMigrated {
+    pub agreement_id: String,
+    pub payments: u32,
+}
+
+/// Emitted when a new fee collector is queued
+#[contractevent(topics = ["fee_scheduled"])]
+pub struct FeeCollectorChangeScheduled {
+    #[topic]
+    pub proposed_by: Address,
+    pub collector: Address,
+    pub executable_at: u64,
+}
+
+/// Emitted when a queued fee collector takes effect
+#[contractevent(topics = ["fee_executed"])]
+pub struct FeeCollectorChangeExecuted {
+    pub collector: Address,
+}
+
+/// Emitted when a queued fee collector change is dropped
+#[contractevent(topics = ["fee_cancelled"])]
+pub struct FeeCollectorChangeCancelled {
+    #[topic]
+    pub cancelled_by: Address,
+    pub collector: Address,
+}
+
+pub(crate) fn fee_collector_change_scheduled(env: &Env, change: &FeeCollectorChange) {
+    FeeCollectorChangeScheduled {
+        proposed_by: change.proposed_by.clone(),
+        collector: change.collector.clone(),
+        executable_at: change.executable_at,
+    }
+    .publish(env);
+}
+
+pub(crate) fn fee_collector_change_executed(env: &Env, collector: Address) {
+    FeeCollectorChangeExecuted { collector }.publish(env);
+}
+
+pub(crate) fn fee_collector_change_cancelled(env: &Env, cancelled_by: Address, collector: Address) {
+    FeeCollectorChangeCancelled {
+        cancelled_by,
+        collector,
+    }
+    .publish(env);
+}
+
+pub(crate) fn contract_upgraded(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    ContractUpgraded { admin, wasm_hash }.publish(env);
+}
+
+pub(crate) fn contract_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    ContractMigrated {
+        admin,
+        from_version,
+        to_version,
+    }
+    .publish(env);
+}
+
+pub(crate) fn operation_paused(env: &Env, operation: Operation, by: Address) {
+    OperationPaused { operation, by }.publish(env);
+}
+
+pub(crate) fn operation_unpaused(env: &Env, operation: Operation, by: Address) {
+    OperationUnpaused { operation, by }.publish(env);
+}
+
+pub(crate) fn payment_history_migrated(env: &Env, agreement_id: String, payments: u32) {
+    PaymentHistoryMigrated {
+        agreement_id,
+        payments,
+    }
+    .publish(env);
+}
@@ -6,9 +6,11 @@
 //! Handles rent payment processing with automatic commission splitting
 //! and payment record management.
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use chioma_types::access::{self, AccessControl};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, String};
 
 pub mod errors;
+pub mod events;
 pub mod payment_impl;
 pub mod storage;
 pub mod types;
@@ -20,23 +22,253 @@ mod tests;
 pub use errors::PaymentError;
 pub use payment_impl::{calculate_payment_split, create_payment_record};
 pub use storage::DataKey;
-pub use types::{PaymentRecord, PaymentSplit};
+pub use types::{FeeCollectorChange, Operation, PaymentRecord, PaymentSplit, Role};
 
 use crate::errors::PaymentError as Error;
 use crate::storage::DataKey as StorageKey;
-use crate::types::{AgreementStatus, RentAgreement};
+use crate::types::{AgreementStatus, LegacyRentAgreement, RentAgreement};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Seconds a new fee collector must wait before it can be put in place
+pub const FEE_COLLECTOR_TIMELOCK: u64 = 7 * 86400;
+
+/// Seconds after `executable_at` during which a queued fee collector change
+/// can still be executed
+pub const FEE_COLLECTOR_EXECUTION_WINDOW: u64 = 7 * 86400;
+
+pub const SCHEMA_VERSION: u32 = 1;
 
 #[contract]
 pub struct PaymentContract;
 
+impl AccessControl for PaymentContract {
+    type Error = Error;
+
+    fn admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&StorageKey::Admin)
+    }
+
+    fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&StorageKey::Admin, admin);
+    }
+
+    fn is_grantable(role: &Role) -> bool {
+        matches!(role, Role::FeeManager | Role::Pauser | Role::Guardian)
+    }
+}
+
 #[contractimpl]
 impl PaymentContract {
-    /// Sets the platform fee collector address
-    pub fn set_platform_fee_collector(env: Env, collector: Address) {
-        collector.require_auth();
+    /// Initialize the contract with an admin and the first platform fee
+    /// collector. Later collectors go through the timelocked
+    /// `schedule_fee_collector_change`.
+    pub fn initialize(env: Env, admin: Address, fee_collector: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&StorageKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        env.storage().instance().set(&StorageKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&StorageKey::PlatformFeeCollector, &fee_collector);
+        env.storage()
+            .instance()
+            .set(&StorageKey::SchemaVersion, &SCHEMA_VERSION);
+
+        Ok(())
+    }
+
+    /// Replace the contract's code with a previously uploaded WASM (admin only).
+    /// Storage is kept; the admin may be a multisig account.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        access::require_admin::<Self>(&env, &admin)?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        events::contract_upgraded(&env, admin, new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Bring stored data up to `SCHEMA_VERSION` after an upgrade (admin only).
+    /// Returns the schema version after migrating.
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, Error> {
+        access::require_admin::<Self>(&env, &admin)?;
+
+        let from_version = Self::get_schema_version(env.clone());
+        if from_version >= SCHEMA_VERSION {
+            return Ok(from_version);
+        }
+
+        // Agreements with inline payment history are moved one at a time by
+        // `migrate_payment_history`.
+
+        env.storage()
+            .instance()
+            .set(&StorageKey::SchemaVersion, &SCHEMA_VERSION);
+        events::contract_migrated(&env, admin, from_version, SCHEMA_VERSION);
+
+        Ok(SCHEMA_VERSION)
+    }
+
+    /// Get the schema version of the stored data, 0 if written before versioning
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::SchemaVersion)
+            .unwrap_or(0)
+    }
+
+    /// Propose a new admin (admin only). Takes effect once the nominee
+    /// calls `accept_admin`; a later proposal replaces this one.
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), Error> {
+        access::propose_admin::<Self>(&env, admin, new_admin)
+    }
+
+    /// Accept a pending admin proposal (nominee only)
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::accept_admin::<Self>(&env, new_admin)
+    }
+
+    /// Get the current admin, if initialized
+    pub fn get_admin(env: Env) -> Option<Address> {
+        <Self as AccessControl>::admin(&env)
+    }
+
+    /// Get the proposed admin awaiting acceptance, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        access::get_pending_admin(&env)
+    }
+
+    /// Grant a role to an account (admin only). Only `FeeManager`, `Pauser`
+    /// and `Guardian` can be granted on this contract.
+    pub fn grant_role(env: Env, admin: Address, role: Role, account: Address) -> Result<(), Error> {
+        access::grant_role::<Self>(&env, admin, role, account)
+    }
+
+    /// Revoke a role from an account (admin only)
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), Error> {
+        access::revoke_role::<Self>(&env, admin, role, account)
+    }
+
+    /// Check whether an account holds a role. The admin holds every role.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access::has_role::<Self>(&env, role, account)
+    }
+
+    /// Pause one group of calls (admin, pauser or guardian)
+    pub fn pause(env: Env, caller: Address, operation: Operation) -> Result<(), Error> {
+        if access::pause::<Self, _>(&env, &caller, operation.clone())? {
+            events::operation_paused(&env, operation, caller);
+        }
+
+        Ok(())
+    }
+
+    /// Resume a paused group of calls (admin or pauser). Guardians can't unpause.
+    pub fn unpause(env: Env, caller: Address, operation: Operation) -> Result<(), Error> {
+        if access::unpause::<Self, _>(&env, &caller, operation.clone())? {
+            events::operation_unpaused(&env, operation, caller);
+        }
+
+        Ok(())
+    }
+
+    /// Check whether a group of calls is paused
+    pub fn is_paused(env: Env, operation: Operation) -> bool {
+        access::is_paused(&env, operation)
+    }
+
+    /// Queue a new platform fee collector (admin or fee manager). It takes
+    /// effect once `FEE_COLLECTOR_TIMELOCK` has passed and someone calls
+    /// `execute_fee_collector_change`, which must happen within
+    /// `FEE_COLLECTOR_EXECUTION_WINDOW` after that. Replaces any change
+    /// already queued, so an older change can never run after a newer one.
+    pub fn schedule_fee_collector_change(
+        env: Env,
+        caller: Address,
+        collector: Address,
+    ) -> Result<(), Error> {
+        access::require_role::<Self>(&env, &caller, Role::FeeManager)?;
+
+        let now = env.ledger().timestamp();
+        let change = FeeCollectorChange {
+            collector,
+            proposed_by: caller,
+            scheduled_at: now,
+            executable_at: now + FEE_COLLECTOR_TIMELOCK,
+        };
+        env.storage()
+            .instance()
+            .set(&StorageKey::FeeCollectorChange, &change);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+        events::fee_collector_change_scheduled(&env, &change);
+
+        Ok(())
+    }
+
+    /// Drop the queued fee collector change (admin or fee manager)
+    pub fn cancel_fee_collector_change(env: Env, caller: Address) -> Result<(), Error> {
+        access::require_role::<Self>(&env, &caller, Role::FeeManager)?;
+
+        let change = Self::get_fee_collector_change(env.clone()).ok_or(Error::NoScheduledChange)?;
         env.storage()
             .instance()
-            .set(&StorageKey::PlatformFeeCollector, &collector);
+            .remove(&StorageKey::FeeCollectorChange);
+
+        events::fee_collector_change_cancelled(&env, caller, change.collector);
+
+        Ok(())
+    }
+
+    /// Put the queued fee collector in place once its timelock has passed
+    /// and before its execution window closes. Anyone may call this.
+    pub fn execute_fee_collector_change(env: Env) -> Result<(), Error> {
+        let change = Self::get_fee_collector_change(env.clone()).ok_or(Error::NoScheduledChange)?;
+
+        let now = env.ledger().timestamp();
+        if now < change.executable_at {
+            return Err(Error::TimelockNotElapsed);
+        }
+        if now > change.executable_at + FEE_COLLECTOR_EXECUTION_WINDOW {
+            return Err(Error::ChangeExpired);
+        }
+
+        env.storage()
+            .instance()
+            .set(&StorageKey::PlatformFeeCollector, &change.collector);
+        env.storage()
+            .instance()
+            .remove(&StorageKey::FeeCollectorChange);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+        events::fee_collector_change_executed(&env, change.collector);
+
+        Ok(())
+    }
+
+    /// Get the queued fee collector change, if any
+    pub fn get_fee_collector_change(env: Env) -> Option<FeeCollectorChange> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::FeeCollectorChange)
+    }
+
+    /// Get the address that receives the platform's share of rent, if set
+    pub fn get_platform_fee_collector(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::PlatformFeeCollector)
     }
 
     /// Get a payment record by ID
@@ -108,15 +340,13 @@ impl PaymentContract {
     ) -> Result<(), Error> {
         use soroban_sdk::token;
 
+        access::require_not_paused::<Self, _>(&env, Operation::Payments)?;
+
         // Authorization
         from.require_auth();
 
         // Load agreement
-        let mut agreement: RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id.clone()))
-            .ok_or(Error::AgreementNotFound)?;
+        let mut agreement = Self::load_agreement(&env, &agreement_id)?;
 
         // Validation
         if agreement.status != AgreementStatus::Active {
@@ -151,16 +381,22 @@ impl PaymentContract {
             .ok_or(Error::PaymentFailed)?;
 
         // Effects: Update state BEFORE external calls
-        let payment_month = agreement.payment_history.len();
-        agreement.payment_history.set(
+        Self::drain_legacy_history(&env, &mut agreement);
+        let payment_month = agreement.payment_count;
+        Self::store_split(
+            &env,
+            &agreement_id,
             payment_month,
-            PaymentSplit {
+            &PaymentSplit {
                 landlord_amount,
                 platform_amount,
                 token: agreement.payment_token.clone(),
                 payment_date: current_time,
+                payer: from.clone(),
             },
         );
+        agreement.payment_count += 1;
+        agreement.total_rent_paid += payment_amount;
         agreement.next_payment_due = current_time + 2_592_000; // 30 days
 
         env.storage()
@@ -181,15 +417,78 @@ impl PaymentContract {
         agreement_id: String,
         month: u32,
     ) -> Result<PaymentSplit, Error> {
-        let agreement: RentAgreement = env
+        if let Some(split) = env
             .storage()
             .persistent()
-            .get(&StorageKey::Agreement(agreement_id))
-            .ok_or(Error::AgreementNotFound)?;
+            .get(&StorageKey::PaymentSplit(agreement_id.clone(), month))
+        {
+            return Ok(split);
+        }
+
+        // Agreements not yet migrated still hold their history inline
+        let agreement = Self::load_agreement(&env, &agreement_id)?;
 
         agreement
             .payment_history
             .get(month)
             .ok_or(Error::PaymentNotFound)
     }
+
+    /// Move payment history stored inline on an agreement into per-month
+    /// entries. Anyone may call this; it is a no-op once the agreement has
+    /// been migrated. Returns the number of payments moved.
+    pub fn migrate_payment_history(env: Env, agreement_id: String) -> Result<u32, Error> {
+        let mut agreement = Self::load_agreement(&env, &agreement_id)?;
+
+        let moved = Self::drain_legacy_history(&env, &mut agreement);
+        if moved > 0 {
+            let key = StorageKey::Agreement(agreement_id.clone());
+            env.storage().persistent().set(&key, &agreement);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+            events::payment_history_migrated(&env, agreement_id, moved);
+        }
+
+        Ok(moved)
+    }
+
+    /// Load an agreement, including one whose inline history was written
+    /// before splits recorded the payer
+    fn load_agreement(env: &Env, agreement_id: &String) -> Result<RentAgreement, Error> {
+        let stored: LegacyRentAgreement = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Agreement(agreement_id.clone()))
+            .ok_or(Error::AgreementNotFound)?;
+
+        Ok(stored.into_agreement(env))
+    }
+
+    fn store_split(env: &Env, agreement_id: &String, month: u32, split: &PaymentSplit) {
+        let key = StorageKey::PaymentSplit(agreement_id.clone(), month);
+        env.storage().persistent().set(&key, split);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// Move inline history into per-month entries and fold it into the
+    /// agreement's totals, which earlier versions did not maintain
+    fn drain_legacy_history(env: &Env, agreement: &mut RentAgreement) -> u32 {
+        let moved = agreement.payment_history.len();
+        if moved == 0 {
+            return 0;
+        }
+
+        for (month, split) in agreement.payment_history.iter() {
+            agreement.total_rent_paid += split.landlord_amount + split.platform_amount;
+            Self::store_split(env, &agreement.agreement_id, month, &split);
+        }
+        agreement.payment_count = agreement.payment_count.max(moved);
+        agreement.payment_history = Map::new(env);
+
+        moved
+    }
 }
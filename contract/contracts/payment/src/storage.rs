@@ -13,6 +13,14 @@ pub enum DataKey {
     PaymentCount,
     /// Platform fee collector address
     PlatformFeeCollector,
+    /// Fee collector change waiting out the timelock
+    FeeCollectorChange,
     /// Agreement storage (for reading agreement data)
     Agreement(String),
+    /// Split of a rent payment by agreement ID and month
+    PaymentSplit(String, u32),
+    /// Address allowed to upgrade and migrate the contract
+    Admin,
+    /// Schema version of the stored data
+    SchemaVersion,
 }
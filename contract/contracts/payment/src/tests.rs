@@ -3,7 +3,10 @@
 use crate::payment_impl::*;
 use crate::types::*;
 use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
-use soroban_sdk::{testutils::Address as _, Address, Env, Map, String};
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Events, Ledger},
+    Address, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec,
+};
 
 // Helper function to create a test agreement
 fn create_test_agreement(
@@ -146,3 +149,315 @@ fn test_agreement_with_agent() {
     assert_eq!(agreement.agent, Some(agent));
     assert_eq!(agreement.agent_commission_rate, 500);
 }
+
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(crate::PaymentContract, ());
+    let client = crate::PaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let wasm_hash = BytesN::from_array(&env, &[9; 32]);
+
+    assert_eq!(
+        client.try_migrate(&admin),
+        Err(Ok(crate::PaymentError::NotInitialized))
+    );
+
+    client.initialize(&admin, &Address::generate(&env));
+    assert_eq!(
+        client.try_initialize(&admin, &Address::generate(&env)),
+        Err(Ok(crate::PaymentError::AlreadyInitialized))
+    );
+    assert_eq!(client.get_schema_version(), crate::SCHEMA_VERSION);
+    assert_eq!(client.migrate(&admin), crate::SCHEMA_VERSION);
+
+    // Data from before schema versioning is migrated and announced
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .remove(&crate::DataKey::SchemaVersion);
+    });
+    assert_eq!(client.migrate(&admin), crate::SCHEMA_VERSION);
+    let event = env.events().all().last().unwrap();
+    let topics: Vec<Val> = (Symbol::new(&env, "migrated"), admin.clone()).into_val(&env);
+    assert_eq!(event.0, contract_id);
+    assert_eq!(event.1, topics);
+
+    assert_eq!(
+        client.try_migrate(&outsider),
+        Err(Ok(crate::PaymentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_upgrade(&outsider, &wasm_hash),
+        Err(Ok(crate::PaymentError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_admin_transfer_and_fee_manager_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(crate::PaymentContract, ());
+    let client = crate::PaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let fee_manager = Address::generate(&env);
+    let collector = Address::generate(&env);
+
+    client.initialize(&admin, &Address::generate(&env));
+
+    // Fee collector changes need the admin or a fee manager
+    assert_eq!(
+        client.try_schedule_fee_collector_change(&fee_manager, &collector),
+        Err(Ok(crate::PaymentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_grant_role(&admin, &Role::Verifier, &fee_manager),
+        Err(Ok(crate::PaymentError::InvalidRole))
+    );
+    client.grant_role(&admin, &Role::FeeManager, &fee_manager);
+    assert!(client.has_role(&Role::FeeManager, &fee_manager));
+    client.schedule_fee_collector_change(&fee_manager, &collector);
+
+    // A fee manager can't act as admin
+    assert_eq!(
+        client.try_propose_admin(&fee_manager, &fee_manager),
+        Err(Ok(crate::PaymentError::Unauthorized))
+    );
+
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(crate::PaymentError::NoPendingAdmin))
+    );
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(
+        client.try_accept_admin(&collector),
+        Err(Ok(crate::PaymentError::Unauthorized))
+    );
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_pending_admin(), None);
+
+    client.revoke_role(&new_admin, &Role::FeeManager, &fee_manager);
+    assert!(!client.has_role(&Role::FeeManager, &fee_manager));
+    assert_eq!(
+        client.try_cancel_fee_collector_change(&fee_manager),
+        Err(Ok(crate::PaymentError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_fee_collector_change_waits_for_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(crate::PaymentContract, ());
+    let client = crate::PaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let initial = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    // The first collector is set directly
+    client.initialize(&admin, &initial);
+    assert_eq!(client.get_platform_fee_collector(), Some(initial.clone()));
+    assert_eq!(
+        client.try_execute_fee_collector_change(),
+        Err(Ok(crate::PaymentError::NoScheduledChange))
+    );
+
+    client.schedule_fee_collector_change(&admin, &first);
+    let change = client.get_fee_collector_change().unwrap();
+    assert_eq!(change.collector, first);
+    assert_eq!(
+        change.executable_at,
+        change.scheduled_at + crate::FEE_COLLECTOR_TIMELOCK
+    );
+
+    // Nothing changes until the delay has passed
+    assert_eq!(
+        client.try_execute_fee_collector_change(),
+        Err(Ok(crate::PaymentError::TimelockNotElapsed))
+    );
+    assert_eq!(client.get_platform_fee_collector(), Some(initial));
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = change.executable_at);
+    client.execute_fee_collector_change();
+    assert_eq!(client.get_platform_fee_collector(), Some(first.clone()));
+    assert_eq!(client.get_fee_collector_change(), None);
+
+    // A cancelled change never takes effect
+    client.schedule_fee_collector_change(&admin, &second);
+    client.cancel_fee_collector_change(&admin);
+    assert_eq!(
+        client.try_cancel_fee_collector_change(&admin),
+        Err(Ok(crate::PaymentError::NoScheduledChange))
+    );
+    env.ledger()
+        .with_mut(|l| l.timestamp += crate::FEE_COLLECTOR_TIMELOCK);
+    assert_eq!(
+        client.try_execute_fee_collector_change(),
+        Err(Ok(crate::PaymentError::NoScheduledChange))
+    );
+    assert_eq!(client.get_platform_fee_collector(), Some(first));
+}
+
+#[test]
+fn test_fee_collector_change_expires_after_execution_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(crate::PaymentContract, ());
+    let client = crate::PaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let stale = Address::generate(&env);
+    let fresh = Address::generate(&env);
+    let initial = Address::generate(&env);
+
+    client.initialize(&admin, &initial);
+    client.schedule_fee_collector_change(&admin, &stale);
+    let change = client.get_fee_collector_change().unwrap();
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = change.executable_at + crate::FEE_COLLECTOR_EXECUTION_WINDOW + 1;
+    });
+    assert_eq!(
+        client.try_execute_fee_collector_change(),
+        Err(Ok(crate::PaymentError::ChangeExpired))
+    );
+    assert_eq!(client.get_platform_fee_collector(), Some(initial));
+
+    // Rescheduling gives fresh notice, and the last moment of the window counts
+    client.schedule_fee_collector_change(&admin, &fresh);
+    let change = client.get_fee_collector_change().unwrap();
+    env.ledger().with_mut(|l| {
+        l.timestamp = change.executable_at + crate::FEE_COLLECTOR_EXECUTION_WINDOW;
+    });
+    client.execute_fee_collector_change();
+    assert_eq!(client.get_platform_fee_collector(), Some(fresh));
+}
+
+#[test]
+fn test_guardian_can_pause_payments_but_not_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(crate::PaymentContract, ());
+    let client = crate::PaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let tenant = Address::generate(&env);
+
+    client.initialize(&admin, &Address::generate(&env));
+
+    assert_eq!(
+        client.try_pause(&guardian, &Operation::Payments),
+        Err(Ok(crate::PaymentError::Unauthorized))
+    );
+    client.grant_role(&admin, &Role::Guardian, &guardian);
+    client.pause(&guardian, &Operation::Payments);
+    assert!(client.is_paused(&Operation::Payments));
+
+    assert_eq!(
+        client.try_pay_rent(&tenant, &String::from_str(&env, "AGR_001"), &1000),
+        Err(Ok(crate::PaymentError::OperationPaused))
+    );
+
+    assert_eq!(
+        client.try_unpause(&guardian, &Operation::Payments),
+        Err(Ok(crate::PaymentError::Unauthorized))
+    );
+    client.unpause(&admin, &Operation::Payments);
+    assert!(!client.is_paused(&Operation::Payments));
+}
+
+#[test]
+fn test_migrate_payment_history_from_first_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(crate::PaymentContract, ());
+    let client = crate::PaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let token = create_token(&env, &admin);
+    client.initialize(&admin, &Address::generate(&env));
+
+    // The first version kept payerless splits inline and no running totals
+    let agreement_id = String::from_str(&env, "AGR_LEGACY");
+    let mut payment_history = Map::new(&env);
+    for month in 0..2u32 {
+        payment_history.set(
+            month,
+            LegacyPaymentSplit {
+                landlord_amount: 900,
+                platform_amount: 100,
+                token: token.clone(),
+                payment_date: 100 + month as u64,
+            },
+        );
+    }
+    let legacy = LegacyRentAgreement {
+        agreement_id: agreement_id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        monthly_rent: 1000,
+        security_deposit: 0,
+        start_date: 0,
+        end_date: 0,
+        agent_commission_rate: 0,
+        status: AgreementStatus::Active,
+        total_rent_paid: 0,
+        payment_count: 0,
+        signed_at: None,
+        payment_token: token.clone(),
+        next_payment_due: 0,
+        payment_history,
+    };
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::Agreement(agreement_id.clone()), &legacy);
+    });
+
+    // Unmigrated history is readable, with the tenant as payer
+    let split = client.get_payment_split(&agreement_id, &1);
+    assert_eq!(split.payment_date, 101);
+    assert_eq!(split.payer, tenant);
+
+    assert_eq!(client.migrate_payment_history(&agreement_id), 2);
+    let event = env.events().all().last().unwrap();
+    let topics: Vec<Val> = (Symbol::new(&env, "history_migrated"),).into_val(&env);
+    assert_eq!(event.0, contract_id);
+    assert_eq!(event.1, topics);
+    assert_eq!(client.migrate_payment_history(&agreement_id), 0);
+
+    env.as_contract(&contract_id, || {
+        let agreement: RentAgreement = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Agreement(agreement_id.clone()))
+            .unwrap();
+        assert!(agreement.payment_history.is_empty());
+        assert_eq!(agreement.payment_count, 2);
+        assert_eq!(agreement.total_rent_paid, 2000);
+
+        let key = crate::DataKey::PaymentSplit(agreement_id.clone(), 0);
+        assert!(env.storage().persistent().get_ttl(&key) >= 500000);
+    });
+    assert_eq!(client.get_payment_split(&agreement_id, &0).payer, tenant);
+
+    // Payments continue after the migrated months
+    TokenAdminClient::new(&env, &token).mint(&tenant, &1000);
+    env.ledger().with_mut(|li| li.timestamp = 1);
+    client.pay_rent(&tenant, &agreement_id, &1000);
+    assert_eq!(client.get_payment_split(&agreement_id, &2).payment_date, 1);
+}
@@ -1,46 +1,47 @@
 //! Data structures for the Payment contract.
-use soroban_sdk::{contracttype, Address, Map, String};
+use soroban_sdk::{contracttype, Address, Env, Map, String};
 
-/// Payment record for tracking individual payments
+pub use chioma_types::{AgreementStatus, PaymentSplit, RentAgreement, Role};
+
+/// A group of calls that can be paused on its own. Reads and admin calls
+/// are never paused.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PaymentRecord {
-    pub agreement_id: String,
-    pub payment_number: u32,
-    pub amount: i128,
-    pub landlord_amount: i128,
-    pub agent_amount: i128,
-    pub timestamp: u64,
-    pub tenant: Address,
+pub enum Operation {
+    /// Paying rent
+    Payments,
 }
 
-/// Payment split information for rent payments
+/// A new platform fee collector waiting out the timelock. It can be
+/// executed by anyone once `executable_at` has passed, or cancelled before
+/// then.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PaymentSplit {
-    pub landlord_amount: i128,
-    pub platform_amount: i128,
-    pub token: Address,
-    pub payment_date: u64,
+pub struct FeeCollectorChange {
+    pub collector: Address,
+    pub proposed_by: Address,
+    pub scheduled_at: u64,
+    pub executable_at: u64,
 }
 
-/// Agreement status enum (needed for payment validation)
+/// A payment split as the first version of this contract stored it inline,
+/// before splits recorded who paid
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum AgreementStatus {
-    Draft,
-    Pending,
-    Active,
-    Completed,
-    Cancelled,
-    Terminated,
-    Disputed,
+pub struct LegacyPaymentSplit {
+    pub landlord_amount: i128,
+    pub platform_amount: i128,
+    pub token: Address,
+    pub payment_date: u64,
 }
 
-/// Rent agreement structure (needed for payment processing)
+/// An agreement as this contract stores it. Only the inline history differs
+/// from `RentAgreement`: entries written by the first version have no payer,
+/// and later versions never write inline history, so every stored agreement
+/// decodes as this.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct RentAgreement {
+pub struct LegacyRentAgreement {
     pub agreement_id: String,
     pub landlord: Address,
     pub tenant: Address,
@@ -56,5 +57,57 @@ pub struct RentAgreement {
     pub signed_at: Option<u64>,
     pub payment_token: Address,
     pub next_payment_due: u64,
-    pub payment_history: Map<u32, PaymentSplit>,
+    pub payment_history: Map<u32, LegacyPaymentSplit>,
+}
+
+impl LegacyRentAgreement {
+    /// Convert to the shared layout. Only the tenant could pay rent when
+    /// splits were stored inline, so they are recorded as the payer.
+    pub fn into_agreement(self, env: &Env) -> RentAgreement {
+        let mut payment_history = Map::new(env);
+        for (month, split) in self.payment_history.iter() {
+            payment_history.set(
+                month,
+                PaymentSplit {
+                    landlord_amount: split.landlord_amount,
+                    platform_amount: split.platform_amount,
+                    token: split.token,
+                    payment_date: split.payment_date,
+                    payer: self.tenant.clone(),
+                },
+            );
+        }
+
+        RentAgreement {
+            agreement_id: self.agreement_id,
+            landlord: self.landlord,
+            tenant: self.tenant,
+            agent: self.agent,
+            monthly_rent: self.monthly_rent,
+            security_deposit: self.security_deposit,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            agent_commission_rate: self.agent_commission_rate,
+            status: self.status,
+            total_rent_paid: self.total_rent_paid,
+            payment_count: self.payment_count,
+            signed_at: self.signed_at,
+            payment_token: self.payment_token,
+            next_payment_due: self.next_payment_due,
+            payment_history,
+        }
+    }
+}
+
+/// Payment record for tracking individual payments
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentRecord {
+    pub agreement_id: String,
+    pub payment_number: u32,
+    pub amount: i128,
+    pub landlord_amount: i128,
+    pub agent_amount: i128,
+    pub timestamp: u64,
+    pub tenant: Address,
 }
@@ -1,3 +1,4 @@
+use chioma_types::AccessError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -12,4 +13,19 @@ pub enum PropertyError {
     AlreadyVerified = 6,
     InvalidPropertyId = 7,
     InvalidMetadata = 8,
+    NoPendingAdmin = 9,
+    InvalidRole = 10,
+    OperationPaused = 11,
+}
+
+impl From<AccessError> for PropertyError {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => PropertyError::NotInitialized,
+            AccessError::Unauthorized => PropertyError::Unauthorized,
+            AccessError::InvalidRole => PropertyError::InvalidRole,
+            AccessError::NoPendingAdmin => PropertyError::NoPendingAdmin,
+            AccessError::OperationPaused => PropertyError::OperationPaused,
+        }
+    }
 }
@@ -1,4 +1,5 @@
-use soroban_sdk::{contractevent, Address, Env, String};
+use crate::types::Operation;
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
 
 /// Event emitted when the contract is initialized
 /// Topics: ["initialized", admin: Address]
@@ -29,6 +30,43 @@ pub struct PropertyVerified {
     pub property_id: String,
 }
 
+/// Event emitted when the contract code is upgraded
+/// Topics: ["upgraded", admin: Address]
+#[contractevent(topics = ["upgraded"])]
+pub struct ContractUpgraded {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Event emitted when stored data is migrated to a new schema version
+/// Topics: ["migrated", admin: Address]
+#[contractevent(topics = ["migrated"])]
+pub struct ContractMigrated {
+    #[topic]
+    pub admin: Address,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+/// Event emitted when an operation is paused
+/// Topics: ["op_paused", operation: Operation]
+#[contractevent(topics = ["op_paused"])]
+pub struct OperationPaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+/// Event emitted when a paused operation is resumed
+/// Topics: ["op_unpaused", operation: Operation]
+#[contractevent(topics = ["op_unpaused"])]
+pub struct OperationUnpaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
 /// Helper function to emit contract initialized event
 pub(crate) fn contract_initialized(env: &Env, admin: Address) {
     ContractInitialized { admin }.publish(env);
@@ -53,3 +91,28 @@ pub(crate) fn property_registered(
 pub(crate) fn property_verified(env: &Env, property_id: String, admin: Address) {
     PropertyVerified { admin, property_id }.publish(env);
 }
+
+/// Helper function to emit contract upgraded event
+pub(crate) fn contract_upgraded(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    ContractUpgraded { admin, wasm_hash }.publish(env);
+}
+
+/// Helper function to emit contract migrated event
+pub(crate) fn contract_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    ContractMigrated {
+        admin,
+        from_version,
+        to_version,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit operation paused event
+pub(crate) fn operation_paused(env: &Env, operation: Operation, by: Address) {
+    OperationPaused { operation, by }.publish(env);
+}
+
+/// Helper function to emit operation unpaused event
+pub(crate) fn operation_unpaused(env: &Env, operation: Operation, by: Address) {
+    OperationUnpaused { operation, by }.publish(env);
+}
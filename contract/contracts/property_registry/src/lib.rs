@@ -1,22 +1,27 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use access::Access;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String};
 
+mod access;
 mod errors;
 mod events;
 mod property;
 mod storage;
 mod types;
+mod upgrade;
 
 #[cfg(test)]
 mod tests;
 
+pub use access::{has_role, is_paused, pause, unpause};
 pub use errors::PropertyError;
 pub use property::{
     get_property, get_property_count, has_property, register_property, verify_property,
 };
 pub use storage::DataKey;
-pub use types::{ContractState, PropertyDetails};
+pub use types::{ContractState, Operation, PropertyDetails, Role};
+pub use upgrade::{get_schema_version, migrate, upgrade, SCHEMA_VERSION};
 
 #[contract]
 pub struct PropertyRegistryContract;
@@ -49,6 +54,7 @@ impl PropertyRegistryContract {
 
         env.storage().instance().set(&DataKey::State, &state);
         env.storage().instance().extend_ttl(500000, 500000);
+        upgrade::set_schema_version(&env, upgrade::SCHEMA_VERSION);
 
         events::contract_initialized(&env, admin);
 
@@ -63,6 +69,178 @@ impl PropertyRegistryContract {
         env.storage().instance().get(&DataKey::State)
     }
 
+    /// Replace the contract's code with a previously uploaded WASM (admin only).
+    ///
+    /// Storage is kept across the upgrade. The admin may be a multisig
+    /// account, in which case its signers authorize the upgrade together.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the upgrade
+    /// * `new_wasm_hash` - The hash of the uploaded WASM to run from now on
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), PropertyError> {
+        upgrade::upgrade(&env, admin, new_wasm_hash)
+    }
+
+    /// Bring stored data up to the schema this code expects (admin only).
+    ///
+    /// Call after `upgrade` when the new code changes the storage schema.
+    /// Does nothing if the data is already current.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the migration
+    ///
+    /// # Returns
+    /// * `u32` - The schema version after migrating
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, PropertyError> {
+        upgrade::migrate(&env, admin)
+    }
+
+    /// Get the schema version of the stored data.
+    ///
+    /// # Returns
+    /// * `u32` - The stored schema version, 0 if written before versioning
+    pub fn get_schema_version(env: Env) -> u32 {
+        upgrade::get_schema_version(&env)
+    }
+
+    /// Nominate a new admin (admin only).
+    ///
+    /// The current admin stays in charge until the nominee calls
+    /// `accept_admin`, so a mistyped address cannot lock the contract.
+    ///
+    /// # Arguments
+    /// * `admin` - The current admin address
+    /// * `new_admin` - The address nominated to take over
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn propose_admin(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+    ) -> Result<(), PropertyError> {
+        chioma_types::access::propose_admin::<Access>(&env, admin, new_admin)
+    }
+
+    /// Accept a pending admin nomination.
+    ///
+    /// # Arguments
+    /// * `new_admin` - The nominated address
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `NoPendingAdmin` - If no admin has been nominated
+    /// * `Unauthorized` - If the caller is not the nominee
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), PropertyError> {
+        chioma_types::access::accept_admin::<Access>(&env, new_admin)
+    }
+
+    /// Get the nominated admin awaiting acceptance, if any.
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The pending admin
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        chioma_types::access::get_pending_admin(&env)
+    }
+
+    /// Grant an operational role to an account (admin only).
+    ///
+    /// Only `Verifier`, `Pauser` and `Guardian` can be granted on this contract.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `role` - The role to grant
+    /// * `account` - The account receiving the role
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidRole` - If the role can't be granted on this contract
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), PropertyError> {
+        chioma_types::access::grant_role::<Access>(&env, admin, role, account)
+    }
+
+    /// Revoke an operational role from an account (admin only).
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the action
+    /// * `role` - The role to revoke
+    /// * `account` - The account losing the role
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidRole` - If the role can't be granted on this contract
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), PropertyError> {
+        chioma_types::access::revoke_role::<Access>(&env, admin, role, account)
+    }
+
+    /// Check whether an account holds a role. The admin holds every role.
+    ///
+    /// # Returns
+    /// * `bool` - True if the account holds the role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access::has_role(&env, role, account)
+    }
+
+    /// Pause one group of operations, leaving reads and the rest of the
+    /// contract running (admin, pauser or guardian).
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `Pauser` or `Guardian`
+    /// * `operation` - The operation to pause
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller holds none of the roles
+    pub fn pause(env: Env, caller: Address, operation: Operation) -> Result<(), PropertyError> {
+        access::pause(&env, caller, operation)
+    }
+
+    /// Resume a paused operation (admin or pauser). Guardians can't unpause.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `Pauser`
+    /// * `operation` - The operation to resume
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If the caller holds neither role
+    pub fn unpause(env: Env, caller: Address, operation: Operation) -> Result<(), PropertyError> {
+        access::unpause(&env, caller, operation)
+    }
+
+    /// Check whether an operation is paused.
+    ///
+    /// # Returns
+    /// * `bool` - True if calls in the operation are currently rejected
+    pub fn is_paused(env: Env, operation: Operation) -> bool {
+        access::is_paused(&env, operation)
+    }
+
     /// Register a new property on-chain.
     ///
     /// # Arguments
@@ -84,23 +262,23 @@ impl PropertyRegistryContract {
         property::register_property(&env, landlord, property_id, metadata_hash)
     }
 
-    /// Verify a registered property (admin only).
+    /// Verify a registered property (admin or verifier).
     ///
     /// # Arguments
-    /// * `admin` - The admin address performing the verification
+    /// * `caller` - The admin or an account holding `Verifier`
     /// * `property_id` - The ID of the property to verify
     ///
     /// # Errors
     /// * `NotInitialized` - If the contract hasn't been initialized
-    /// * `Unauthorized` - If the caller is not the admin
+    /// * `Unauthorized` - If the caller holds neither role
     /// * `PropertyNotFound` - If the property doesn't exist
     /// * `AlreadyVerified` - If the property is already verified
     pub fn verify_property(
         env: Env,
-        admin: Address,
+        caller: Address,
         property_id: String,
     ) -> Result<(), PropertyError> {
-        property::verify_property(&env, admin, property_id)
+        property::verify_property(&env, caller, property_id)
     }
 
     /// Get details of a registered property.
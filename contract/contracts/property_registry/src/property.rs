@@ -1,9 +1,10 @@
 use soroban_sdk::{Address, Env, String};
 
+use crate::access;
 use crate::errors::PropertyError;
 use crate::events;
 use crate::storage::DataKey;
-use crate::types::{ContractState, PropertyDetails};
+use crate::types::{Operation, PropertyDetails, Role};
 
 pub fn register_property(
     env: &Env,
@@ -11,6 +12,8 @@ pub fn register_property(
     property_id: String,
     metadata_hash: String,
 ) -> Result<(), PropertyError> {
+    access::require_not_paused(env, Operation::Registration)?;
+
     if !env.storage().persistent().has(&DataKey::Initialized) {
         return Err(PropertyError::NotInitialized);
     }
@@ -56,20 +59,12 @@ pub fn register_property(
 
 pub fn verify_property(
     env: &Env,
-    admin: Address,
+    caller: Address,
     property_id: String,
 ) -> Result<(), PropertyError> {
-    let state: ContractState = env
-        .storage()
-        .instance()
-        .get(&DataKey::State)
-        .ok_or(PropertyError::NotInitialized)?;
+    access::require_not_paused(env, Operation::Verification)?;
 
-    admin.require_auth();
-
-    if admin != state.admin {
-        return Err(PropertyError::Unauthorized);
-    }
+    access::require_role(env, &caller, Role::Verifier)?;
 
     let key = DataKey::Property(property_id.clone());
     let mut property: PropertyDetails = env
@@ -88,7 +83,7 @@ pub fn verify_property(
     env.storage().persistent().set(&key, &property);
     env.storage().persistent().extend_ttl(&key, 500000, 500000);
 
-    events::property_verified(env, property_id, admin);
+    events::property_verified(env, property_id, caller);
 
     Ok(())
 }
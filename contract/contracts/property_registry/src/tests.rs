@@ -51,6 +51,166 @@ fn test_double_initialization_fails() {
     client.initialize(&admin);
 }
 
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let wasm_hash = soroban_sdk::BytesN::from_array(&env, &[9; 32]);
+
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.try_migrate(&admin),
+        Err(Ok(PropertyError::NotInitialized))
+    );
+
+    client.initialize(&admin);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_migrate(&outsider),
+        Err(Ok(PropertyError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_upgrade(&outsider, &wasm_hash),
+        Err(Ok(PropertyError::Unauthorized))
+    );
+
+    // A deployment from before schema versioning has no version key
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_admin_transfer_requires_acceptance() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(PropertyError::NoPendingAdmin))
+    );
+    assert_eq!(
+        client.try_propose_admin(&outsider, &new_admin),
+        Err(Ok(PropertyError::Unauthorized))
+    );
+
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_state().unwrap().admin, admin);
+
+    assert_eq!(
+        client.try_accept_admin(&outsider),
+        Err(Ok(PropertyError::Unauthorized))
+    );
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_state().unwrap().admin, new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_migrate(&admin),
+        Err(Ok(PropertyError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_verifier_role_can_verify_properties() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.try_grant_role(&admin, &Role::FeeManager, &verifier),
+        Err(Ok(PropertyError::InvalidRole))
+    );
+    assert_eq!(
+        client.try_grant_role(&verifier, &Role::Verifier, &verifier),
+        Err(Ok(PropertyError::Unauthorized))
+    );
+
+    client.grant_role(&admin, &Role::Verifier, &verifier);
+    assert!(client.has_role(&Role::Verifier, &verifier));
+    assert!(!client.has_role(&Role::Admin, &verifier));
+    assert!(client.has_role(&Role::Verifier, &admin));
+
+    let first = String::from_str(&env, "PROP-001");
+    let second = String::from_str(&env, "PROP-002");
+    let metadata_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    client.register_property(&landlord, &first, &metadata_hash);
+    client.register_property(&landlord, &second, &metadata_hash);
+
+    client.verify_property(&verifier, &first);
+    assert!(client.get_property(&first).unwrap().verified);
+
+    client.revoke_role(&admin, &Role::Verifier, &verifier);
+    assert!(!client.has_role(&Role::Verifier, &verifier));
+    assert_eq!(
+        client.try_verify_property(&verifier, &second),
+        Err(Ok(PropertyError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_guardian_can_pause_but_not_unpause() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.try_pause(&guardian, &Operation::Registration),
+        Err(Ok(PropertyError::Unauthorized))
+    );
+
+    client.grant_role(&admin, &Role::Guardian, &guardian);
+    client.pause(&guardian, &Operation::Registration);
+    assert!(client.is_paused(&Operation::Registration));
+    assert!(!client.is_paused(&Operation::Verification));
+
+    let property_id = String::from_str(&env, "PROP-001");
+    let metadata_hash = String::from_str(&env, "QmXoypizjW3WknFiJnKLwHCnL72vedxjQkDDP1mXWo6uco");
+    assert_eq!(
+        client.try_register_property(&landlord, &property_id, &metadata_hash),
+        Err(Ok(PropertyError::OperationPaused))
+    );
+    assert_eq!(
+        client.try_unpause(&guardian, &Operation::Registration),
+        Err(Ok(PropertyError::Unauthorized))
+    );
+
+    client.unpause(&admin, &Operation::Registration);
+    assert!(!client.is_paused(&Operation::Registration));
+    client.register_property(&landlord, &property_id, &metadata_hash);
+}
+
 #[test]
 fn test_register_property_success() {
     let env = Env::default();
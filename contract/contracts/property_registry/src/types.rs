@@ -1,15 +1,6 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address};
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PropertyDetails {
-    pub property_id: String,
-    pub landlord: Address,
-    pub metadata_hash: String,
-    pub verified: bool,
-    pub registered_at: u64,
-    pub verified_at: Option<u64>,
-}
+pub use chioma_types::{PropertyDetails, Role};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -17,3 +8,11 @@ pub struct ContractState {
     pub admin: Address,
     pub initialized: bool,
 }
+
+/// A group of calls that can be paused on its own. Reads are never paused.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    Registration,
+    Verification,
+}
@@ -0,0 +1,54 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::access;
+use crate::errors::PropertyError;
+use crate::events;
+use crate::storage::DataKey;
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Returns the schema version of the stored data. Zero means the data was
+/// written before schema versioning was introduced.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+pub(crate) fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+    env.storage().instance().extend_ttl(500000, 500000);
+}
+
+/// Replace the contract's code with an uploaded WASM (admin only). Storage
+/// is kept; call `migrate` afterwards if the new code changed its schema.
+pub fn upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), PropertyError> {
+    access::require_admin(env, &admin)?;
+
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+
+    events::contract_upgraded(env, admin, new_wasm_hash);
+
+    Ok(())
+}
+
+/// Bring stored data up to `SCHEMA_VERSION` (admin only). A no-op when the
+/// data is already current. Returns the schema version after migrating.
+pub fn migrate(env: &Env, admin: Address) -> Result<u32, PropertyError> {
+    access::require_admin(env, &admin)?;
+
+    let from_version = get_schema_version(env);
+    if from_version >= SCHEMA_VERSION {
+        return Ok(from_version);
+    }
+
+    set_schema_version(env, SCHEMA_VERSION);
+
+    events::contract_migrated(env, admin, from_version, SCHEMA_VERSION);
+
+    Ok(SCHEMA_VERSION)
+}
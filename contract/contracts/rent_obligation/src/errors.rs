@@ -1,3 +1,4 @@
+use chioma_types::AccessError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -10,4 +11,19 @@ pub enum ObligationError {
     ObligationNotFound = 4,
     Unauthorized = 5,
     InvalidOwner = 6,
+    NoPendingAdmin = 7,
+    OperationPaused = 8,
+    InvalidRole = 9,
+}
+
+impl From<AccessError> for ObligationError {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => ObligationError::NotInitialized,
+            AccessError::Unauthorized => ObligationError::Unauthorized,
+            AccessError::InvalidRole => ObligationError::InvalidRole,
+            AccessError::NoPendingAdmin => ObligationError::NoPendingAdmin,
+            AccessError::OperationPaused => ObligationError::OperationPaused,
+        }
+    }
 }
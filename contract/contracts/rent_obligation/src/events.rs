@@ -1,4 +1,6 @@
-use soroban_sdk::{contractevent, Address, Env, String};
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
+
+use crate::types::Operation;
 
 /// Event emitted when a rent obligation NFT is minted
 /// Topics: ["minted", landlord: Address]
@@ -21,6 +23,43 @@ pub struct ObligationTransferred {
     pub agreement_id: String,
 }
 
+/// Event emitted when the contract code is upgraded
+/// Topics: ["upgraded", admin: Address]
+#[contractevent(topics = ["upgraded"])]
+pub struct ContractUpgraded {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Event emitted when stored data is migrated to a new schema version
+/// Topics: ["migrated", admin: Address]
+#[contractevent(topics = ["migrated"])]
+pub struct ContractMigrated {
+    #[topic]
+    pub admin: Address,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+/// Event emitted when an operation is paused
+/// Topics: ["op_paused", operation: Operation]
+#[contractevent(topics = ["op_paused"])]
+pub struct OperationPaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
+/// Event emitted when a paused operation is resumed
+/// Topics: ["op_unpaused", operation: Operation]
+#[contractevent(topics = ["op_unpaused"])]
+pub struct OperationUnpaused {
+    #[topic]
+    pub operation: Operation,
+    pub by: Address,
+}
+
 /// Helper function to emit obligation minted event
 pub(crate) fn obligation_minted(
     env: &Env,
@@ -45,3 +84,28 @@ pub(crate) fn obligation_transferred(env: &Env, agreement_id: String, from: Addr
     }
     .publish(env);
 }
+
+/// Helper function to emit contract upgraded event
+pub(crate) fn contract_upgraded(env: &Env, admin: Address, wasm_hash: BytesN<32>) {
+    ContractUpgraded { admin, wasm_hash }.publish(env);
+}
+
+/// Helper function to emit contract migrated event
+pub(crate) fn contract_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    ContractMigrated {
+        admin,
+        from_version,
+        to_version,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit operation paused event
+pub(crate) fn operation_paused(env: &Env, operation: Operation, by: Address) {
+    OperationPaused { operation, by }.publish(env);
+}
+
+/// Helper function to emit operation unpaused event
+pub(crate) fn operation_unpaused(env: &Env, operation: Operation, by: Address) {
+    OperationUnpaused { operation, by }.publish(env);
+}
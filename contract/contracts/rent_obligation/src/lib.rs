@@ -1,6 +1,8 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use chioma_types::access::{self, AccessControl};
+use chioma_types::Role;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String};
 
 mod errors;
 mod events;
@@ -12,27 +14,58 @@ mod tests;
 
 pub use errors::ObligationError;
 pub use storage::DataKey;
-pub use types::RentObligation;
+pub use types::{Operation, RentObligation};
+
+pub const SCHEMA_VERSION: u32 = 1;
 
 #[contract]
 pub struct TokenizedRentObligationContract;
 
+impl AccessControl for TokenizedRentObligationContract {
+    type Error = ObligationError;
+
+    fn admin(env: &Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Admin)
+    }
+
+    fn set_admin(env: &Env, admin: &Address) {
+        env.storage().persistent().set(&DataKey::Admin, admin);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::Admin, 500000, 500000);
+    }
+
+    fn is_grantable(role: &Role) -> bool {
+        *role == Role::Guardian
+    }
+}
+
 #[contractimpl]
 impl TokenizedRentObligationContract {
-    /// Initialize the contract.
+    /// Initialize the contract with an admin address.
+    ///
+    /// # Arguments
+    /// * `admin` - The address allowed to upgrade and migrate the contract
     ///
     /// # Errors
     /// * `AlreadyInitialized` - If the contract has already been initialized
-    pub fn initialize(env: Env) -> Result<(), ObligationError> {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ObligationError> {
         if env.storage().persistent().has(&DataKey::Initialized) {
             return Err(ObligationError::AlreadyInitialized);
         }
 
+        admin.require_auth();
+
         env.storage().persistent().set(&DataKey::Initialized, &true);
         env.storage()
             .persistent()
             .extend_ttl(&DataKey::Initialized, 500000, 500000);
 
+        env.storage().persistent().set(&DataKey::Admin, &admin);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::Admin, 500000, 500000);
+
         env.storage()
             .persistent()
             .set(&DataKey::ObligationCount, &0u32);
@@ -40,9 +73,237 @@ impl TokenizedRentObligationContract {
             .persistent()
             .extend_ttl(&DataKey::ObligationCount, 500000, 500000);
 
+        Self::set_schema_version(&env, SCHEMA_VERSION);
+
         Ok(())
     }
 
+    /// Get the contract admin.
+    ///
+    /// # Returns
+    /// The admin address, or None if the contract hasn't been initialized
+    pub fn get_admin(env: Env) -> Option<Address> {
+        <Self as AccessControl>::admin(&env)
+    }
+
+    fn set_schema_version(env: &Env, version: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::SchemaVersion, &version);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::SchemaVersion, 500000, 500000);
+    }
+
+    /// Nominate a new admin (admin only).
+    ///
+    /// The current admin stays in charge until the nominee calls
+    /// `accept_admin`, so a mistyped address cannot lock the contract.
+    ///
+    /// # Arguments
+    /// * `admin` - The current admin address
+    /// * `new_admin` - The address nominated to take over
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn propose_admin(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+    ) -> Result<(), ObligationError> {
+        access::propose_admin::<Self>(&env, admin, new_admin)
+    }
+
+    /// Accept a pending admin nomination.
+    ///
+    /// # Arguments
+    /// * `new_admin` - The nominated address
+    ///
+    /// # Errors
+    /// * `NoPendingAdmin` - If no admin has been nominated
+    /// * `Unauthorized` - If the caller is not the nominee
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ObligationError> {
+        access::accept_admin::<Self>(&env, new_admin)
+    }
+
+    /// Get the nominated admin awaiting acceptance, if any.
+    ///
+    /// # Returns
+    /// The pending admin address, if one has been nominated
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        access::get_pending_admin(&env)
+    }
+
+    /// Replace the contract's code with a previously uploaded WASM (admin only).
+    ///
+    /// Storage is kept across the upgrade. The admin may be a multisig
+    /// account, in which case its signers authorize the upgrade together.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the upgrade
+    /// * `new_wasm_hash` - The hash of the uploaded WASM to run from now on
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ObligationError> {
+        access::require_admin::<Self>(&env, &admin)?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::contract_upgraded(&env, admin, new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Bring stored data up to the schema this code expects (admin only).
+    ///
+    /// Call after `upgrade` when the new code changes the storage schema.
+    /// Does nothing if the data is already current.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address performing the migration
+    ///
+    /// # Returns
+    /// The schema version after migrating
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, ObligationError> {
+        access::require_admin::<Self>(&env, &admin)?;
+
+        let from_version = Self::get_schema_version(env.clone());
+        if from_version >= SCHEMA_VERSION {
+            return Ok(from_version);
+        }
+
+        Self::set_schema_version(&env, SCHEMA_VERSION);
+
+        events::contract_migrated(&env, admin, from_version, SCHEMA_VERSION);
+
+        Ok(SCHEMA_VERSION)
+    }
+
+    /// Get the schema version of the stored data.
+    ///
+    /// # Returns
+    /// The stored schema version, 0 if written before versioning
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(0)
+    }
+
+    /// Grant a role to an account (admin only).
+    ///
+    /// Only `Guardian` can be granted here. Guardians can pause operations
+    /// in an emergency but can't unpause them or do anything else.
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address
+    /// * `role` - The role to grant
+    /// * `account` - The account receiving the role
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidRole` - If the role can't be granted on this contract
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), ObligationError> {
+        access::grant_role::<Self>(&env, admin, role, account)
+    }
+
+    /// Revoke a role from an account (admin only).
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address
+    /// * `role` - The role to revoke
+    /// * `account` - The account losing the role
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidRole` - If the role can't be granted on this contract
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), ObligationError> {
+        access::revoke_role::<Self>(&env, admin, role, account)
+    }
+
+    /// Check whether an account holds a role. The admin holds every role.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        access::has_role::<Self>(&env, role, account)
+    }
+
+    /// Pause an operation (admin or guardian).
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or guardian address
+    /// * `operation` - The group of calls to pause
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is neither admin nor guardian
+    pub fn pause(env: Env, caller: Address, operation: Operation) -> Result<(), ObligationError> {
+        access::require_pause_right::<Self>(&env, &caller, true)?;
+
+        if !Self::is_paused(env.clone(), operation.clone()) {
+            let key = DataKey::Paused(operation.clone());
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+            events::operation_paused(&env, operation, caller);
+        }
+
+        Ok(())
+    }
+
+    /// Resume a paused operation (admin only).
+    ///
+    /// # Arguments
+    /// * `admin` - The admin address
+    /// * `operation` - The group of calls to resume
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn unpause(env: Env, admin: Address, operation: Operation) -> Result<(), ObligationError> {
+        access::require_admin::<Self>(&env, &admin)?;
+
+        if Self::is_paused(env.clone(), operation.clone()) {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Paused(operation.clone()));
+
+            events::operation_unpaused(&env, operation, admin);
+        }
+
+        Ok(())
+    }
+
+    /// Check whether an operation is paused.
+    pub fn is_paused(env: Env, operation: Operation) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Paused(operation))
+            .unwrap_or(false)
+    }
+
     /// Mint a new tokenized rent obligation NFT for a rent agreement.
     ///
     /// # Arguments
@@ -52,6 +313,7 @@ impl TokenizedRentObligationContract {
     /// # Errors
     /// * `NotInitialized` - If contract hasn't been initialized
     /// * `ObligationAlreadyExists` - If an obligation for this agreement already exists
+    /// * `OperationPaused` - If minting is paused
     pub fn mint_obligation(
         env: Env,
         agreement_id: String,
@@ -61,6 +323,10 @@ impl TokenizedRentObligationContract {
             return Err(ObligationError::NotInitialized);
         }
 
+        if Self::is_paused(env.clone(), Operation::Minting) {
+            return Err(ObligationError::OperationPaused);
+        }
+
         landlord.require_auth();
 
         let obligation_key = DataKey::Obligation(agreement_id.clone());
@@ -115,6 +381,7 @@ impl TokenizedRentObligationContract {
     /// * `NotInitialized` - If contract hasn't been initialized
     /// * `ObligationNotFound` - If the obligation doesn't exist
     /// * `Unauthorized` - If the caller is not the current owner
+    /// * `OperationPaused` - If transfers are paused
     pub fn transfer_obligation(
         env: Env,
         from: Address,
@@ -125,6 +392,10 @@ impl TokenizedRentObligationContract {
             return Err(ObligationError::NotInitialized);
         }
 
+        if Self::is_paused(env.clone(), Operation::Transfers) {
+            return Err(ObligationError::OperationPaused);
+        }
+
         from.require_auth();
 
         let obligation_key = DataKey::Obligation(agreement_id.clone());
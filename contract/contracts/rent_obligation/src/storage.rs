@@ -1,5 +1,7 @@
 use soroban_sdk::{contracttype, String};
 
+use crate::types::Operation;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
@@ -7,4 +9,7 @@ pub enum DataKey {
     Obligation(String),
     Owner(String),
     ObligationCount,
+    Admin,
+    SchemaVersion,
+    Paused(Operation),
 }
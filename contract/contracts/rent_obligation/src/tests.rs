@@ -9,13 +9,33 @@ fn create_contract(env: &Env) -> TokenizedRentObligationContractClient<'_> {
     TokenizedRentObligationContractClient::new(env, &contract_id)
 }
 
+fn initialize_contract(env: &Env, client: &TokenizedRentObligationContractClient) -> Address {
+    let admin = Address::generate(env);
+    client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "initialize",
+                args: (admin.clone(),).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .initialize(&admin);
+    admin
+}
+
 #[test]
 fn test_successful_initialization() {
     let env = Env::default();
     let client = create_contract(&env);
 
-    let result = client.try_initialize();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    let result = client.try_initialize(&admin);
     assert!(result.is_ok());
+    assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
 
     let count = client.get_obligation_count();
     assert_eq!(count, 0);
@@ -27,8 +47,10 @@ fn test_double_initialization_fails() {
     let env = Env::default();
     let client = create_contract(&env);
 
-    client.initialize();
-    client.initialize();
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.initialize(&admin);
 }
 
 #[test]
@@ -37,7 +59,7 @@ fn test_mint_obligation() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -68,7 +90,7 @@ fn test_mint_obligation_requires_auth() {
     let env = Env::default();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -83,7 +105,7 @@ fn test_mint_duplicate_obligation_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -112,7 +134,7 @@ fn test_transfer_obligation() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -138,7 +160,7 @@ fn test_transfer_obligation_requires_auth() {
     let env = Env::default();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -166,7 +188,7 @@ fn test_transfer_nonexistent_obligation_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -182,7 +204,7 @@ fn test_transfer_from_non_owner_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord = Address::generate(&env);
     let fake_owner = Address::generate(&env);
@@ -200,7 +222,7 @@ fn test_multiple_obligations() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord1 = Address::generate(&env);
     let landlord2 = Address::generate(&env);
@@ -225,7 +247,7 @@ fn test_multiple_obligations() {
 fn test_get_nonexistent_obligation() {
     let env = Env::default();
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let agreement_id = String::from_str(&env, "nonexistent");
 
@@ -245,7 +267,7 @@ fn test_transfer_chain() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord = Address::generate(&env);
     let buyer1 = Address::generate(&env);
@@ -286,7 +308,7 @@ fn test_events_emitted() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    initialize_contract(&env, &client);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -298,3 +320,116 @@ fn test_events_emitted() {
     let all_events = env.events().all();
     assert!(!all_events.is_empty());
 }
+
+#[test]
+fn test_migrate_schema_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = initialize_contract(&env, &client);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_migrate(&outsider),
+        Err(Ok(ObligationError::Unauthorized))
+    );
+    let wasm_hash = soroban_sdk::BytesN::from_array(&env, &[9; 32]);
+    assert_eq!(
+        client.try_upgrade(&outsider, &wasm_hash),
+        Err(Ok(ObligationError::Unauthorized))
+    );
+
+    // A deployment from before schema versioning has no version key
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_admin_transfer_requires_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = initialize_contract(&env, &client);
+    let new_admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(ObligationError::NoPendingAdmin))
+    );
+    assert_eq!(
+        client.try_propose_admin(&outsider, &new_admin),
+        Err(Ok(ObligationError::Unauthorized))
+    );
+
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_admin(), Some(admin.clone()));
+    assert_eq!(
+        client.try_accept_admin(&outsider),
+        Err(Ok(ObligationError::Unauthorized))
+    );
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_migrate(&admin),
+        Err(Ok(ObligationError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_guardian_can_pause_transfers_but_not_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = initialize_contract(&env, &client);
+    let guardian = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "AGR_001");
+
+    client.mint_obligation(&agreement_id, &landlord);
+
+    assert_eq!(
+        client.try_pause(&guardian, &Operation::Transfers),
+        Err(Ok(ObligationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_grant_role(&admin, &Role::Pauser, &guardian),
+        Err(Ok(ObligationError::InvalidRole))
+    );
+    client.grant_role(&admin, &Role::Guardian, &guardian);
+    assert!(client.has_role(&Role::Guardian, &guardian));
+    client.pause(&guardian, &Operation::Transfers);
+    assert!(client.is_paused(&Operation::Transfers));
+    assert!(!client.is_paused(&Operation::Minting));
+
+    assert_eq!(
+        client.try_transfer_obligation(&landlord, &buyer, &agreement_id),
+        Err(Ok(ObligationError::OperationPaused))
+    );
+    client.mint_obligation(&String::from_str(&env, "AGR_002"), &landlord);
+
+    assert_eq!(
+        client.try_unpause(&guardian, &Operation::Transfers),
+        Err(Ok(ObligationError::Unauthorized))
+    );
+    client.unpause(&admin, &Operation::Transfers);
+    client.revoke_role(&admin, &Role::Guardian, &guardian);
+    assert_eq!(
+        client.try_pause(&guardian, &Operation::Transfers),
+        Err(Ok(ObligationError::Unauthorized))
+    );
+    client.transfer_obligation(&landlord, &buyer, &agreement_id);
+    assert_eq!(client.get_obligation_owner(&agreement_id), Some(buyer));
+}
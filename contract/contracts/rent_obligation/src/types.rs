@@ -1,5 +1,13 @@
 use soroban_sdk::{contracttype, Address, String};
 
+/// A group of calls that can be paused on its own
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    Minting,
+    Transfers,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RentObligation {
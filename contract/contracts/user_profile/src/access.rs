@@ -0,0 +1,113 @@
+//! Access control for the User Profile contract. Admin transfer, roles and
+//! pause flags are handled by `chioma_types::access`; this module binds them
+//! to the stored admin, emits this contract's pause events and turns access
+//! failures into this contract's panics.
+use chioma_types::access::{self as shared, AccessControl, AccessError};
+use soroban_sdk::{Address, Env};
+
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{Operation, Role};
+
+pub(crate) struct Access;
+
+impl AccessControl for Access {
+    type Error = AccessError;
+
+    fn admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    fn is_grantable(role: &Role) -> bool {
+        matches!(role, Role::Verifier | Role::Pauser | Role::Guardian)
+    }
+}
+
+/// Panic on an access failure, using `unauthorized` as the message when the
+/// caller was not allowed
+fn expect_access<T>(result: Result<T, AccessError>, unauthorized: &str) -> T {
+    match result {
+        Ok(value) => value,
+        Err(AccessError::NotInitialized) => panic!("Admin not configured"),
+        Err(AccessError::Unauthorized) => panic!("{}", unauthorized),
+        Err(AccessError::InvalidRole) => panic!("Role cannot be granted"),
+        Err(AccessError::NoPendingAdmin) => panic!("No pending admin"),
+        Err(AccessError::OperationPaused) => panic!("Operation paused"),
+    }
+}
+
+/// Require `admin` to be the contract admin and to have authorized the call
+pub(crate) fn require_admin(env: &Env, admin: &Address) {
+    expect_access(
+        shared::require_admin::<Access>(env, admin),
+        "Unauthorized: caller is not admin",
+    );
+}
+
+/// Require `caller` to hold `role` and to have authorized the call. The
+/// admin holds every role.
+pub(crate) fn require_role(env: &Env, caller: &Address, role: Role) {
+    expect_access(
+        shared::require_role::<Access>(env, caller, role),
+        "Unauthorized: caller lacks role",
+    );
+}
+
+pub(crate) fn propose_admin(env: &Env, admin: Address, new_admin: Address) {
+    expect_access(
+        shared::propose_admin::<Access>(env, admin, new_admin),
+        "Unauthorized: caller is not admin",
+    );
+}
+
+pub(crate) fn accept_admin(env: &Env, new_admin: Address) {
+    expect_access(
+        shared::accept_admin::<Access>(env, new_admin),
+        "Unauthorized: caller is not the pending admin",
+    );
+}
+
+pub(crate) fn grant_role(env: &Env, admin: Address, role: Role, account: Address) {
+    expect_access(
+        shared::grant_role::<Access>(env, admin, role, account),
+        "Unauthorized: caller is not admin",
+    );
+}
+
+pub(crate) fn revoke_role(env: &Env, admin: Address, role: Role, account: Address) {
+    expect_access(
+        shared::revoke_role::<Access>(env, admin, role, account),
+        "Unauthorized: caller is not admin",
+    );
+}
+
+pub(crate) fn is_paused(env: &Env, operation: Operation) -> bool {
+    shared::is_paused(env, operation)
+}
+
+/// Pause one operation. Pausing an already paused operation is a no-op.
+pub(crate) fn pause(env: &Env, caller: Address, operation: Operation) {
+    let paused = shared::pause::<Access, _>(env, &caller, operation.clone());
+    if expect_access(paused, "Unauthorized: caller lacks role") {
+        events::operation_paused(env, operation, caller);
+    }
+}
+
+/// Resume a paused operation. Guardians can't unpause.
+pub(crate) fn unpause(env: &Env, caller: Address, operation: Operation) {
+    let resumed = shared::unpause::<Access, _>(env, &caller, operation.clone());
+    if expect_access(resumed, "Unauthorized: caller lacks role") {
+        events::operation_unpaused(env, operation, caller);
+    }
+}
+
+pub(crate) fn require_not_paused(env: &Env, operation: Operation) {
+    expect_access(
+        shared::require_not_paused::<Access, _>(env, operation),
+        "Operation paused",
+    );
+}
@@ -1,5 +1,7 @@
 #![no_std]
 
+mod access;
+mod events;
 mod profile;
 mod storage;
 mod types;
@@ -1,7 +1,11 @@
-use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String};
 
+use crate::access::{self, Access};
+use crate::events;
 use crate::storage::DataKey;
-use crate::types::{AccountType, UserProfile};
+use crate::types::{AccountType, Operation, Role, UserProfile};
+
+pub const SCHEMA_VERSION: u32 = 1;
 
 #[contract]
 pub struct UserProfileContract;
@@ -19,6 +23,9 @@ impl UserProfileContract {
 
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Initialized, &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
     }
 
     /// Create a new user profile
@@ -29,6 +36,8 @@ impl UserProfileContract {
         account_type: AccountType,
         data_hash: Bytes,
     ) -> UserProfile {
+        access::require_not_paused(&env, Operation::Profiles);
+
         // Require authorization from the account owner
         account_id.require_auth();
 
@@ -72,6 +81,8 @@ impl UserProfileContract {
         account_type: Option<AccountType>,
         data_hash: Option<Bytes>,
     ) -> UserProfile {
+        access::require_not_paused(&env, Operation::Profiles);
+
         // Require authorization from the account owner
         account_id.require_auth();
 
@@ -121,22 +132,11 @@ impl UserProfileContract {
         env.storage().persistent().has(&key)
     }
 
-    /// Verify a user profile (admin only)
+    /// Verify a user profile (admin or verifier)
     /// Sets is_verified flag to true
-    pub fn verify_profile(env: Env, admin: Address, account_id: Address) -> UserProfile {
-        // Require admin authorization
-        admin.require_auth();
-
-        // Verify admin
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("Admin not configured"));
-
-        if admin != stored_admin {
-            panic!("Unauthorized: caller is not admin");
-        }
+    pub fn verify_profile(env: Env, caller: Address, account_id: Address) -> UserProfile {
+        access::require_not_paused(&env, Operation::Verification);
+        access::require_role(&env, &caller, Role::Verifier);
 
         let key = DataKey::Profile(account_id.clone());
 
@@ -157,22 +157,11 @@ impl UserProfileContract {
         profile
     }
 
-    /// Unverify a user profile (admin only)
+    /// Unverify a user profile (admin or verifier)
     /// Sets is_verified flag to false
-    pub fn unverify_profile(env: Env, admin: Address, account_id: Address) -> UserProfile {
-        // Require admin authorization
-        admin.require_auth();
-
-        // Verify admin
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("Admin not configured"));
-
-        if admin != stored_admin {
-            panic!("Unauthorized: caller is not admin");
-        }
+    pub fn unverify_profile(env: Env, caller: Address, account_id: Address) -> UserProfile {
+        access::require_not_paused(&env, Operation::Verification);
+        access::require_role(&env, &caller, Role::Verifier);
 
         let key = DataKey::Profile(account_id.clone());
 
@@ -217,12 +206,103 @@ impl UserProfileContract {
         // Remove profile from storage
         env.storage().persistent().remove(&key);
     }
+
+    /// Replace the contract code with a previously uploaded WASM (admin only)
+    /// Storage is kept; the admin may be a multisig account
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        access::require_admin(&env, &admin);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::contract_upgraded(&env, admin, new_wasm_hash);
+    }
+
+    /// Bring stored data up to SCHEMA_VERSION after an upgrade (admin only)
+    /// Returns the schema version after migrating
+    pub fn migrate(env: Env, admin: Address) -> u32 {
+        access::require_admin(&env, &admin);
+
+        let from_version = Self::get_schema_version(env.clone());
+        if from_version >= SCHEMA_VERSION {
+            return from_version;
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+
+        events::contract_migrated(&env, admin, from_version, SCHEMA_VERSION);
+
+        SCHEMA_VERSION
+    }
+
+    /// Get the schema version of the stored data
+    /// Returns 0 for data written before schema versioning
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(0)
+    }
+
+    /// Propose a new admin (admin only)
+    /// Takes effect once the nominee calls accept_admin
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) {
+        access::propose_admin(&env, admin, new_admin);
+    }
+
+    /// Accept a pending admin proposal (nominee only)
+    pub fn accept_admin(env: Env, new_admin: Address) {
+        access::accept_admin(&env, new_admin);
+    }
+
+    /// Get the proposed admin awaiting acceptance, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        chioma_types::access::get_pending_admin(&env)
+    }
+
+    /// Grant a role to an account (admin only)
+    /// Only Verifier, Pauser and Guardian can be granted on this contract
+    pub fn grant_role(env: Env, admin: Address, role: Role, account: Address) {
+        access::grant_role(&env, admin, role, account);
+    }
+
+    /// Revoke a role from an account (admin only)
+    pub fn revoke_role(env: Env, admin: Address, role: Role, account: Address) {
+        access::revoke_role(&env, admin, role, account);
+    }
+
+    /// Check whether an account holds a role
+    /// The admin holds every role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        chioma_types::access::has_role::<Access>(&env, role, account)
+    }
+
+    /// Pause an operation (admin, pauser or guardian)
+    pub fn pause(env: Env, caller: Address, operation: Operation) {
+        access::pause(&env, caller, operation);
+    }
+
+    /// Resume a paused operation (admin or pauser)
+    /// Guardians can pause but not unpause
+    pub fn unpause(env: Env, caller: Address, operation: Operation) {
+        access::unpause(&env, caller, operation);
+    }
+
+    /// Check whether an operation is paused
+    pub fn is_paused(env: Env, operation: Operation) -> bool {
+        access::is_paused(&env, operation)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Bytes, Env};
+    use soroban_sdk::{
+        testutils::{Address as _, Events},
+        Bytes, Env, IntoVal, Symbol, Val, Vec,
+    };
 
     #[test]
     fn test_initialize_contract() {
@@ -253,6 +333,47 @@ mod test {
         client.initialize(&admin);
     }
 
+    #[test]
+    fn test_migrate_schema_version() {
+        let env = Env::default();
+        let contract_id = env.register(UserProfileContract, ());
+        let client = UserProfileContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+        assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+
+        // A deployment from before schema versioning has no version key
+        env.as_contract(&contract_id, || {
+            env.storage().instance().remove(&DataKey::SchemaVersion);
+        });
+        assert_eq!(client.get_schema_version(), 0);
+        assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+
+        let event = env.events().all().last().unwrap();
+        let topics: Vec<Val> = (Symbol::new(&env, "migrated"), admin.clone()).into_val(&env);
+        assert_eq!(event.0, contract_id);
+        assert_eq!(event.1, topics);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller is not admin")]
+    fn test_upgrade_by_non_admin_fails() {
+        let env = Env::default();
+        let contract_id = env.register(UserProfileContract, ());
+        let client = UserProfileContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.upgrade(&user, &BytesN::from_array(&env, &[9; 32]));
+    }
+
     #[test]
     fn test_create_profile() {
         let env = Env::default();
@@ -425,6 +546,154 @@ mod test {
         assert!(!unverified.is_verified);
     }
 
+    #[test]
+    fn test_admin_transfer_requires_acceptance() {
+        let env = Env::default();
+        let contract_id = env.register(UserProfileContract, ());
+        let client = UserProfileContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.propose_admin(&admin, &new_admin);
+
+        assert_eq!(client.get_admin(), admin);
+        assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+        client.accept_admin(&new_admin);
+
+        let event = env.events().all().last().unwrap();
+        let topics: Vec<Val> = (
+            Symbol::new(&env, "admin_changed"),
+            admin.clone(),
+            new_admin.clone(),
+        )
+            .into_val(&env);
+        assert_eq!(event.0, contract_id);
+        assert_eq!(event.1, topics);
+
+        assert_eq!(client.get_admin(), new_admin);
+        assert_eq!(client.get_pending_admin(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller is not the pending admin")]
+    fn test_accept_admin_by_non_nominee_fails() {
+        let env = Env::default();
+        let contract_id = env.register(UserProfileContract, ());
+        let client = UserProfileContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        let outsider = Address::generate(&env);
+
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.propose_admin(&admin, &new_admin);
+        client.accept_admin(&outsider);
+    }
+
+    #[test]
+    fn test_verifier_role_can_verify_profile() {
+        let env = Env::default();
+        let contract_id = env.register(UserProfileContract, ());
+        let client = UserProfileContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let user = Address::generate(&env);
+        let data_hash = Bytes::from_array(&env, &[0u8; 32]);
+
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.create_profile(&user, &AccountType::Tenant, &data_hash);
+        client.grant_role(&admin, &Role::Verifier, &verifier);
+
+        assert!(client.has_role(&Role::Verifier, &verifier));
+        assert!(!client.has_role(&Role::Admin, &verifier));
+
+        let verified = client.verify_profile(&verifier, &user);
+        assert!(verified.is_verified);
+
+        client.revoke_role(&admin, &Role::Verifier, &verifier);
+        let event = env.events().all().last().unwrap();
+        let topics: Vec<Val> = (Symbol::new(&env, "role_revoked"), verifier.clone()).into_val(&env);
+        assert_eq!(event.0, contract_id);
+        assert_eq!(event.1, topics);
+        assert!(!client.has_role(&Role::Verifier, &verifier));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller lacks role")]
+    fn test_verify_profile_without_role_fails() {
+        let env = Env::default();
+        let contract_id = env.register(UserProfileContract, ());
+        let client = UserProfileContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        let user = Address::generate(&env);
+        let data_hash = Bytes::from_array(&env, &[0u8; 32]);
+
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.create_profile(&user, &AccountType::Tenant, &data_hash);
+        client.verify_profile(&outsider, &user);
+    }
+
+    #[test]
+    fn test_guardian_can_pause_verification() {
+        let env = Env::default();
+        let contract_id = env.register(UserProfileContract, ());
+        let client = UserProfileContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let guardian = Address::generate(&env);
+        let user = Address::generate(&env);
+        let data_hash = Bytes::from_array(&env, &[0u8; 32]);
+
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.create_profile(&user, &AccountType::Tenant, &data_hash);
+        client.grant_role(&admin, &Role::Guardian, &guardian);
+
+        client.pause(&guardian, &Operation::Verification);
+        assert!(client.is_paused(&Operation::Verification));
+        assert!(!client.is_paused(&Operation::Profiles));
+        assert!(client.try_verify_profile(&admin, &user).is_err());
+
+        // Profile edits are still open
+        client.update_profile(&user, &Some(AccountType::Landlord), &None);
+
+        client.unpause(&admin, &Operation::Verification);
+        assert!(client.verify_profile(&admin, &user).is_verified);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller lacks role")]
+    fn test_guardian_cannot_unpause() {
+        let env = Env::default();
+        let contract_id = env.register(UserProfileContract, ());
+        let client = UserProfileContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let guardian = Address::generate(&env);
+
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.grant_role(&admin, &Role::Guardian, &guardian);
+        client.pause(&guardian, &Operation::Profiles);
+        client.unpause(&guardian, &Operation::Profiles);
+    }
+
     #[test]
     fn test_delete_profile() {
         let env = Env::default();
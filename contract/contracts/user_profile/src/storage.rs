@@ -12,4 +12,7 @@ pub enum DataKey {
 
     /// Contract initialization flag
     Initialized,
+
+    /// Schema version of the stored data
+    SchemaVersion,
 }
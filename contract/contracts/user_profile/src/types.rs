@@ -1,5 +1,17 @@
 use soroban_sdk::{contracttype, Address, Bytes, String};
 
+pub use chioma_types::Role;
+
+/// A group of calls that can be paused on its own
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operation {
+    /// Creating and updating profiles
+    Profiles,
+    /// Verifying and unverifying profiles
+    Verification,
+}
+
 /// Account type enumeration
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -0,0 +1,324 @@
+//! Two-step admin transfer and operational roles shared by the Chioma
+//! contracts.
+//!
+//! Each contract keeps its admin where it always has and exposes it through
+//! [`AccessControl`]. Nominated admins and granted roles are stored under
+//! [`AccessKey`], and failures surface as the contract's own error type via
+//! `From<AccessError>`. Per-operation pause flags live here too, keyed by each
+//! contract's own `Operation` enum.
+use soroban_sdk::{contractevent, contracttype, symbol_short, Address, Env, IntoVal, Symbol, Val};
+
+use crate::types::Role;
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Why an access check failed
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AccessError {
+    /// The contract has no admin yet
+    NotInitialized,
+    Unauthorized,
+    /// The role can't be granted on this contract
+    InvalidRole,
+    NoPendingAdmin,
+    /// The operation is paused
+    OperationPaused,
+}
+
+/// Storage keys for access control. They encode like the contracts' own
+/// `DataKey::PendingAdmin` and `DataKey::Role` variants.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccessKey {
+    /// Proposed admin awaiting acceptance (instance storage)
+    PendingAdmin,
+    /// Whether an account holds a role (persistent storage)
+    Role(Role, Address),
+}
+
+/// A contract whose admin and roles are managed by this module.
+pub trait AccessControl {
+    type Error: From<AccessError>;
+
+    /// The current admin, or None before the contract is initialized
+    fn admin(env: &Env) -> Option<Address>;
+
+    /// Store `admin` as the contract admin
+    fn set_admin(env: &Env, admin: &Address);
+
+    /// Roles the contract checks. Others can't be granted on it.
+    fn is_grantable(role: &Role) -> bool;
+}
+
+/// Emitted when the admin nominates a successor
+#[contractevent(topics = ["admin_proposed"])]
+pub struct AdminProposed {
+    #[topic]
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+/// Emitted when a nominee accepts the admin role
+#[contractevent(topics = ["admin_changed"])]
+pub struct AdminTransferred {
+    #[topic]
+    pub previous_admin: Address,
+    #[topic]
+    pub new_admin: Address,
+}
+
+/// Emitted when the admin grants a role
+#[contractevent(topics = ["role_granted"])]
+pub struct RoleGranted {
+    #[topic]
+    pub account: Address,
+    pub role: Role,
+    pub admin: Address,
+}
+
+/// Emitted when the admin revokes a role
+#[contractevent(topics = ["role_revoked"])]
+pub struct RoleRevoked {
+    #[topic]
+    pub account: Address,
+    pub role: Role,
+    pub admin: Address,
+}
+
+/// Require `admin` to be the contract admin and to have authorized the call
+pub fn require_admin<C: AccessControl>(env: &Env, admin: &Address) -> Result<(), C::Error> {
+    let stored = C::admin(env).ok_or(AccessError::NotInitialized)?;
+
+    admin.require_auth();
+
+    if *admin != stored {
+        return Err(AccessError::Unauthorized.into());
+    }
+
+    Ok(())
+}
+
+/// Require `caller` to hold `role` and to have authorized the call. The
+/// admin holds every role.
+pub fn require_role<C: AccessControl>(
+    env: &Env,
+    caller: &Address,
+    role: Role,
+) -> Result<(), C::Error> {
+    if C::admin(env).is_none() {
+        return Err(AccessError::NotInitialized.into());
+    }
+
+    caller.require_auth();
+
+    if !has_role::<C>(env, role, caller.clone()) {
+        return Err(AccessError::Unauthorized.into());
+    }
+
+    Ok(())
+}
+
+/// Whether `account` holds `role`. The admin holds every role.
+pub fn has_role<C: AccessControl>(env: &Env, role: Role, account: Address) -> bool {
+    if C::admin(env) == Some(account.clone()) {
+        return true;
+    }
+
+    role != Role::Admin
+        && env
+            .storage()
+            .persistent()
+            .get(&AccessKey::Role(role, account))
+            .unwrap_or(false)
+}
+
+/// Grant `role` to `account` (admin only)
+pub fn grant_role<C: AccessControl>(
+    env: &Env,
+    admin: Address,
+    role: Role,
+    account: Address,
+) -> Result<(), C::Error> {
+    require_admin::<C>(env, &admin)?;
+
+    if !C::is_grantable(&role) {
+        return Err(AccessError::InvalidRole.into());
+    }
+
+    let key = AccessKey::Role(role.clone(), account.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    RoleGranted {
+        account,
+        role,
+        admin,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Revoke `role` from `account` (admin only)
+pub fn revoke_role<C: AccessControl>(
+    env: &Env,
+    admin: Address,
+    role: Role,
+    account: Address,
+) -> Result<(), C::Error> {
+    require_admin::<C>(env, &admin)?;
+
+    if !C::is_grantable(&role) {
+        return Err(AccessError::InvalidRole.into());
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&AccessKey::Role(role.clone(), account.clone()));
+
+    RoleRevoked {
+        account,
+        role,
+        admin,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Nominate the next admin (admin only). Nothing changes until the nominee
+/// accepts; a later nomination replaces an unaccepted one.
+pub fn propose_admin<C: AccessControl>(
+    env: &Env,
+    admin: Address,
+    new_admin: Address,
+) -> Result<(), C::Error> {
+    require_admin::<C>(env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&AccessKey::PendingAdmin, &new_admin);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+    AdminProposed { admin, new_admin }.publish(env);
+
+    Ok(())
+}
+
+/// Take over as admin (nominee only). Granted roles are left in place.
+pub fn accept_admin<C: AccessControl>(env: &Env, new_admin: Address) -> Result<(), C::Error> {
+    let previous_admin = C::admin(env).ok_or(AccessError::NotInitialized)?;
+    let pending = get_pending_admin(env).ok_or(AccessError::NoPendingAdmin)?;
+
+    new_admin.require_auth();
+
+    if new_admin != pending {
+        return Err(AccessError::Unauthorized.into());
+    }
+
+    C::set_admin(env, &new_admin);
+    env.storage().instance().remove(&AccessKey::PendingAdmin);
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+    AdminTransferred {
+        previous_admin,
+        new_admin,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// The nominated admin awaiting acceptance, if any
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&AccessKey::PendingAdmin)
+}
+
+/// Require `caller` to be allowed to make this pause change. Pausing is open
+/// to guardians and pausers; only pausers may unpause.
+pub fn require_pause_right<C: AccessControl>(
+    env: &Env,
+    caller: &Address,
+    paused: bool,
+) -> Result<(), C::Error> {
+    if paused && has_role::<C>(env, Role::Guardian, caller.clone()) {
+        caller.require_auth();
+        return Ok(());
+    }
+
+    require_role::<C>(env, caller, Role::Pauser)
+}
+
+/// Storage key for an operation's pause flag. It encodes like the contracts'
+/// own `DataKey::Paused(operation)` variant, so flags written before the
+/// logic moved here are still read.
+fn paused_key<O: IntoVal<Env, Val>>(env: &Env, operation: O) -> (Symbol, Val) {
+    (symbol_short!("Paused"), operation.into_val(env))
+}
+
+/// Whether `operation` is paused
+pub fn is_paused<O: IntoVal<Env, Val>>(env: &Env, operation: O) -> bool {
+    env.storage()
+        .instance()
+        .get(&paused_key(env, operation))
+        .unwrap_or(false)
+}
+
+/// Set or clear the pause flag for `operation` without any access check
+pub fn set_paused<O: IntoVal<Env, Val>>(env: &Env, operation: O, paused: bool) {
+    let key = paused_key(env, operation);
+    if paused {
+        env.storage().instance().set(&key, &true);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Pause `operation` (admin, pauser or guardian). Returns whether the flag
+/// changed, so the caller emits its pause event only then.
+pub fn pause<C: AccessControl, O: IntoVal<Env, Val> + Clone>(
+    env: &Env,
+    caller: &Address,
+    operation: O,
+) -> Result<bool, C::Error> {
+    require_pause_right::<C>(env, caller, true)?;
+
+    if is_paused(env, operation.clone()) {
+        return Ok(false);
+    }
+    set_paused(env, operation, true);
+
+    Ok(true)
+}
+
+/// Resume `operation` (admin or pauser). Returns whether the flag changed.
+pub fn unpause<C: AccessControl, O: IntoVal<Env, Val> + Clone>(
+    env: &Env,
+    caller: &Address,
+    operation: O,
+) -> Result<bool, C::Error> {
+    require_pause_right::<C>(env, caller, false)?;
+
+    if !is_paused(env, operation.clone()) {
+        return Ok(false);
+    }
+    set_paused(env, operation, false);
+
+    Ok(true)
+}
+
+/// Fail with `OperationPaused` if `operation` is paused
+pub fn require_not_paused<C: AccessControl, O: IntoVal<Env, Val>>(
+    env: &Env,
+    operation: O,
+) -> Result<(), C::Error> {
+    if is_paused(env, operation) {
+        return Err(AccessError::OperationPaused.into());
+    }
+
+    Ok(())
+}
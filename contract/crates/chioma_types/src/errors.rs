@@ -0,0 +1,64 @@
+//! Errors returned by the chioma contract.
+use soroban_sdk::contracterror;
+
+use crate::access::AccessError;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum RentalError {
+    AlreadyInitialized = 1,
+    InvalidAdmin = 2,
+    InvalidConfig = 3,
+    AgreementAlreadyExists = 4,
+    InvalidAmount = 5,
+    InvalidDate = 6,
+    InvalidCommissionRate = 7,
+    AgreementNotActive = 10,
+    AgreementNotFound = 13,
+    NotTenant = 14,
+    Unauthorized = 18,
+    InvalidState = 15,
+    Expired = 16,
+    ContractPaused = 17,
+    DisputeContractNotSet = 19,
+    MaintenanceRequestNotFound = 20,
+    InvalidMaintenanceRequest = 21,
+    SlaNotBreached = 22,
+    RentProposalNotFound = 23,
+    RentProposalPending = 24,
+    RentIncreaseExceedsCap = 25,
+    RentIncreaseTooSoon = 26,
+    InvalidShares = 27,
+    ShareAlreadyPaid = 28,
+    NoShortfall = 29,
+    TermsMismatch = 30,
+    CounterOfferNotFound = 31,
+    CounterOfferPending = 32,
+    PropertyNotFound = 33,
+    PropertyNotVerified = 34,
+    PropertyOwnerMismatch = 35,
+    PropertyUnavailable = 36,
+    AddendumNotFound = 37,
+    NoPendingAdmin = 38,
+    InvalidRole = 39,
+    ConfigChangeNotFound = 40,
+    TimelockNotElapsed = 41,
+    PropertyRegistryNotSet = 42,
+    PaymentNotDue = 43,
+    ConfigChangeExpired = 44,
+    ConfigChangeSuperseded = 45,
+    PaymentNotFound = 46,
+}
+
+impl From<AccessError> for RentalError {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => RentalError::InvalidState,
+            AccessError::Unauthorized => RentalError::Unauthorized,
+            AccessError::InvalidRole => RentalError::InvalidRole,
+            AccessError::NoPendingAdmin => RentalError::NoPendingAdmin,
+            AccessError::OperationPaused => RentalError::ContractPaused,
+        }
+    }
+}
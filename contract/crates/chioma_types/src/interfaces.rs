@@ -0,0 +1,53 @@
+//! Client interfaces for cross-contract calls between Chioma contracts.
+use soroban_sdk::{contractclient, Address, BytesN, Env, String};
+
+use crate::errors::RentalError;
+use crate::types::{CoTenancy, Escrow, MaintenanceRequest, PropertyDetails, RentAgreement};
+
+/// Subset of the chioma contract interface called by other contracts.
+#[contractclient(name = "ChiomaClient")]
+pub trait ChiomaInterface {
+    /// Retrieve a rental agreement by its identifier.
+    fn get_agreement(env: Env, agreement_id: String) -> Option<RentAgreement>;
+
+    /// Retrieve the co-tenancy of a shared agreement, or None for a sole tenant.
+    fn get_co_tenancy(env: Env, agreement_id: String) -> Option<CoTenancy>;
+
+    /// Move an Active agreement into the Disputed state.
+    fn mark_disputed(env: Env, agreement_id: String) -> Result<(), RentalError>;
+
+    /// Close a dispute, restoring the agreement to Active or terminating it.
+    fn resolve_dispute(env: Env, agreement_id: String, terminate: bool) -> Result<(), RentalError>;
+
+    /// Retrieve a maintenance request by its identifier.
+    fn get_maintenance_request(env: Env, request_id: u32) -> Option<MaintenanceRequest>;
+}
+
+/// Subset of the escrow contract interface called by other contracts.
+#[contractclient(name = "EscrowClient")]
+pub trait EscrowInterface {
+    /// Retrieve an escrow by its identifier.
+    fn get_escrow(env: Env, escrow_id: BytesN<32>) -> Escrow;
+
+    /// Freeze a funded escrow pending a dispute outcome.
+    fn initiate_dispute(env: Env, escrow_id: BytesN<32>, caller: Address, reason: String);
+
+    /// Release a disputed escrow to the given party (arbiter only).
+    fn resolve_dispute(env: Env, escrow_id: BytesN<32>, caller: Address, release_to: Address);
+
+    /// Split a disputed escrow, sending `beneficiary_bps` of the funds to the
+    /// beneficiary and the rest to the depositor (arbiter only).
+    fn resolve_dispute_split(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        beneficiary_bps: u32,
+    );
+}
+
+/// Subset of the property registry interface called by other contracts.
+#[contractclient(name = "PropertyRegistryClient")]
+pub trait PropertyRegistryInterface {
+    /// Retrieve a registered property by its identifier.
+    fn get_property(env: Env, property_id: String) -> Option<PropertyDetails>;
+}
@@ -0,0 +1,32 @@
+#![no_std]
+
+//! Chioma Types
+//!
+//! Canonical contract types, errors, client interfaces and access control
+//! shared by the Chioma contracts. Any contract that stores, returns or decodes a rent
+//! agreement must use these definitions so that the XDR encoding stays
+//! identical across contract boundaries.
+//!
+//! Every contract stores a `SCHEMA_VERSION` naming the storage layout its
+//! build reads and writes, with zero meaning data written before versioning.
+//! A build that changes a stored shape bumps the constant and adds an
+//! `if from_version < N` step to its admin-only `migrate`.
+
+pub mod access;
+pub mod errors;
+pub mod interfaces;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use access::{AccessControl, AccessError};
+pub use errors::RentalError;
+pub use interfaces::{
+    ChiomaClient, ChiomaInterface, EscrowClient, EscrowInterface, PropertyRegistryClient,
+    PropertyRegistryInterface,
+};
+pub use types::{
+    AgreementStatus, CoTenancy, Escrow, EscrowStatus, MaintenancePriority, MaintenanceRequest,
+    MaintenanceStatus, PaymentSplit, PropertyDetails, RentAgreement, Role, SlaBreach, TenantShare,
+};
@@ -0,0 +1,319 @@
+//! XDR compatibility tests for the shared contract types.
+//!
+//! These pin the on-chain encoding of every shared type. A failure here means
+//! a change would break decoding of values written by deployed contracts.
+
+use super::*;
+use crate::access::AccessKey;
+use soroban_sdk::{
+    testutils::Address as _,
+    xdr::{FromXdr, ToXdr},
+    Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+fn sample_agreement(env: &Env) -> RentAgreement {
+    let token = Address::generate(env);
+    let tenant = Address::generate(env);
+
+    let mut payment_history = Map::new(env);
+    payment_history.set(
+        0,
+        PaymentSplit {
+            landlord_amount: 900,
+            platform_amount: 100,
+            token: token.clone(),
+            payment_date: 1_000,
+            payer: tenant.clone(),
+        },
+    );
+
+    RentAgreement {
+        agreement_id: String::from_str(env, "AGREEMENT_001"),
+        landlord: Address::generate(env),
+        tenant,
+        agent: Some(Address::generate(env)),
+        monthly_rent: 1000,
+        security_deposit: 2000,
+        start_date: 100,
+        end_date: 200,
+        agent_commission_rate: 5,
+        status: AgreementStatus::Active,
+        total_rent_paid: 1000,
+        payment_count: 1,
+        signed_at: Some(50),
+        payment_token: token,
+        next_payment_due: 150,
+        payment_history,
+    }
+}
+
+fn field_names(env: &Env, val: Val) -> Vec<Symbol> {
+    Map::<Symbol, Val>::try_from_val(env, &val).unwrap().keys()
+}
+
+fn symbols(env: &Env, names: &[&str]) -> Vec<Symbol> {
+    let mut out = Vec::new(env);
+    for name in names {
+        out.push_back(Symbol::new(env, name));
+    }
+    out
+}
+
+#[test]
+fn test_rent_agreement_xdr_round_trip() {
+    let env = Env::default();
+    let agreement = sample_agreement(&env);
+
+    let bytes = agreement.clone().to_xdr(&env);
+    let decoded = RentAgreement::from_xdr(&env, &bytes).unwrap();
+
+    assert_eq!(decoded, agreement);
+}
+
+#[test]
+fn test_rent_agreement_field_names() {
+    let env = Env::default();
+    let val: Val = sample_agreement(&env).into_val(&env);
+
+    assert_eq!(
+        field_names(&env, val),
+        symbols(
+            &env,
+            &[
+                "agent",
+                "agent_commission_rate",
+                "agreement_id",
+                "end_date",
+                "landlord",
+                "monthly_rent",
+                "next_payment_due",
+                "payment_count",
+                "payment_history",
+                "payment_token",
+                "security_deposit",
+                "signed_at",
+                "start_date",
+                "status",
+                "tenant",
+                "total_rent_paid",
+            ]
+        )
+    );
+}
+
+#[test]
+fn test_payment_split_field_names() {
+    let env = Env::default();
+    let split = sample_agreement(&env).payment_history.get(0).unwrap();
+    let val: Val = split.into_val(&env);
+
+    assert_eq!(
+        field_names(&env, val),
+        symbols(
+            &env,
+            &[
+                "landlord_amount",
+                "payer",
+                "payment_date",
+                "platform_amount",
+                "token",
+            ]
+        )
+    );
+}
+
+#[test]
+fn test_property_details_field_names() {
+    let env = Env::default();
+    let property = PropertyDetails {
+        property_id: String::from_str(&env, "PROPERTY_001"),
+        landlord: Address::generate(&env),
+        metadata_hash: String::from_str(&env, "QmProperty"),
+        verified: true,
+        registered_at: 100,
+        verified_at: Some(200),
+    };
+    let val: Val = property.into_val(&env);
+
+    assert_eq!(
+        field_names(&env, val),
+        symbols(
+            &env,
+            &[
+                "landlord",
+                "metadata_hash",
+                "property_id",
+                "registered_at",
+                "verified",
+                "verified_at",
+            ]
+        )
+    );
+}
+
+#[test]
+fn test_agreement_status_variants() {
+    let env = Env::default();
+    let cases = [
+        (AgreementStatus::Draft, "Draft"),
+        (AgreementStatus::Pending, "Pending"),
+        (AgreementStatus::Active, "Active"),
+        (AgreementStatus::Completed, "Completed"),
+        (AgreementStatus::Cancelled, "Cancelled"),
+        (AgreementStatus::Terminated, "Terminated"),
+        (AgreementStatus::Disputed, "Disputed"),
+    ];
+
+    for (status, name) in cases {
+        let val: Val = status.clone().into_val(&env);
+        assert_eq!(
+            Vec::<Symbol>::try_from_val(&env, &val).unwrap(),
+            symbols(&env, &[name])
+        );
+        assert_eq!(AgreementStatus::try_from_val(&env, &val).unwrap(), status);
+    }
+}
+
+#[test]
+fn test_role_variants() {
+    let env = Env::default();
+    let cases = [
+        (Role::Admin, "Admin"),
+        (Role::Pauser, "Pauser"),
+        (Role::Verifier, "Verifier"),
+        (Role::FeeManager, "FeeManager"),
+        (Role::ArbiterManager, "ArbiterManager"),
+        (Role::Guardian, "Guardian"),
+    ];
+
+    for (role, name) in cases {
+        let val: Val = role.clone().into_val(&env);
+        assert_eq!(
+            Vec::<Symbol>::try_from_val(&env, &val).unwrap(),
+            symbols(&env, &[name])
+        );
+        assert_eq!(Role::try_from_val(&env, &val).unwrap(), role);
+    }
+}
+
+#[test]
+fn test_access_keys() {
+    let env = Env::default();
+    let account = Address::generate(&env);
+
+    // Keys stored by the contracts before access control was shared
+    let val: Val = AccessKey::PendingAdmin.into_val(&env);
+    assert_eq!(
+        Vec::<Symbol>::try_from_val(&env, &val).unwrap(),
+        symbols(&env, &["PendingAdmin"])
+    );
+
+    let val: Val = AccessKey::Role(Role::Guardian, account.clone()).into_val(&env);
+    let expected: Val = (Symbol::new(&env, "Role"), Role::Guardian, account.clone()).into_val(&env);
+    assert_eq!(
+        Vec::<Val>::try_from_val(&env, &val).unwrap().to_xdr(&env),
+        Vec::<Val>::try_from_val(&env, &expected)
+            .unwrap()
+            .to_xdr(&env)
+    );
+}
+
+#[soroban_sdk::contract]
+struct StorageHost;
+
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+enum TestOperation {
+    Payments,
+}
+
+#[soroban_sdk::contracttype]
+enum LegacyKey {
+    Paused(TestOperation),
+}
+
+#[test]
+fn test_pause_flags_read_legacy_keys() {
+    let env = Env::default();
+    let host = env.register(StorageHost, ());
+
+    env.as_contract(&host, || {
+        // A flag stored by a contract under its own `DataKey::Paused`
+        env.storage()
+            .instance()
+            .set(&LegacyKey::Paused(TestOperation::Payments), &true);
+        assert!(access::is_paused(&env, TestOperation::Payments));
+
+        access::set_paused(&env, TestOperation::Payments, false);
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&LegacyKey::Paused(TestOperation::Payments)));
+    });
+}
+
+#[test]
+fn test_rental_error_codes() {
+    assert_eq!(RentalError::AlreadyInitialized as u32, 1);
+    assert_eq!(RentalError::InvalidAdmin as u32, 2);
+    assert_eq!(RentalError::InvalidConfig as u32, 3);
+    assert_eq!(RentalError::AgreementAlreadyExists as u32, 4);
+    assert_eq!(RentalError::InvalidAmount as u32, 5);
+    assert_eq!(RentalError::InvalidDate as u32, 6);
+    assert_eq!(RentalError::InvalidCommissionRate as u32, 7);
+    assert_eq!(RentalError::AgreementNotActive as u32, 10);
+    assert_eq!(RentalError::AgreementNotFound as u32, 13);
+    assert_eq!(RentalError::NotTenant as u32, 14);
+    assert_eq!(RentalError::InvalidState as u32, 15);
+    assert_eq!(RentalError::Expired as u32, 16);
+    assert_eq!(RentalError::ContractPaused as u32, 17);
+    assert_eq!(RentalError::Unauthorized as u32, 18);
+    assert_eq!(RentalError::DisputeContractNotSet as u32, 19);
+    assert_eq!(RentalError::MaintenanceRequestNotFound as u32, 20);
+    assert_eq!(RentalError::InvalidMaintenanceRequest as u32, 21);
+    assert_eq!(RentalError::SlaNotBreached as u32, 22);
+    assert_eq!(RentalError::RentProposalNotFound as u32, 23);
+    assert_eq!(RentalError::RentProposalPending as u32, 24);
+    assert_eq!(RentalError::RentIncreaseExceedsCap as u32, 25);
+    assert_eq!(RentalError::RentIncreaseTooSoon as u32, 26);
+    assert_eq!(RentalError::InvalidShares as u32, 27);
+    assert_eq!(RentalError::ShareAlreadyPaid as u32, 28);
+    assert_eq!(RentalError::NoShortfall as u32, 29);
+    assert_eq!(RentalError::TermsMismatch as u32, 30);
+    assert_eq!(RentalError::CounterOfferNotFound as u32, 31);
+    assert_eq!(RentalError::CounterOfferPending as u32, 32);
+    assert_eq!(RentalError::PropertyNotFound as u32, 33);
+    assert_eq!(RentalError::PropertyNotVerified as u32, 34);
+    assert_eq!(RentalError::PropertyOwnerMismatch as u32, 35);
+    assert_eq!(RentalError::PropertyUnavailable as u32, 36);
+    assert_eq!(RentalError::AddendumNotFound as u32, 37);
+    assert_eq!(RentalError::NoPendingAdmin as u32, 38);
+    assert_eq!(RentalError::InvalidRole as u32, 39);
+}
+
+#[test]
+fn test_maintenance_request_xdr_round_trip() {
+    let env = Env::default();
+    let request = MaintenanceRequest {
+        id: 1,
+        agreement_id: String::from_str(&env, "AGREEMENT_001"),
+        tenant: Address::generate(&env),
+        landlord: Address::generate(&env),
+        description_hash: String::from_str(&env, "QmRepair"),
+        priority: MaintenancePriority::High,
+        status: MaintenanceStatus::Acknowledged,
+        opened_at: 100,
+        acknowledge_by: 200,
+        complete_by: 300,
+        acknowledged_at: Some(250),
+        scheduled_for: None,
+        completed_at: None,
+        acknowledgement_breached_at: Some(250),
+        completion_breached_at: None,
+    };
+
+    let bytes = request.clone().to_xdr(&env);
+    let decoded = MaintenanceRequest::from_xdr(&env, &bytes).unwrap();
+
+    assert_eq!(decoded, request);
+}
@@ -0,0 +1,200 @@
+//! Rent agreement types shared across contract boundaries.
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Vec};
+
+/// Lifecycle status of a rent agreement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    Draft,
+    Pending,
+    Active,
+    Completed,
+    Cancelled,
+    Terminated,
+    Disputed,
+}
+
+/// Rent agreement as stored and returned by the chioma contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentAgreement {
+    pub agreement_id: String,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub agent: Option<Address>,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub agent_commission_rate: u32,
+    pub status: AgreementStatus,
+    pub total_rent_paid: i128,
+    pub payment_count: u32,
+    pub signed_at: Option<u64>,
+    pub payment_token: Address,
+    pub next_payment_due: u64,
+    /// Payment history stored inline by earlier contract versions. New
+    /// payments are kept in per-payment entries and this stays empty once an
+    /// agreement has been migrated; it remains so existing entries decode.
+    pub payment_history: Map<u32, PaymentSplit>,
+}
+
+/// A tenant's share of the monthly rent on a shared agreement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TenantShare {
+    pub tenant: Address,
+    pub share_bps: u32,
+}
+
+/// The tenants who share an agreement and who guarantees their rent.
+///
+/// The agreement's `tenant` is the lead tenant and is always one of
+/// `tenants`; any rounding remainder of the rent falls to their share.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoTenancy {
+    pub tenants: Vec<TenantShare>,
+    /// Liable for any share still unpaid once a period is overdue
+    pub guarantor: Option<Address>,
+}
+
+/// A property as recorded by the property registry contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PropertyDetails {
+    pub property_id: String,
+    pub landlord: Address,
+    pub metadata_hash: String,
+    pub verified: bool,
+    pub registered_at: u64,
+    pub verified_at: Option<u64>,
+}
+
+/// Split of a single rent payment between landlord and platform.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentSplit {
+    pub landlord_amount: i128,
+    pub platform_amount: i128,
+    pub token: Address,
+    pub payment_date: u64,
+    pub payer: Address,
+}
+
+/// Urgency of a maintenance request, which selects its SLA deadlines.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MaintenancePriority {
+    Low,
+    Medium,
+    High,
+    Emergency,
+}
+
+/// Progress of a maintenance request through the landlord's workflow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MaintenanceStatus {
+    Open,
+    Acknowledged,
+    Scheduled,
+    Completed,
+}
+
+/// SLA deadline a maintenance request has missed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SlaBreach {
+    /// The landlord did not acknowledge the request in time
+    Acknowledgement,
+    /// The landlord did not complete the work in time
+    Completion,
+}
+
+/// Repair request raised by a tenant against an agreement.
+///
+/// Deadlines are fixed when the request is opened. Each missed deadline is
+/// recorded with the time it was flagged and kept permanently, so the
+/// request can be cited as dispute evidence even after the work is done.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceRequest {
+    pub id: u32,
+    pub agreement_id: String,
+    pub tenant: Address,
+    pub landlord: Address,
+    /// Hash reference to the off-chain description (IPFS, etc.)
+    pub description_hash: String,
+    pub priority: MaintenancePriority,
+    pub status: MaintenanceStatus,
+    pub opened_at: u64,
+    pub acknowledge_by: u64,
+    pub complete_by: u64,
+    pub acknowledged_at: Option<u64>,
+    pub scheduled_for: Option<u64>,
+    pub completed_at: Option<u64>,
+    /// When a missed acknowledgement deadline was recorded
+    pub acknowledgement_breached_at: Option<u64>,
+    /// When a missed completion deadline was recorded
+    pub completion_breached_at: Option<u64>,
+}
+
+/// Status of an escrow throughout its lifecycle.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[contracttype]
+pub enum EscrowStatus {
+    /// Initial state after creation, not yet funded
+    Pending = 0,
+    /// Funds have been deposited into escrow
+    Funded = 1,
+    /// Funds have been released to the beneficiary
+    Released = 2,
+    /// Funds have been refunded to the depositor
+    Refunded = 3,
+    /// Under dispute, awaiting admin resolution
+    Disputed = 4,
+}
+
+/// Represents a security deposit escrow managed by 2-of-3 multi-sig.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Escrow {
+    /// Unique identifier for the escrow (hash of agreement_id)
+    pub id: BytesN<32>,
+    /// The party depositing funds (tenant)
+    pub depositor: Address,
+    /// The party who benefits from the deposit (landlord)
+    pub beneficiary: Address,
+    /// The admin/arbiter who can resolve disputes
+    pub arbiter: Address,
+    /// Amount of funds in the escrow
+    pub amount: i128,
+    /// Token contract address (USDC, XLM, etc.)
+    pub token: Address,
+    /// Current status of the escrow
+    pub status: EscrowStatus,
+    /// Timestamp when escrow was created
+    pub created_at: u64,
+    /// Reason for dispute, if any
+    pub dispute_reason: Option<String>,
+}
+
+/// Operational role that may be granted to an account so it can perform a
+/// narrow set of privileged actions without holding the admin key. The admin
+/// implicitly holds every role; `Admin` itself only moves by admin transfer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Role {
+    Admin,
+    /// May pause and unpause the contract
+    Pauser,
+    /// May verify properties, agents and user profiles
+    Verifier,
+    /// May change platform fees and where they are collected
+    FeeManager,
+    /// May add, deactivate, reactivate and remove arbiters
+    ArbiterManager,
+    /// May pause operations in an emergency but not unpause them
+    Guardian,
+}